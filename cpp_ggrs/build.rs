@@ -0,0 +1,23 @@
+// compiles the c++ side of the bridge into the library, so rust can call into user supplied c++ types
+fn main() {
+    let mut bridges = vec!["src/lib.rs"];
+    // c++ helpers for the integration tests, they never end up in a normal build
+    let testing = std::env::var_os("CARGO_FEATURE_TESTING").is_some();
+    if testing {
+        bridges.push("src/testing.rs");
+    }
+    let mut build = cxx_build::bridges(bridges);
+    if testing {
+        build.file("tests/support/ggrs_testing.cc");
+    }
+    build
+        .flag_if_supported("-std=c++17")
+        .compile("cpp_ggrs_bridge");
+
+    println!("cargo:rerun-if-changed=src/lib.rs");
+    println!("cargo:rerun-if-changed=src/testing.rs");
+    println!("cargo:rerun-if-changed=include/ggrs_socket.h");
+    println!("cargo:rerun-if-changed=include/ggrs_events.h");
+    println!("cargo:rerun-if-changed=tests/support/ggrs_testing.h");
+    println!("cargo:rerun-if-changed=tests/support/ggrs_testing.cc");
+}
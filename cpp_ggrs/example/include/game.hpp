@@ -2,6 +2,7 @@
 #define _EXAMPLE_GAME_H
 
 #include "../../out/cpp_ggrs.h"
+#include <vector>
 
 namespace ex {
 namespace game {
@@ -15,13 +16,10 @@ struct Game {
   Player players[2];
 };
 
-struct SaveState {
-  Game save;
-};
-
 void AdvanceGame(Game &game, rust::Vec<GGRS::GGRSInput> inputs);
-void SaveGame(Game game, SaveState &save);
-void LoadGame(Game &game, SaveState &save);
+std::vector<std::uint8_t> SaveGame(const Game &game);
+void LoadGame(Game &game, const rust::Vec<std::uint8_t> &state);
+std::uint64_t Checksum(const std::vector<std::uint8_t> &state);
 
 bool IsBitSet(std::uint32_t bf, int pos);
 
@@ -1,4 +1,5 @@
 #include "../include/game.hpp"
+#include <cstring>
 #include <iostream>
 
 void MovePlayer(ex::game::Game &game, std::uint32_t input, int player) {
@@ -17,20 +18,33 @@ void ex::game::AdvanceGame(Game &game, rust::Vec<GGRS::GGRSInput> inputs) {
   game.frame++;
   int idx = 0;
   for (auto input : inputs) {
-    MovePlayer(game, input.input, idx);
+    MovePlayer(game, input.input[0], idx);
     idx++;
   }
 }
 
-void ex::game::SaveGame(Game game, SaveState &save) {
+std::vector<std::uint8_t> ex::game::SaveGame(const Game &game) {
   std::cout << "Saved Frame: " << game.frame << std::endl;
-  save.save = game;
+  std::vector<std::uint8_t> state(sizeof(Game));
+  std::memcpy(state.data(), &game, sizeof(Game));
+  return state;
 }
 
-void ex::game::LoadGame(Game &game, SaveState &save) {
-  std::cout << "Loaded Frame: " << save.save.frame << std::endl;
-  auto tmp = save.save;
-  game = tmp;
+void ex::game::LoadGame(Game &game, const rust::Vec<std::uint8_t> &state) {
+  if (state.size() != sizeof(Game))
+    return;
+  std::memcpy(&game, state.data(), sizeof(Game));
+  std::cout << "Loaded Frame: " << game.frame << std::endl;
+}
+
+std::uint64_t ex::game::Checksum(const std::vector<std::uint8_t> &state) {
+  // FNV-1a, good enough to spot a desync
+  std::uint64_t hash = 14695981039346656037ull;
+  for (auto byte : state) {
+    hash ^= byte;
+    hash *= 1099511628211ull;
+  }
+  return hash;
 }
 
 bool ex::game::IsBitSet(std::uint32_t bf, int pos) {
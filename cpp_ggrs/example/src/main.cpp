@@ -10,10 +10,10 @@
 
 
 void DrawGameState(ex::game::Game &game);
-void PrintEvent(GGRS::GGRSEvent &ev, int &frames_to_skip);
-void HandleRequests(ex::game::Game &game, ex::game::SaveState &save,
+void PrintEvent(GGRS::GGRSEvent &ev);
+void HandleRequests(ex::game::Game &game, GGRS::GGRSSession &sess,
                     rust::Vec<GGRS::GGRSFrameAction> requests);
-std::uint32_t FetchLocalInput();
+std::uint8_t FetchLocalInput();
 
 int main(int argc, char **argv) {
   if (argc != 4) {
@@ -30,66 +30,68 @@ int main(int argc, char **argv) {
   std::cout << local_player << " " << local_port << " " << remote_addr
             << std::endl;
   // setup game
-  ex::game::SaveState save;
   ex::game::Game game;
-  // setup ggrs
-  GGRS::GGRSSessionInfo info;
-  GGRS::GGRSPlayer players[2];
-  GGRS::setup_ggrs_info(info);
-  GGRS::set_num_players(info, 2);
-  GGRS::set_sparse_saving(info, true);
-  GGRS::setup_p2p_session(info, local_port, 60, 1, 7);
+  // setup ggrs. every builder call throws right away when the value is rejected
+  rust::Box<GGRS::GGRSSessionBuilder> builder = GGRS::new_session_builder();
+  builder->with_num_players(2)
+      .with_sparse_saving_mode(true)
+      .with_input_size(1)
+      .with_fps(60)
+      .with_input_delay(1)
+      .with_max_prediction_window(7);
   // add players
   for (int i = 0; i < 2; i++) {
-    players[i].player_handle = i;
+    GGRS::GGRSPlayer player;
+    player.player_handle = i;
     if (i == local_player) {
-      players[i].player_type = GGRS::GGRSPlayerType::Local;
-      players[i].socket_addr = "localhost";
+      player.player_type = GGRS::GGRSPlayerType::Local;
     } else {
-      players[i].player_type = GGRS::GGRSPlayerType::Remote;
-      players[i].socket_addr = remote_addr;
+      player.player_type = GGRS::GGRSPlayerType::Remote;
+      player.socket_addr = remote_addr;
     }
-    GGRS::add_player(info, players[i]);
+    builder->add_player(player);
   }
   // add spectators (optional)
-  // creating the session pointer
-  auto sess = GGRS::create_session(info);
+  // creating the session, the box frees it when it goes out of scope
+  rust::Box<GGRS::GGRSSession> sess = builder->start_p2p_session(local_port);
+  std::cout << "Listening on " << std::string(sess->local_address())
+            << std::endl;
   // setup raylib
   InitWindow(600, 600, "GGRS C++ GAME");
   SetTargetFPS(60);
-  // timesync
-  int frames_to_skip = 0;
-  //
   while (!WindowShouldClose()) {
-    // poll other peers
-    GGRS::poll_remote_clients(sess);
-    // handle events
-    auto events = GGRS::get_events(sess);
-    for (int i = 0; i < events.size(); i++) {
-      PrintEvent(events[i], frames_to_skip);
-    }
-    // keep the clients synced
-    if( frames_to_skip > 0) {
-      frames_to_skip -= 1;
-      std::cout << "Frame: " << game.frame << " skipped: WaitRecommendation" << std::endl;
-    }
-    else if (GGRS::get_current_state(sess) == GGRS::GGRSSessionState::Running) {
-      // add local input
-      GGRS::add_local_input(sess, local_player, FetchLocalInput());
-      // advance frame
-      auto result = GGRS::advance_frame(sess);
+    // keep the clients synced. raylib runs the loop at a fixed 60 fps, so the
+    // pacer skips frames while this client runs ahead
+    auto pacing = sess->frame_pacing();
+    if (pacing.skip_frame) {
+      // skipped frames only keep the connection alive
+      sess->poll_remote_clients();
+      std::cout << "Frame: " << game.frame << " skipped: "
+                << pacing.frames_ahead << " frames ahead" << std::endl;
+    } else {
+      // poll, add local input and advance in one call
+      GGRS::GGRSLocalInput input;
+      input.player_handle = local_player;
+      input.input.push_back(FetchLocalInput());
+      auto tick =
+          sess->tick(rust::Slice<const GGRS::GGRSLocalInput>(&input, 1));
+      // handle events
+      for (auto &ev : tick.events) {
+        PrintEvent(ev);
+      }
       // handle update
-      if (!result.skip_frame) {
-        HandleRequests(game, save, result.actions);
-      } else {
-        std::cout << "Skipping Frame: " << game.frame << std::endl;
+      if (tick.state == GGRS::GGRSSessionState::Running) {
+        if (!tick.skip_frame) {
+          HandleRequests(game, *sess, tick.actions);
+        } else {
+          std::cout << "Skipping Frame: " << game.frame << std::endl;
+        }
       }
     }
     // render game
     DrawGameState(game);
   }
-  std::cout << "Session Clean: " << GGRS::clean_session(sess) << std::endl;
-  std::cout << "Session Clean: " << sess << std::endl;
+  std::cout << "Session Clean: " << sess->clean_session() << std::endl;
   return 0;
 }
 
@@ -127,26 +129,33 @@ std::string EventTypeToString(GGRS::GGRSEventType type) {
     return "NetworkResumed";
   case GGRS::GGRSEventType::WaitRecommendation:
     return "WaitRecommendation";
+  case GGRS::GGRSEventType::DesyncDetected:
+    return "DesyncDetected";
   default:
     return "Invalid Event Type";
   }
 }
 
-void PrintEvent(GGRS::GGRSEvent &ev, int &frames_to_skip) {
+void PrintEvent(GGRS::GGRSEvent &ev) {
   
   std::cout << "Event: " << EventTypeToString(ev.event_type)
             << "\nAddress: " << ev.event_info.addr
             << "\nCount: " << ev.event_info.count
             << "\nTotal: " << ev.event_info.total
-            << "\nTimeout: " << ev.event_info.disconnect_timeout
-            << "\nFrames: " << ev.event_info.skip_frames << std::endl;
-  
-  if (ev.event_type == GGRS::GGRSEventType::WaitRecommendation) 
-    frames_to_skip += ev.event_info.skip_frames;
+            << "\nTimeout: " << ev.event_info.disconnect_timeout.ms << "ms"
+            << "\nFrames: " << ev.event_info.skip_frames << "\nPlayers:";
+  for (auto handle : ev.event_info.player_handles)
+    std::cout << " " << handle;
+  std::cout << std::endl;
+
+  if (ev.event_type == GGRS::GGRSEventType::DesyncDetected)
+    std::cout << "Desync at frame " << ev.event_info.frame << ": local "
+              << ev.event_info.local_checksum_lo << " remote "
+              << ev.event_info.remote_checksum_lo << std::endl;
 }
 
-std::uint32_t FetchLocalInput() {
-  std::uint32_t input = 0;
+std::uint8_t FetchLocalInput() {
+  std::uint8_t input = 0;
 
   if (IsKeyDown(KEY_W))
     input |= (1 << 0);
@@ -160,15 +169,21 @@ std::uint32_t FetchLocalInput() {
   return input;
 }
 
-void HandleRequests(ex::game::Game &game, ex::game::SaveState &save,
+void HandleRequests(ex::game::Game &game, GGRS::GGRSSession &sess,
                     rust::Vec<GGRS::GGRSFrameAction> requests) {
   for (auto action : requests) {
     switch (action.action_type) {
-    case GGRS::GGRSFrameActionType::SaveGameState:
-      ex::game::SaveGame(game,  save);
+    case GGRS::GGRSFrameActionType::SaveGameState: {
+      auto state = ex::game::SaveGame(game);
+      sess.save_game_state(
+          action.action_info.frame,
+          rust::Slice<const std::uint8_t>(state.data(), state.size()));
+      sess.submit_checksum(action.action_info.frame, 0,
+                           ex::game::Checksum(state));
       break;
+    }
     case GGRS::GGRSFrameActionType::LoadGameState:
-      ex::game::LoadGame(game, save);
+      ex::game::LoadGame(game, action.action_info.state);
       break;
     case GGRS::GGRSFrameActionType::AdvanceFrame:
       ex::game::AdvanceGame(game, action.action_info.inputs);
@@ -0,0 +1,47 @@
+#pragma once
+#include "rust/cxx.h"
+#include <cstdint>
+
+namespace GGRS {
+// defined by the generated bridge header after it included this file
+enum class GGRSPlayerType : std::uint8_t;
+struct GGRSDuration;
+
+// implement this and hand it to set_event_handler to get events as calls
+// instead of polling get_events. poll_remote_clients invokes the callbacks
+// for everything that happened since the last call. override only what you
+// need, the rest does nothing. an exception thrown by a callback is turned into
+// an error of poll_remote_clients after the remaining events were delivered.
+// callbacks run while the session is in the middle of a call (tick polls as
+// well), so they must not call any method of that session or clean it up.
+// remember what happened and act on it once the call returned.
+class GGRSEventHandler {
+public:
+  virtual ~GGRSEventHandler() = default;
+  virtual void on_synchronizing(rust::Str /*addr*/,
+                                rust::Slice<const std::uint32_t> /*player_handles*/,
+                                GGRSPlayerType /*player_type*/,
+                                std::uint32_t /*count*/, std::uint32_t /*total*/) {}
+  virtual void on_synchronized(rust::Str /*addr*/,
+                               rust::Slice<const std::uint32_t> /*player_handles*/,
+                               GGRSPlayerType /*player_type*/) {}
+  virtual void on_network_interrupted(rust::Str /*addr*/,
+                                      rust::Slice<const std::uint32_t> /*player_handles*/,
+                                      GGRSPlayerType /*player_type*/,
+                                      const GGRSDuration & /*disconnect_timeout*/) {}
+  virtual void on_network_resumed(rust::Str /*addr*/,
+                                  rust::Slice<const std::uint32_t> /*player_handles*/,
+                                  GGRSPlayerType /*player_type*/) {}
+  virtual void on_disconnected(rust::Str /*addr*/,
+                               rust::Slice<const std::uint32_t> /*player_handles*/,
+                               GGRSPlayerType /*player_type*/) {}
+  virtual void on_wait_recommendation(std::uint32_t /*skip_frames*/) {}
+  virtual void on_desync_detected(std::int32_t /*frame*/, rust::Str /*addr*/,
+                                  rust::Slice<const std::uint32_t> /*player_handles*/,
+                                  GGRSPlayerType /*player_type*/,
+                                  std::uint64_t /*local_checksum_hi*/,
+                                  std::uint64_t /*local_checksum_lo*/,
+                                  std::uint64_t /*remote_checksum_hi*/,
+                                  std::uint64_t /*remote_checksum_lo*/) {}
+};
+} // namespace GGRS
@@ -0,0 +1,22 @@
+#pragma once
+#include "rust/cxx.h"
+#include <cstdint>
+
+namespace GGRS {
+struct GGRSReceivedMessage;
+
+// implement this to run a session over your own transport and hand it to
+// start_p2p_session_with_socket or start_spectator_session_with_socket.
+// addresses are whatever strings your transport uses to tell peers apart,
+// they are passed through untouched. an exception thrown by send_to counts as a
+// lost packet, one thrown by receive_all_messages as nothing received.
+class GGRSNonBlockingSocket {
+public:
+  virtual ~GGRSNonBlockingSocket() = default;
+  // must not block. msg is an opaque ggrs packet that has to arrive as is.
+  virtual void send_to(rust::Slice<const std::uint8_t> msg,
+                       rust::Str addr) = 0;
+  // must not block. return every packet received since the last call.
+  virtual rust::Vec<GGRSReceivedMessage> receive_all_messages() = 0;
+};
+} // namespace GGRS
@@ -1,4 +1,7 @@
+#include "cpp_ggrs/include/ggrs_socket.h"
+#include "cpp_ggrs/include/ggrs_events.h"
 #include "rust/cxx.h"
+#include <memory>
 
 namespace rust {
 inline namespace cxxbridge1 {
@@ -19,6 +22,14 @@ template <typename T>
 struct operator_new<T, decltype(T::operator new(sizeof(T)))> {
   void *operator()(::std::size_t sz) { return T::operator new(sz); }
 };
+
+class Fail final {
+  ::rust::repr::PtrLen &throw$;
+public:
+  Fail(::rust::repr::PtrLen &throw$) noexcept : throw$(throw$) {}
+  void operator()(char const *) noexcept;
+  void operator()(std::string const &) noexcept;
+};
 } // namespace detail
 
 template <typename T>
@@ -47,51 +58,65 @@ public:
     return error;
   }
 };
+
+template <bool> struct deleter_if {
+  template <typename T> void operator()(T *) {}
+};
+
+template <> struct deleter_if<true> {
+  template <typename T> void operator()(T *ptr) { ptr->~T(); }
+};
 } // namespace
 } // namespace cxxbridge1
+
+namespace behavior {
+class missing {};
+missing trycatch(...);
+
+template <typename Try, typename Fail>
+static typename ::std::enable_if<
+    ::std::is_same<decltype(trycatch(::std::declval<Try>(), ::std::declval<Fail>())),
+                 missing>::value>::type
+trycatch(Try &&func, Fail &&fail) noexcept try {
+  func();
+} catch (::std::exception const &e) {
+  fail(e.what());
+}
+} // namespace behavior
 } // namespace rust
 
 namespace GGRS {
-  struct GGRSSessionInfo;
   struct GGRSPlayer;
   enum class GGRSPlayerType : ::std::uint8_t;
-  enum class GGRSSessionType : ::std::uint8_t;
+  enum class GGRSAddressPreference : ::std::uint8_t;
+  enum class GGRSPacingMode : ::std::uint8_t;
   enum class GGRSSessionState : ::std::uint8_t;
   enum class GGRSEventType : ::std::uint8_t;
   struct GGRSEventInfo;
   struct GGRSEvent;
   struct GGRSFrameResult;
+  struct GGRSFramePacing;
+  struct GGRSLocalInput;
+  struct GGRSTickResult;
+  struct GGRSChecksumMismatch;
   struct GGRSFrameAction;
   enum class GGRSFrameActionType : ::std::uint8_t;
   struct GGRSFrameActionInfo;
   struct GGRSInput;
   enum class GGRSInputStatus : ::std::uint8_t;
+  enum class GGRSErrorCode : ::std::uint8_t;
+  struct GGRSErrorInfo;
+  struct GGRSReceivedMessage;
+  struct GGRSNetworkConditions;
+  struct GGRSDuration;
   struct GGRSNetworkStats;
+  using GGRSNonBlockingSocket = ::GGRS::GGRSNonBlockingSocket;
+  using GGRSEventHandler = ::GGRS::GGRSEventHandler;
   struct GGRSSession;
+  struct GGRSSessionBuilder;
 }
 
 namespace GGRS {
-#ifndef CXXBRIDGE1_STRUCT_GGRS$GGRSSessionInfo
-#define CXXBRIDGE1_STRUCT_GGRS$GGRSSessionInfo
-struct GGRSSessionInfo final {
-  bool session_started;
-  ::GGRS::GGRSSessionType session_type;
-  ::std::uint32_t num_players;
-  ::std::uint32_t fps;
-  ::std::uint32_t input_delay;
-  ::std::uint32_t max_prediction_frames;
-  ::std::uint32_t max_frames_behind;
-  ::std::uint32_t catchup_speed;
-  ::std::uint32_t check_distance;
-  ::std::uint16_t local_port;
-  ::rust::String host;
-  bool sparse_saving;
-  ::rust::Vec<::GGRS::GGRSPlayer> players;
-
-  using IsRelocatable = ::std::true_type;
-};
-#endif // CXXBRIDGE1_STRUCT_GGRS$GGRSSessionInfo
-
 #ifndef CXXBRIDGE1_STRUCT_GGRS$GGRSPlayer
 #define CXXBRIDGE1_STRUCT_GGRS$GGRSPlayer
 struct GGRSPlayer final {
@@ -112,15 +137,21 @@ enum class GGRSPlayerType : ::std::uint8_t {
 };
 #endif // CXXBRIDGE1_ENUM_GGRS$GGRSPlayerType
 
-#ifndef CXXBRIDGE1_ENUM_GGRS$GGRSSessionType
-#define CXXBRIDGE1_ENUM_GGRS$GGRSSessionType
-enum class GGRSSessionType : ::std::uint8_t {
-  NotSet = 0,
-  Peer2Peer = 1,
-  Spectator = 2,
-  Synctest = 3,
+#ifndef CXXBRIDGE1_ENUM_GGRS$GGRSAddressPreference
+#define CXXBRIDGE1_ENUM_GGRS$GGRSAddressPreference
+enum class GGRSAddressPreference : ::std::uint8_t {
+  IPv4 = 0,
+  IPv6 = 1,
+};
+#endif // CXXBRIDGE1_ENUM_GGRS$GGRSAddressPreference
+
+#ifndef CXXBRIDGE1_ENUM_GGRS$GGRSPacingMode
+#define CXXBRIDGE1_ENUM_GGRS$GGRSPacingMode
+enum class GGRSPacingMode : ::std::uint8_t {
+  FixedStep = 0,
+  VariableRefresh = 1,
 };
-#endif // CXXBRIDGE1_ENUM_GGRS$GGRSSessionType
+#endif // CXXBRIDGE1_ENUM_GGRS$GGRSPacingMode
 
 #ifndef CXXBRIDGE1_ENUM_GGRS$GGRSSessionState
 #define CXXBRIDGE1_ENUM_GGRS$GGRSSessionState
@@ -140,17 +171,35 @@ enum class GGRSEventType : ::std::uint8_t {
   NetworkInterrupted = 4,
   NetworkResumed = 5,
   WaitRecommendation = 6,
+  DesyncDetected = 7,
 };
 #endif // CXXBRIDGE1_ENUM_GGRS$GGRSEventType
 
+#ifndef CXXBRIDGE1_STRUCT_GGRS$GGRSDuration
+#define CXXBRIDGE1_STRUCT_GGRS$GGRSDuration
+struct GGRSDuration final {
+  ::std::uint64_t ms;
+  ::std::uint64_t us;
+
+  using IsRelocatable = ::std::true_type;
+};
+#endif // CXXBRIDGE1_STRUCT_GGRS$GGRSDuration
+
 #ifndef CXXBRIDGE1_STRUCT_GGRS$GGRSEventInfo
 #define CXXBRIDGE1_STRUCT_GGRS$GGRSEventInfo
 struct GGRSEventInfo final {
   ::rust::String addr;
   ::std::uint32_t total;
   ::std::uint32_t count;
-  ::std::uint64_t disconnect_timeout;
+  ::GGRS::GGRSDuration disconnect_timeout;
   ::std::uint32_t skip_frames;
+  ::std::int32_t frame;
+  ::std::uint64_t local_checksum_hi;
+  ::std::uint64_t local_checksum_lo;
+  ::std::uint64_t remote_checksum_hi;
+  ::std::uint64_t remote_checksum_lo;
+  ::rust::Vec<::std::uint32_t> player_handles;
+  ::GGRS::GGRSPlayerType player_type;
 
   using IsRelocatable = ::std::true_type;
 };
@@ -171,16 +220,68 @@ struct GGRSEvent final {
 struct GGRSFrameResult final {
   bool skip_frame;
   ::rust::Vec<::GGRS::GGRSFrameAction> actions;
+  ::rust::Vec<::GGRS::GGRSChecksumMismatch> checksum_mismatches;
 
   using IsRelocatable = ::std::true_type;
 };
 #endif // CXXBRIDGE1_STRUCT_GGRS$GGRSFrameResult
 
+#ifndef CXXBRIDGE1_STRUCT_GGRS$GGRSFramePacing
+#define CXXBRIDGE1_STRUCT_GGRS$GGRSFramePacing
+struct GGRSFramePacing final {
+  bool skip_frame;
+  ::GGRS::GGRSDuration sleep;
+  ::GGRS::GGRSDuration frame_duration;
+  ::std::int32_t frames_ahead;
+
+  using IsRelocatable = ::std::true_type;
+};
+#endif // CXXBRIDGE1_STRUCT_GGRS$GGRSFramePacing
+
+#ifndef CXXBRIDGE1_STRUCT_GGRS$GGRSLocalInput
+#define CXXBRIDGE1_STRUCT_GGRS$GGRSLocalInput
+struct GGRSLocalInput final {
+  ::std::uint32_t player_handle;
+  ::rust::Vec<::std::uint8_t> input;
+
+  using IsRelocatable = ::std::true_type;
+};
+#endif // CXXBRIDGE1_STRUCT_GGRS$GGRSLocalInput
+
+#ifndef CXXBRIDGE1_STRUCT_GGRS$GGRSTickResult
+#define CXXBRIDGE1_STRUCT_GGRS$GGRSTickResult
+struct GGRSTickResult final {
+  ::GGRS::GGRSSessionState state;
+  ::rust::Vec<::GGRS::GGRSEvent> events;
+  bool skip_frame;
+  ::rust::Vec<::GGRS::GGRSFrameAction> actions;
+  ::rust::Vec<::GGRS::GGRSChecksumMismatch> checksum_mismatches;
+
+  using IsRelocatable = ::std::true_type;
+};
+#endif // CXXBRIDGE1_STRUCT_GGRS$GGRSTickResult
+
+#ifndef CXXBRIDGE1_STRUCT_GGRS$GGRSChecksumMismatch
+#define CXXBRIDGE1_STRUCT_GGRS$GGRSChecksumMismatch
+struct GGRSChecksumMismatch final {
+  ::std::int32_t frame;
+  bool has_original_checksum;
+  ::std::uint64_t original_checksum_hi;
+  ::std::uint64_t original_checksum_lo;
+  bool has_resimulated_checksum;
+  ::std::uint64_t resimulated_checksum_hi;
+  ::std::uint64_t resimulated_checksum_lo;
+
+  using IsRelocatable = ::std::true_type;
+};
+#endif // CXXBRIDGE1_STRUCT_GGRS$GGRSChecksumMismatch
+
 #ifndef CXXBRIDGE1_STRUCT_GGRS$GGRSFrameActionInfo
 #define CXXBRIDGE1_STRUCT_GGRS$GGRSFrameActionInfo
 struct GGRSFrameActionInfo final {
   ::std::int32_t frame;
   ::rust::Vec<::GGRS::GGRSInput> inputs;
+  ::rust::Vec<::std::uint8_t> state;
 
   using IsRelocatable = ::std::true_type;
 };
@@ -208,7 +309,7 @@ enum class GGRSFrameActionType : ::std::uint8_t {
 #ifndef CXXBRIDGE1_STRUCT_GGRS$GGRSInput
 #define CXXBRIDGE1_STRUCT_GGRS$GGRSInput
 struct GGRSInput final {
-  ::std::uint32_t input;
+  ::rust::Vec<::std::uint8_t> input;
   ::GGRS::GGRSInputStatus status;
 
   using IsRelocatable = ::std::true_type;
@@ -224,11 +325,75 @@ enum class GGRSInputStatus : ::std::uint8_t {
 };
 #endif // CXXBRIDGE1_ENUM_GGRS$GGRSInputStatus
 
+#ifndef CXXBRIDGE1_ENUM_GGRS$GGRSErrorCode
+#define CXXBRIDGE1_ENUM_GGRS$GGRSErrorCode
+enum class GGRSErrorCode : ::std::uint8_t {
+  Ok = 0,
+  PredictionThreshold = 1,
+  InvalidRequest = 2,
+  MismatchedChecksum = 3,
+  NotSynchronized = 4,
+  SpectatorTooFarBehind = 5,
+  SocketCreationFailed = 6,
+  PlayerDisconnected = 7,
+  DecodingError = 8,
+  BindFailed = 9,
+  AddressParseFailed = 10,
+  InvalidConfiguration = 11,
+  InvalidInputSize = 12,
+  InvalidSocket = 13,
+  NoPendingSave = 14,
+  UnsupportedOperation = 15,
+  SessionClosed = 16,
+  Panic = 17,
+  SessionPoisoned = 18,
+  ReplayIoFailed = 19,
+  InvalidReplay = 20,
+  ReplayFinished = 21,
+  DesyncReportFailed = 22,
+  EventHandlerFailed = 23,
+};
+#endif // CXXBRIDGE1_ENUM_GGRS$GGRSErrorCode
+
+#ifndef CXXBRIDGE1_STRUCT_GGRS$GGRSErrorInfo
+#define CXXBRIDGE1_STRUCT_GGRS$GGRSErrorInfo
+struct GGRSErrorInfo final {
+  ::GGRS::GGRSErrorCode code;
+  ::rust::String message;
+
+  using IsRelocatable = ::std::true_type;
+};
+#endif // CXXBRIDGE1_STRUCT_GGRS$GGRSErrorInfo
+
+#ifndef CXXBRIDGE1_STRUCT_GGRS$GGRSReceivedMessage
+#define CXXBRIDGE1_STRUCT_GGRS$GGRSReceivedMessage
+struct GGRSReceivedMessage final {
+  ::rust::String addr;
+  ::rust::Vec<::std::uint8_t> msg;
+
+  using IsRelocatable = ::std::true_type;
+};
+#endif // CXXBRIDGE1_STRUCT_GGRS$GGRSReceivedMessage
+
+#ifndef CXXBRIDGE1_STRUCT_GGRS$GGRSNetworkConditions
+#define CXXBRIDGE1_STRUCT_GGRS$GGRSNetworkConditions
+struct GGRSNetworkConditions final {
+  ::std::uint32_t latency_ms;
+  ::std::uint32_t jitter_ms;
+  float packet_loss;
+  float duplication;
+  float reordering;
+  ::std::uint64_t seed;
+
+  using IsRelocatable = ::std::true_type;
+};
+#endif // CXXBRIDGE1_STRUCT_GGRS$GGRSNetworkConditions
+
 #ifndef CXXBRIDGE1_STRUCT_GGRS$GGRSNetworkStats
 #define CXXBRIDGE1_STRUCT_GGRS$GGRSNetworkStats
 struct GGRSNetworkStats final {
   ::std::uint32_t send_queue_len;
-  ::std::uint64_t ping;
+  ::GGRS::GGRSDuration ping;
   ::std::uint64_t kbps_sent;
   ::std::int32_t local_frames_behind;
   ::std::int32_t remote_frames_behind;
@@ -240,6 +405,43 @@ struct GGRSNetworkStats final {
 #ifndef CXXBRIDGE1_STRUCT_GGRS$GGRSSession
 #define CXXBRIDGE1_STRUCT_GGRS$GGRSSession
 struct GGRSSession final : public ::rust::Opaque {
+  bool poll_remote_clients();
+  bool add_local_input(::std::uint32_t player_handle, ::rust::Slice<::std::uint8_t const> input);
+  bool add_local_inputs(::rust::Slice<::GGRS::GGRSLocalInput const> local_inputs);
+  ::GGRS::GGRSSessionState get_current_state();
+  ::rust::Vec<::GGRS::GGRSEvent> get_events();
+  ::GGRS::GGRSFrameResult advance_frame();
+  ::GGRS::GGRSTickResult tick(::rust::Slice<::GGRS::GGRSLocalInput const> local_inputs);
+  ::GGRS::GGRSFramePacing frame_pacing();
+  bool save_game_state(::std::int32_t frame, ::rust::Slice<::std::uint8_t const> state);
+  bool submit_checksum(::std::int32_t frame, ::std::uint64_t checksum_hi, ::std::uint64_t checksum_lo);
+  ::std::int32_t get_frames_ahead();
+  bool clean_session() noexcept;
+  ::GGRS::GGRSNetworkStats network_stats(::std::uint32_t player_handle);
+  bool disconnect_player(::std::uint32_t player_handle);
+  bool start_recording(::rust::Str path);
+  bool stop_recording();
+  ::rust::String desync_report_path();
+  ::rust::String local_address();
+  bool set_event_handler(::std::unique_ptr<::GGRS::GGRSEventHandler> handler);
+  ::GGRS::GGRSErrorInfo try_poll_remote_clients() noexcept;
+  ::GGRS::GGRSErrorInfo try_add_local_input(::std::uint32_t player_handle, ::rust::Slice<::std::uint8_t const> input) noexcept;
+  ::GGRS::GGRSErrorInfo try_add_local_inputs(::rust::Slice<::GGRS::GGRSLocalInput const> local_inputs) noexcept;
+  ::GGRS::GGRSErrorInfo try_get_current_state(::GGRS::GGRSSessionState &state) noexcept;
+  ::GGRS::GGRSErrorInfo try_get_events(::rust::Vec<::GGRS::GGRSEvent> &events) noexcept;
+  ::GGRS::GGRSErrorInfo try_advance_frame(::GGRS::GGRSFrameResult &result) noexcept;
+  ::GGRS::GGRSErrorInfo try_frame_pacing(::GGRS::GGRSFramePacing &pacing) noexcept;
+  ::GGRS::GGRSErrorInfo try_tick(::rust::Slice<::GGRS::GGRSLocalInput const> local_inputs, ::GGRS::GGRSTickResult &result) noexcept;
+  ::GGRS::GGRSErrorInfo try_save_game_state(::std::int32_t frame, ::rust::Slice<::std::uint8_t const> state) noexcept;
+  ::GGRS::GGRSErrorInfo try_submit_checksum(::std::int32_t frame, ::std::uint64_t checksum_hi, ::std::uint64_t checksum_lo) noexcept;
+  ::GGRS::GGRSErrorInfo try_get_frames_ahead(::std::int32_t &frames_ahead) noexcept;
+  ::GGRS::GGRSErrorInfo try_network_stats(::std::uint32_t player_handle, ::GGRS::GGRSNetworkStats &stats) noexcept;
+  ::GGRS::GGRSErrorInfo try_disconnect_player(::std::uint32_t player_handle) noexcept;
+  ::GGRS::GGRSErrorInfo try_start_recording(::rust::Str path) noexcept;
+  ::GGRS::GGRSErrorInfo try_stop_recording() noexcept;
+  ::GGRS::GGRSErrorInfo try_desync_report_path(::rust::String &path) noexcept;
+  ::GGRS::GGRSErrorInfo try_local_address(::rust::String &addr) noexcept;
+  ::GGRS::GGRSErrorInfo try_set_event_handler(::std::unique_ptr<::GGRS::GGRSEventHandler> handler) noexcept;
   ~GGRSSession() = delete;
 
 private:
@@ -251,43 +453,368 @@ private:
 };
 #endif // CXXBRIDGE1_STRUCT_GGRS$GGRSSession
 
+#ifndef CXXBRIDGE1_STRUCT_GGRS$GGRSSessionBuilder
+#define CXXBRIDGE1_STRUCT_GGRS$GGRSSessionBuilder
+struct GGRSSessionBuilder final : public ::rust::Opaque {
+  ::GGRS::GGRSSessionBuilder &with_num_players(::std::uint32_t num_players);
+  ::GGRS::GGRSSessionBuilder &with_input_size(::std::uint32_t bytes);
+  ::GGRS::GGRSSessionBuilder &with_fps(::std::uint32_t fps);
+  ::GGRS::GGRSSessionBuilder &with_input_delay(::std::uint32_t delay);
+  ::GGRS::GGRSSessionBuilder &with_max_prediction_window(::std::uint32_t window);
+  ::GGRS::GGRSSessionBuilder &with_max_frames_behind(::std::uint32_t max_frames_behind);
+  ::GGRS::GGRSSessionBuilder &with_catchup_speed(::std::uint32_t catchup_speed);
+  ::GGRS::GGRSSessionBuilder &with_check_distance(::std::uint32_t check_distance);
+  ::GGRS::GGRSSessionBuilder &with_disconnect_timeout(::std::uint64_t timeout_ms);
+  ::GGRS::GGRSSessionBuilder &with_disconnect_notify_delay(::std::uint64_t notify_delay_ms);
+  ::GGRS::GGRSSessionBuilder &with_network_conditions(::GGRS::GGRSNetworkConditions conditions);
+  ::GGRS::GGRSSessionBuilder &with_sparse_saving_mode(bool enable);
+  ::GGRS::GGRSSessionBuilder &with_desync_detection_interval(::std::uint32_t interval);
+  ::GGRS::GGRSSessionBuilder &with_desync_report_dir(::rust::Str dir);
+  ::GGRS::GGRSSessionBuilder &with_desync_report_frames(::std::uint32_t frames);
+  ::GGRS::GGRSSessionBuilder &with_address_preference(::GGRS::GGRSAddressPreference preference);
+  ::GGRS::GGRSSessionBuilder &with_pacing_mode(::GGRS::GGRSPacingMode mode);
+  ::GGRS::GGRSSessionBuilder &with_bind_address(::rust::Str ip);
+  ::GGRS::GGRSSessionBuilder &add_player(::GGRS::GGRSPlayer player);
+  ::rust::Box<::GGRS::GGRSSession> start_p2p_session(::std::uint16_t local_port);
+  ::rust::Box<::GGRS::GGRSSession> start_spectator_session(::std::uint16_t local_port, ::rust::Str host);
+  ::rust::Box<::GGRS::GGRSSession> start_synctest_session();
+  ::rust::Box<::GGRS::GGRSSession> start_p2p_session_with_socket(::std::unique_ptr<::GGRS::GGRSNonBlockingSocket> socket);
+  ::rust::Box<::GGRS::GGRSSession> start_spectator_session_with_socket(::rust::Str host, ::std::unique_ptr<::GGRS::GGRSNonBlockingSocket> socket);
+  ::rust::Box<::GGRS::GGRSSession> start_p2p_session_on_loopback(::rust::Str local_name);
+  ::rust::Box<::GGRS::GGRSSession> start_spectator_session_on_loopback(::rust::Str local_name, ::rust::Str host);
+  ::GGRS::GGRSErrorInfo try_with_num_players(::std::uint32_t num_players) noexcept;
+  ::GGRS::GGRSErrorInfo try_with_input_size(::std::uint32_t bytes) noexcept;
+  ::GGRS::GGRSErrorInfo try_with_fps(::std::uint32_t fps) noexcept;
+  ::GGRS::GGRSErrorInfo try_with_input_delay(::std::uint32_t delay) noexcept;
+  ::GGRS::GGRSErrorInfo try_with_max_prediction_window(::std::uint32_t window) noexcept;
+  ::GGRS::GGRSErrorInfo try_with_max_frames_behind(::std::uint32_t max_frames_behind) noexcept;
+  ::GGRS::GGRSErrorInfo try_with_catchup_speed(::std::uint32_t catchup_speed) noexcept;
+  ::GGRS::GGRSErrorInfo try_with_check_distance(::std::uint32_t check_distance) noexcept;
+  ::GGRS::GGRSErrorInfo try_with_disconnect_timeout(::std::uint64_t timeout_ms) noexcept;
+  ::GGRS::GGRSErrorInfo try_with_disconnect_notify_delay(::std::uint64_t notify_delay_ms) noexcept;
+  ::GGRS::GGRSErrorInfo try_with_network_conditions(::GGRS::GGRSNetworkConditions conditions) noexcept;
+  ::GGRS::GGRSErrorInfo try_with_sparse_saving_mode(bool enable) noexcept;
+  ::GGRS::GGRSErrorInfo try_with_desync_detection_interval(::std::uint32_t interval) noexcept;
+  ::GGRS::GGRSErrorInfo try_with_desync_report_dir(::rust::Str dir) noexcept;
+  ::GGRS::GGRSErrorInfo try_with_desync_report_frames(::std::uint32_t frames) noexcept;
+  ::GGRS::GGRSErrorInfo try_with_address_preference(::GGRS::GGRSAddressPreference preference) noexcept;
+  ::GGRS::GGRSErrorInfo try_with_bind_address(::rust::Str ip) noexcept;
+  ::GGRS::GGRSErrorInfo try_with_pacing_mode(::GGRS::GGRSPacingMode mode) noexcept;
+  ::GGRS::GGRSErrorInfo try_add_player(::GGRS::GGRSPlayer player) noexcept;
+  ::rust::Box<::GGRS::GGRSSession> try_start_p2p_session(::std::uint16_t local_port, ::GGRS::GGRSErrorInfo &error) noexcept;
+  ::rust::Box<::GGRS::GGRSSession> try_start_spectator_session(::std::uint16_t local_port, ::rust::Str host, ::GGRS::GGRSErrorInfo &error) noexcept;
+  ::rust::Box<::GGRS::GGRSSession> try_start_synctest_session(::GGRS::GGRSErrorInfo &error) noexcept;
+  ::rust::Box<::GGRS::GGRSSession> try_start_p2p_session_with_socket(::std::unique_ptr<::GGRS::GGRSNonBlockingSocket> socket, ::GGRS::GGRSErrorInfo &error) noexcept;
+  ::rust::Box<::GGRS::GGRSSession> try_start_spectator_session_with_socket(::rust::Str host, ::std::unique_ptr<::GGRS::GGRSNonBlockingSocket> socket, ::GGRS::GGRSErrorInfo &error) noexcept;
+  ::rust::Box<::GGRS::GGRSSession> try_start_p2p_session_on_loopback(::rust::Str local_name, ::GGRS::GGRSErrorInfo &error) noexcept;
+  ::rust::Box<::GGRS::GGRSSession> try_start_spectator_session_on_loopback(::rust::Str local_name, ::rust::Str host, ::GGRS::GGRSErrorInfo &error) noexcept;
+  ~GGRSSessionBuilder() = delete;
+
+private:
+  friend ::rust::layout;
+  struct layout {
+    static ::std::size_t size() noexcept;
+    static ::std::size_t align() noexcept;
+  };
+};
+#endif // CXXBRIDGE1_STRUCT_GGRS$GGRSSessionBuilder
+
 extern "C" {
+::rust::repr::PtrLen GGRS$cxxbridge1$GGRSNonBlockingSocket$send_to(::GGRS::GGRSNonBlockingSocket &self, ::rust::Slice<::std::uint8_t const> msg, ::rust::Str addr) noexcept {
+  void (::GGRS::GGRSNonBlockingSocket::*send_to$)(::rust::Slice<::std::uint8_t const>, ::rust::Str) = &::GGRS::GGRSNonBlockingSocket::send_to;
+  ::rust::repr::PtrLen throw$;
+  ::rust::behavior::trycatch(
+      [&] {
+        (self.*send_to$)(msg, addr);
+        throw$.ptr = nullptr;
+      },
+      ::rust::detail::Fail(throw$));
+  return throw$;
+}
+
+::rust::repr::PtrLen GGRS$cxxbridge1$GGRSNonBlockingSocket$receive_all_messages(::GGRS::GGRSNonBlockingSocket &self, ::rust::Vec<::GGRS::GGRSReceivedMessage> *return$) noexcept {
+  ::rust::Vec<::GGRS::GGRSReceivedMessage> (::GGRS::GGRSNonBlockingSocket::*receive_all_messages$)() = &::GGRS::GGRSNonBlockingSocket::receive_all_messages;
+  ::rust::repr::PtrLen throw$;
+  ::rust::behavior::trycatch(
+      [&] {
+        new (return$) ::rust::Vec<::GGRS::GGRSReceivedMessage>((self.*receive_all_messages$)());
+        throw$.ptr = nullptr;
+      },
+      ::rust::detail::Fail(throw$));
+  return throw$;
+}
+
+::rust::repr::PtrLen GGRS$cxxbridge1$GGRSEventHandler$on_synchronizing(::GGRS::GGRSEventHandler &self, ::rust::Str addr, ::rust::Slice<::std::uint32_t const> player_handles, ::GGRS::GGRSPlayerType player_type, ::std::uint32_t count, ::std::uint32_t total) noexcept {
+  void (::GGRS::GGRSEventHandler::*on_synchronizing$)(::rust::Str, ::rust::Slice<::std::uint32_t const>, ::GGRS::GGRSPlayerType, ::std::uint32_t, ::std::uint32_t) = &::GGRS::GGRSEventHandler::on_synchronizing;
+  ::rust::repr::PtrLen throw$;
+  ::rust::behavior::trycatch(
+      [&] {
+        (self.*on_synchronizing$)(addr, player_handles, player_type, count, total);
+        throw$.ptr = nullptr;
+      },
+      ::rust::detail::Fail(throw$));
+  return throw$;
+}
+
+::rust::repr::PtrLen GGRS$cxxbridge1$GGRSEventHandler$on_synchronized(::GGRS::GGRSEventHandler &self, ::rust::Str addr, ::rust::Slice<::std::uint32_t const> player_handles, ::GGRS::GGRSPlayerType player_type) noexcept {
+  void (::GGRS::GGRSEventHandler::*on_synchronized$)(::rust::Str, ::rust::Slice<::std::uint32_t const>, ::GGRS::GGRSPlayerType) = &::GGRS::GGRSEventHandler::on_synchronized;
+  ::rust::repr::PtrLen throw$;
+  ::rust::behavior::trycatch(
+      [&] {
+        (self.*on_synchronized$)(addr, player_handles, player_type);
+        throw$.ptr = nullptr;
+      },
+      ::rust::detail::Fail(throw$));
+  return throw$;
+}
+
+::rust::repr::PtrLen GGRS$cxxbridge1$GGRSEventHandler$on_network_interrupted(::GGRS::GGRSEventHandler &self, ::rust::Str addr, ::rust::Slice<::std::uint32_t const> player_handles, ::GGRS::GGRSPlayerType player_type, ::GGRS::GGRSDuration const &disconnect_timeout) noexcept {
+  void (::GGRS::GGRSEventHandler::*on_network_interrupted$)(::rust::Str, ::rust::Slice<::std::uint32_t const>, ::GGRS::GGRSPlayerType, ::GGRS::GGRSDuration const &) = &::GGRS::GGRSEventHandler::on_network_interrupted;
+  ::rust::repr::PtrLen throw$;
+  ::rust::behavior::trycatch(
+      [&] {
+        (self.*on_network_interrupted$)(addr, player_handles, player_type, disconnect_timeout);
+        throw$.ptr = nullptr;
+      },
+      ::rust::detail::Fail(throw$));
+  return throw$;
+}
+
+::rust::repr::PtrLen GGRS$cxxbridge1$GGRSEventHandler$on_network_resumed(::GGRS::GGRSEventHandler &self, ::rust::Str addr, ::rust::Slice<::std::uint32_t const> player_handles, ::GGRS::GGRSPlayerType player_type) noexcept {
+  void (::GGRS::GGRSEventHandler::*on_network_resumed$)(::rust::Str, ::rust::Slice<::std::uint32_t const>, ::GGRS::GGRSPlayerType) = &::GGRS::GGRSEventHandler::on_network_resumed;
+  ::rust::repr::PtrLen throw$;
+  ::rust::behavior::trycatch(
+      [&] {
+        (self.*on_network_resumed$)(addr, player_handles, player_type);
+        throw$.ptr = nullptr;
+      },
+      ::rust::detail::Fail(throw$));
+  return throw$;
+}
+
+::rust::repr::PtrLen GGRS$cxxbridge1$GGRSEventHandler$on_disconnected(::GGRS::GGRSEventHandler &self, ::rust::Str addr, ::rust::Slice<::std::uint32_t const> player_handles, ::GGRS::GGRSPlayerType player_type) noexcept {
+  void (::GGRS::GGRSEventHandler::*on_disconnected$)(::rust::Str, ::rust::Slice<::std::uint32_t const>, ::GGRS::GGRSPlayerType) = &::GGRS::GGRSEventHandler::on_disconnected;
+  ::rust::repr::PtrLen throw$;
+  ::rust::behavior::trycatch(
+      [&] {
+        (self.*on_disconnected$)(addr, player_handles, player_type);
+        throw$.ptr = nullptr;
+      },
+      ::rust::detail::Fail(throw$));
+  return throw$;
+}
+
+::rust::repr::PtrLen GGRS$cxxbridge1$GGRSEventHandler$on_wait_recommendation(::GGRS::GGRSEventHandler &self, ::std::uint32_t skip_frames) noexcept {
+  void (::GGRS::GGRSEventHandler::*on_wait_recommendation$)(::std::uint32_t) = &::GGRS::GGRSEventHandler::on_wait_recommendation;
+  ::rust::repr::PtrLen throw$;
+  ::rust::behavior::trycatch(
+      [&] {
+        (self.*on_wait_recommendation$)(skip_frames);
+        throw$.ptr = nullptr;
+      },
+      ::rust::detail::Fail(throw$));
+  return throw$;
+}
+
+::rust::repr::PtrLen GGRS$cxxbridge1$GGRSEventHandler$on_desync_detected(::GGRS::GGRSEventHandler &self, ::std::int32_t frame, ::rust::Str addr, ::rust::Slice<::std::uint32_t const> player_handles, ::GGRS::GGRSPlayerType player_type, ::std::uint64_t local_checksum_hi, ::std::uint64_t local_checksum_lo, ::std::uint64_t remote_checksum_hi, ::std::uint64_t remote_checksum_lo) noexcept {
+  void (::GGRS::GGRSEventHandler::*on_desync_detected$)(::std::int32_t, ::rust::Str, ::rust::Slice<::std::uint32_t const>, ::GGRS::GGRSPlayerType, ::std::uint64_t, ::std::uint64_t, ::std::uint64_t, ::std::uint64_t) = &::GGRS::GGRSEventHandler::on_desync_detected;
+  ::rust::repr::PtrLen throw$;
+  ::rust::behavior::trycatch(
+      [&] {
+        (self.*on_desync_detected$)(frame, addr, player_handles, player_type, local_checksum_hi, local_checksum_lo, remote_checksum_hi, remote_checksum_lo);
+        throw$.ptr = nullptr;
+      },
+      ::rust::detail::Fail(throw$));
+  return throw$;
+}
 ::std::size_t GGRS$cxxbridge1$GGRSSession$operator$sizeof() noexcept;
 ::std::size_t GGRS$cxxbridge1$GGRSSession$operator$alignof() noexcept;
+::std::size_t GGRS$cxxbridge1$GGRSSessionBuilder$operator$sizeof() noexcept;
+::std::size_t GGRS$cxxbridge1$GGRSSessionBuilder$operator$alignof() noexcept;
+
+::GGRS::GGRSSessionBuilder *GGRS$cxxbridge1$new_session_builder() noexcept;
+
+::rust::repr::PtrLen GGRS$cxxbridge1$GGRSSessionBuilder$with_num_players(::GGRS::GGRSSessionBuilder &self, ::std::uint32_t num_players, ::GGRS::GGRSSessionBuilder **return$) noexcept;
+
+::rust::repr::PtrLen GGRS$cxxbridge1$GGRSSessionBuilder$with_input_size(::GGRS::GGRSSessionBuilder &self, ::std::uint32_t bytes, ::GGRS::GGRSSessionBuilder **return$) noexcept;
+
+::rust::repr::PtrLen GGRS$cxxbridge1$GGRSSessionBuilder$with_fps(::GGRS::GGRSSessionBuilder &self, ::std::uint32_t fps, ::GGRS::GGRSSessionBuilder **return$) noexcept;
+
+::rust::repr::PtrLen GGRS$cxxbridge1$GGRSSessionBuilder$with_input_delay(::GGRS::GGRSSessionBuilder &self, ::std::uint32_t delay, ::GGRS::GGRSSessionBuilder **return$) noexcept;
+
+::rust::repr::PtrLen GGRS$cxxbridge1$GGRSSessionBuilder$with_max_prediction_window(::GGRS::GGRSSessionBuilder &self, ::std::uint32_t window, ::GGRS::GGRSSessionBuilder **return$) noexcept;
+
+::rust::repr::PtrLen GGRS$cxxbridge1$GGRSSessionBuilder$with_max_frames_behind(::GGRS::GGRSSessionBuilder &self, ::std::uint32_t max_frames_behind, ::GGRS::GGRSSessionBuilder **return$) noexcept;
+
+::rust::repr::PtrLen GGRS$cxxbridge1$GGRSSessionBuilder$with_catchup_speed(::GGRS::GGRSSessionBuilder &self, ::std::uint32_t catchup_speed, ::GGRS::GGRSSessionBuilder **return$) noexcept;
+
+::rust::repr::PtrLen GGRS$cxxbridge1$GGRSSessionBuilder$with_check_distance(::GGRS::GGRSSessionBuilder &self, ::std::uint32_t check_distance, ::GGRS::GGRSSessionBuilder **return$) noexcept;
+
+::rust::repr::PtrLen GGRS$cxxbridge1$GGRSSessionBuilder$with_disconnect_timeout(::GGRS::GGRSSessionBuilder &self, ::std::uint64_t timeout_ms, ::GGRS::GGRSSessionBuilder **return$) noexcept;
+
+::rust::repr::PtrLen GGRS$cxxbridge1$GGRSSessionBuilder$with_disconnect_notify_delay(::GGRS::GGRSSessionBuilder &self, ::std::uint64_t notify_delay_ms, ::GGRS::GGRSSessionBuilder **return$) noexcept;
+
+::rust::repr::PtrLen GGRS$cxxbridge1$GGRSSessionBuilder$with_network_conditions(::GGRS::GGRSSessionBuilder &self, ::GGRS::GGRSNetworkConditions conditions, ::GGRS::GGRSSessionBuilder **return$) noexcept;
+
+::rust::repr::PtrLen GGRS$cxxbridge1$GGRSSessionBuilder$with_sparse_saving_mode(::GGRS::GGRSSessionBuilder &self, bool enable, ::GGRS::GGRSSessionBuilder **return$) noexcept;
+
+::rust::repr::PtrLen GGRS$cxxbridge1$GGRSSessionBuilder$with_desync_detection_interval(::GGRS::GGRSSessionBuilder &self, ::std::uint32_t interval, ::GGRS::GGRSSessionBuilder **return$) noexcept;
+
+::rust::repr::PtrLen GGRS$cxxbridge1$GGRSSessionBuilder$with_desync_report_dir(::GGRS::GGRSSessionBuilder &self, ::rust::Str dir, ::GGRS::GGRSSessionBuilder **return$) noexcept;
+
+::rust::repr::PtrLen GGRS$cxxbridge1$GGRSSessionBuilder$with_desync_report_frames(::GGRS::GGRSSessionBuilder &self, ::std::uint32_t frames, ::GGRS::GGRSSessionBuilder **return$) noexcept;
+
+::rust::repr::PtrLen GGRS$cxxbridge1$GGRSSessionBuilder$with_address_preference(::GGRS::GGRSSessionBuilder &self, ::GGRS::GGRSAddressPreference preference, ::GGRS::GGRSSessionBuilder **return$) noexcept;
+
+::rust::repr::PtrLen GGRS$cxxbridge1$GGRSSessionBuilder$with_pacing_mode(::GGRS::GGRSSessionBuilder &self, ::GGRS::GGRSPacingMode mode, ::GGRS::GGRSSessionBuilder **return$) noexcept;
+
+::rust::repr::PtrLen GGRS$cxxbridge1$GGRSSessionBuilder$with_bind_address(::GGRS::GGRSSessionBuilder &self, ::rust::Str ip, ::GGRS::GGRSSessionBuilder **return$) noexcept;
+
+::rust::repr::PtrLen GGRS$cxxbridge1$GGRSSessionBuilder$add_player(::GGRS::GGRSSessionBuilder &self, ::GGRS::GGRSPlayer *player, ::GGRS::GGRSSessionBuilder **return$) noexcept;
+
+::rust::repr::PtrLen GGRS$cxxbridge1$GGRSSessionBuilder$start_p2p_session(::GGRS::GGRSSessionBuilder &self, ::std::uint16_t local_port, ::rust::Box<::GGRS::GGRSSession> *return$) noexcept;
+
+::rust::repr::PtrLen GGRS$cxxbridge1$GGRSSessionBuilder$start_spectator_session(::GGRS::GGRSSessionBuilder &self, ::std::uint16_t local_port, ::rust::Str host, ::rust::Box<::GGRS::GGRSSession> *return$) noexcept;
+
+::rust::repr::PtrLen GGRS$cxxbridge1$GGRSSessionBuilder$start_synctest_session(::GGRS::GGRSSessionBuilder &self, ::rust::Box<::GGRS::GGRSSession> *return$) noexcept;
+
+::rust::repr::PtrLen GGRS$cxxbridge1$GGRSSessionBuilder$start_p2p_session_with_socket(::GGRS::GGRSSessionBuilder &self, ::GGRS::GGRSNonBlockingSocket *socket, ::rust::Box<::GGRS::GGRSSession> *return$) noexcept;
+
+::rust::repr::PtrLen GGRS$cxxbridge1$GGRSSessionBuilder$start_spectator_session_with_socket(::GGRS::GGRSSessionBuilder &self, ::rust::Str host, ::GGRS::GGRSNonBlockingSocket *socket, ::rust::Box<::GGRS::GGRSSession> *return$) noexcept;
+
+::rust::repr::PtrLen GGRS$cxxbridge1$GGRSSessionBuilder$start_p2p_session_on_loopback(::GGRS::GGRSSessionBuilder &self, ::rust::Str local_name, ::rust::Box<::GGRS::GGRSSession> *return$) noexcept;
+
+::rust::repr::PtrLen GGRS$cxxbridge1$GGRSSessionBuilder$start_spectator_session_on_loopback(::GGRS::GGRSSessionBuilder &self, ::rust::Str local_name, ::rust::Str host, ::rust::Box<::GGRS::GGRSSession> *return$) noexcept;
+
+::rust::repr::PtrLen GGRS$cxxbridge1$GGRSSession$poll_remote_clients(::GGRS::GGRSSession &self, bool *return$) noexcept;
+
+::rust::repr::PtrLen GGRS$cxxbridge1$GGRSSession$add_local_input(::GGRS::GGRSSession &self, ::std::uint32_t player_handle, ::rust::Slice<::std::uint8_t const> input, bool *return$) noexcept;
+
+::rust::repr::PtrLen GGRS$cxxbridge1$GGRSSession$add_local_inputs(::GGRS::GGRSSession &self, ::rust::Slice<::GGRS::GGRSLocalInput const> local_inputs, bool *return$) noexcept;
+
+::rust::repr::PtrLen GGRS$cxxbridge1$GGRSSession$get_current_state(::GGRS::GGRSSession &self, ::GGRS::GGRSSessionState *return$) noexcept;
+
+::rust::repr::PtrLen GGRS$cxxbridge1$GGRSSession$get_events(::GGRS::GGRSSession &self, ::rust::Vec<::GGRS::GGRSEvent> *return$) noexcept;
+
+::rust::repr::PtrLen GGRS$cxxbridge1$GGRSSession$advance_frame(::GGRS::GGRSSession &self, ::GGRS::GGRSFrameResult *return$) noexcept;
+
+::rust::repr::PtrLen GGRS$cxxbridge1$GGRSSession$tick(::GGRS::GGRSSession &self, ::rust::Slice<::GGRS::GGRSLocalInput const> local_inputs, ::GGRS::GGRSTickResult *return$) noexcept;
+
+::rust::repr::PtrLen GGRS$cxxbridge1$GGRSSession$frame_pacing(::GGRS::GGRSSession &self, ::GGRS::GGRSFramePacing *return$) noexcept;
+
+::rust::repr::PtrLen GGRS$cxxbridge1$GGRSSession$save_game_state(::GGRS::GGRSSession &self, ::std::int32_t frame, ::rust::Slice<::std::uint8_t const> state, bool *return$) noexcept;
+
+::rust::repr::PtrLen GGRS$cxxbridge1$GGRSSession$submit_checksum(::GGRS::GGRSSession &self, ::std::int32_t frame, ::std::uint64_t checksum_hi, ::std::uint64_t checksum_lo, bool *return$) noexcept;
+
+::rust::repr::PtrLen GGRS$cxxbridge1$GGRSSession$get_frames_ahead(::GGRS::GGRSSession &self, ::std::int32_t *return$) noexcept;
+
+bool GGRS$cxxbridge1$GGRSSession$clean_session(::GGRS::GGRSSession &self) noexcept;
+
+::rust::repr::PtrLen GGRS$cxxbridge1$GGRSSession$network_stats(::GGRS::GGRSSession &self, ::std::uint32_t player_handle, ::GGRS::GGRSNetworkStats *return$) noexcept;
+
+::rust::repr::PtrLen GGRS$cxxbridge1$GGRSSession$disconnect_player(::GGRS::GGRSSession &self, ::std::uint32_t player_handle, bool *return$) noexcept;
+
+::rust::repr::PtrLen GGRS$cxxbridge1$GGRSSession$start_recording(::GGRS::GGRSSession &self, ::rust::Str path, bool *return$) noexcept;
+
+::rust::repr::PtrLen GGRS$cxxbridge1$GGRSSession$stop_recording(::GGRS::GGRSSession &self, bool *return$) noexcept;
+
+::rust::repr::PtrLen GGRS$cxxbridge1$start_replay_session(::rust::Str path, ::rust::Box<::GGRS::GGRSSession> *return$) noexcept;
+
+::rust::repr::PtrLen GGRS$cxxbridge1$GGRSSession$desync_report_path(::GGRS::GGRSSession &self, ::rust::String *return$) noexcept;
 
-bool GGRS$cxxbridge1$setup_ggrs_info(::GGRS::GGRSSessionInfo &info) noexcept;
+::rust::repr::PtrLen GGRS$cxxbridge1$GGRSSession$local_address(::GGRS::GGRSSession &self, ::rust::String *return$) noexcept;
 
-bool GGRS$cxxbridge1$add_player(::GGRS::GGRSSessionInfo &info, ::GGRS::GGRSPlayer *player) noexcept;
+::rust::repr::PtrLen GGRS$cxxbridge1$GGRSSession$set_event_handler(::GGRS::GGRSSession &self, ::GGRS::GGRSEventHandler *handler, bool *return$) noexcept;
 
-bool GGRS$cxxbridge1$setup_p2p_session(::GGRS::GGRSSessionInfo &info, ::std::uint16_t local_port, ::std::uint32_t fps, ::std::uint32_t input_delay, ::std::uint32_t max_prediction_frames) noexcept;
+void GGRS$cxxbridge1$GGRSSessionBuilder$try_with_num_players(::GGRS::GGRSSessionBuilder &self, ::std::uint32_t num_players, ::GGRS::GGRSErrorInfo *return$) noexcept;
 
-bool GGRS$cxxbridge1$setup_spectator_session(::GGRS::GGRSSessionInfo &info, ::std::uint16_t local_port, ::rust::String *host, ::std::uint32_t max_frames_behind, ::std::uint32_t catchup_speed) noexcept;
+void GGRS$cxxbridge1$GGRSSessionBuilder$try_with_input_size(::GGRS::GGRSSessionBuilder &self, ::std::uint32_t bytes, ::GGRS::GGRSErrorInfo *return$) noexcept;
 
-bool GGRS$cxxbridge1$setup_synctest_session(::GGRS::GGRSSessionInfo &info, ::std::uint32_t check_distance, ::std::uint32_t input_delay) noexcept;
+void GGRS$cxxbridge1$GGRSSessionBuilder$try_with_fps(::GGRS::GGRSSessionBuilder &self, ::std::uint32_t fps, ::GGRS::GGRSErrorInfo *return$) noexcept;
 
-bool GGRS$cxxbridge1$set_num_players(::GGRS::GGRSSessionInfo &info, ::std::uint32_t num) noexcept;
+void GGRS$cxxbridge1$GGRSSessionBuilder$try_with_input_delay(::GGRS::GGRSSessionBuilder &self, ::std::uint32_t delay, ::GGRS::GGRSErrorInfo *return$) noexcept;
 
-bool GGRS$cxxbridge1$set_sparse_saving(::GGRS::GGRSSessionInfo &info, bool enable) noexcept;
+void GGRS$cxxbridge1$GGRSSessionBuilder$try_with_max_prediction_window(::GGRS::GGRSSessionBuilder &self, ::std::uint32_t window, ::GGRS::GGRSErrorInfo *return$) noexcept;
 
-::rust::repr::PtrLen GGRS$cxxbridge1$create_session(::GGRS::GGRSSessionInfo &info, ::GGRS::GGRSSession **return$) noexcept;
+void GGRS$cxxbridge1$GGRSSessionBuilder$try_with_max_frames_behind(::GGRS::GGRSSessionBuilder &self, ::std::uint32_t max_frames_behind, ::GGRS::GGRSErrorInfo *return$) noexcept;
 
-bool GGRS$cxxbridge1$poll_remote_clients(::GGRS::GGRSSession *session) noexcept;
+void GGRS$cxxbridge1$GGRSSessionBuilder$try_with_catchup_speed(::GGRS::GGRSSessionBuilder &self, ::std::uint32_t catchup_speed, ::GGRS::GGRSErrorInfo *return$) noexcept;
 
-::rust::repr::PtrLen GGRS$cxxbridge1$add_local_input(::GGRS::GGRSSession *session, ::std::uint32_t player_handle, ::std::uint32_t input, bool *return$) noexcept;
+void GGRS$cxxbridge1$GGRSSessionBuilder$try_with_check_distance(::GGRS::GGRSSessionBuilder &self, ::std::uint32_t check_distance, ::GGRS::GGRSErrorInfo *return$) noexcept;
 
-::GGRS::GGRSSessionState GGRS$cxxbridge1$get_current_state(::GGRS::GGRSSession *session) noexcept;
+void GGRS$cxxbridge1$GGRSSessionBuilder$try_with_disconnect_timeout(::GGRS::GGRSSessionBuilder &self, ::std::uint64_t timeout_ms, ::GGRS::GGRSErrorInfo *return$) noexcept;
 
-void GGRS$cxxbridge1$get_events(::GGRS::GGRSSession *session, ::rust::Vec<::GGRS::GGRSEvent> *return$) noexcept;
+void GGRS$cxxbridge1$GGRSSessionBuilder$try_with_disconnect_notify_delay(::GGRS::GGRSSessionBuilder &self, ::std::uint64_t notify_delay_ms, ::GGRS::GGRSErrorInfo *return$) noexcept;
 
-::rust::repr::PtrLen GGRS$cxxbridge1$advance_frame(::GGRS::GGRSSession *session, ::GGRS::GGRSFrameResult *return$) noexcept;
+void GGRS$cxxbridge1$GGRSSessionBuilder$try_with_network_conditions(::GGRS::GGRSSessionBuilder &self, ::GGRS::GGRSNetworkConditions conditions, ::GGRS::GGRSErrorInfo *return$) noexcept;
 
-::std::int32_t GGRS$cxxbridge1$get_frames_ahead(::GGRS::GGRSSession *session) noexcept;
+void GGRS$cxxbridge1$GGRSSessionBuilder$try_with_sparse_saving_mode(::GGRS::GGRSSessionBuilder &self, bool enable, ::GGRS::GGRSErrorInfo *return$) noexcept;
 
-bool GGRS$cxxbridge1$clean_session(::GGRS::GGRSSession *session) noexcept;
+void GGRS$cxxbridge1$GGRSSessionBuilder$try_with_desync_detection_interval(::GGRS::GGRSSessionBuilder &self, ::std::uint32_t interval, ::GGRS::GGRSErrorInfo *return$) noexcept;
 
-::rust::repr::PtrLen GGRS$cxxbridge1$network_stats(::GGRS::GGRSSession *session, ::std::uint32_t player_handle, ::GGRS::GGRSNetworkStats *return$) noexcept;
+void GGRS$cxxbridge1$GGRSSessionBuilder$try_with_desync_report_dir(::GGRS::GGRSSessionBuilder &self, ::rust::Str dir, ::GGRS::GGRSErrorInfo *return$) noexcept;
 
-::rust::repr::PtrLen GGRS$cxxbridge1$disconnect_player(::GGRS::GGRSSession *session, ::std::uint32_t player_handle, bool *return$) noexcept;
+void GGRS$cxxbridge1$GGRSSessionBuilder$try_with_desync_report_frames(::GGRS::GGRSSessionBuilder &self, ::std::uint32_t frames, ::GGRS::GGRSErrorInfo *return$) noexcept;
+
+void GGRS$cxxbridge1$GGRSSessionBuilder$try_with_address_preference(::GGRS::GGRSSessionBuilder &self, ::GGRS::GGRSAddressPreference preference, ::GGRS::GGRSErrorInfo *return$) noexcept;
+
+void GGRS$cxxbridge1$GGRSSessionBuilder$try_with_bind_address(::GGRS::GGRSSessionBuilder &self, ::rust::Str ip, ::GGRS::GGRSErrorInfo *return$) noexcept;
+
+void GGRS$cxxbridge1$GGRSSessionBuilder$try_with_pacing_mode(::GGRS::GGRSSessionBuilder &self, ::GGRS::GGRSPacingMode mode, ::GGRS::GGRSErrorInfo *return$) noexcept;
+
+void GGRS$cxxbridge1$GGRSSessionBuilder$try_add_player(::GGRS::GGRSSessionBuilder &self, ::GGRS::GGRSPlayer *player, ::GGRS::GGRSErrorInfo *return$) noexcept;
+
+::GGRS::GGRSSession *GGRS$cxxbridge1$GGRSSessionBuilder$try_start_p2p_session(::GGRS::GGRSSessionBuilder &self, ::std::uint16_t local_port, ::GGRS::GGRSErrorInfo &error) noexcept;
+
+::GGRS::GGRSSession *GGRS$cxxbridge1$GGRSSessionBuilder$try_start_spectator_session(::GGRS::GGRSSessionBuilder &self, ::std::uint16_t local_port, ::rust::Str host, ::GGRS::GGRSErrorInfo &error) noexcept;
+
+::GGRS::GGRSSession *GGRS$cxxbridge1$GGRSSessionBuilder$try_start_synctest_session(::GGRS::GGRSSessionBuilder &self, ::GGRS::GGRSErrorInfo &error) noexcept;
+
+::GGRS::GGRSSession *GGRS$cxxbridge1$GGRSSessionBuilder$try_start_p2p_session_with_socket(::GGRS::GGRSSessionBuilder &self, ::GGRS::GGRSNonBlockingSocket *socket, ::GGRS::GGRSErrorInfo &error) noexcept;
+
+::GGRS::GGRSSession *GGRS$cxxbridge1$GGRSSessionBuilder$try_start_spectator_session_with_socket(::GGRS::GGRSSessionBuilder &self, ::rust::Str host, ::GGRS::GGRSNonBlockingSocket *socket, ::GGRS::GGRSErrorInfo &error) noexcept;
+
+::GGRS::GGRSSession *GGRS$cxxbridge1$GGRSSessionBuilder$try_start_p2p_session_on_loopback(::GGRS::GGRSSessionBuilder &self, ::rust::Str local_name, ::GGRS::GGRSErrorInfo &error) noexcept;
+
+::GGRS::GGRSSession *GGRS$cxxbridge1$GGRSSessionBuilder$try_start_spectator_session_on_loopback(::GGRS::GGRSSessionBuilder &self, ::rust::Str local_name, ::rust::Str host, ::GGRS::GGRSErrorInfo &error) noexcept;
+
+void GGRS$cxxbridge1$GGRSSession$try_poll_remote_clients(::GGRS::GGRSSession &self, ::GGRS::GGRSErrorInfo *return$) noexcept;
+
+void GGRS$cxxbridge1$GGRSSession$try_add_local_input(::GGRS::GGRSSession &self, ::std::uint32_t player_handle, ::rust::Slice<::std::uint8_t const> input, ::GGRS::GGRSErrorInfo *return$) noexcept;
+
+void GGRS$cxxbridge1$GGRSSession$try_add_local_inputs(::GGRS::GGRSSession &self, ::rust::Slice<::GGRS::GGRSLocalInput const> local_inputs, ::GGRS::GGRSErrorInfo *return$) noexcept;
+
+void GGRS$cxxbridge1$GGRSSession$try_get_current_state(::GGRS::GGRSSession &self, ::GGRS::GGRSSessionState &state, ::GGRS::GGRSErrorInfo *return$) noexcept;
+
+void GGRS$cxxbridge1$GGRSSession$try_get_events(::GGRS::GGRSSession &self, ::rust::Vec<::GGRS::GGRSEvent> &events, ::GGRS::GGRSErrorInfo *return$) noexcept;
+
+void GGRS$cxxbridge1$GGRSSession$try_advance_frame(::GGRS::GGRSSession &self, ::GGRS::GGRSFrameResult &result, ::GGRS::GGRSErrorInfo *return$) noexcept;
+
+void GGRS$cxxbridge1$GGRSSession$try_frame_pacing(::GGRS::GGRSSession &self, ::GGRS::GGRSFramePacing &pacing, ::GGRS::GGRSErrorInfo *return$) noexcept;
+
+void GGRS$cxxbridge1$GGRSSession$try_tick(::GGRS::GGRSSession &self, ::rust::Slice<::GGRS::GGRSLocalInput const> local_inputs, ::GGRS::GGRSTickResult &result, ::GGRS::GGRSErrorInfo *return$) noexcept;
+
+void GGRS$cxxbridge1$GGRSSession$try_save_game_state(::GGRS::GGRSSession &self, ::std::int32_t frame, ::rust::Slice<::std::uint8_t const> state, ::GGRS::GGRSErrorInfo *return$) noexcept;
+
+void GGRS$cxxbridge1$GGRSSession$try_submit_checksum(::GGRS::GGRSSession &self, ::std::int32_t frame, ::std::uint64_t checksum_hi, ::std::uint64_t checksum_lo, ::GGRS::GGRSErrorInfo *return$) noexcept;
+
+void GGRS$cxxbridge1$GGRSSession$try_get_frames_ahead(::GGRS::GGRSSession &self, ::std::int32_t &frames_ahead, ::GGRS::GGRSErrorInfo *return$) noexcept;
+
+void GGRS$cxxbridge1$GGRSSession$try_network_stats(::GGRS::GGRSSession &self, ::std::uint32_t player_handle, ::GGRS::GGRSNetworkStats &stats, ::GGRS::GGRSErrorInfo *return$) noexcept;
+
+void GGRS$cxxbridge1$GGRSSession$try_disconnect_player(::GGRS::GGRSSession &self, ::std::uint32_t player_handle, ::GGRS::GGRSErrorInfo *return$) noexcept;
+
+void GGRS$cxxbridge1$GGRSSession$try_start_recording(::GGRS::GGRSSession &self, ::rust::Str path, ::GGRS::GGRSErrorInfo *return$) noexcept;
+
+void GGRS$cxxbridge1$GGRSSession$try_stop_recording(::GGRS::GGRSSession &self, ::GGRS::GGRSErrorInfo *return$) noexcept;
+
+void GGRS$cxxbridge1$GGRSSession$try_desync_report_path(::GGRS::GGRSSession &self, ::rust::String &path, ::GGRS::GGRSErrorInfo *return$) noexcept;
+
+void GGRS$cxxbridge1$GGRSSession$try_local_address(::GGRS::GGRSSession &self, ::rust::String &addr, ::GGRS::GGRSErrorInfo *return$) noexcept;
+
+void GGRS$cxxbridge1$GGRSSession$try_set_event_handler(::GGRS::GGRSSession &self, ::GGRS::GGRSEventHandler *handler, ::GGRS::GGRSErrorInfo *return$) noexcept;
+
+::GGRS::GGRSSession *GGRS$cxxbridge1$try_start_replay_session(::rust::Str path, ::GGRS::GGRSErrorInfo &error) noexcept;
 } // extern "C"
 
 ::std::size_t GGRSSession::layout::size() noexcept {
@@ -298,175 +825,779 @@ bool GGRS$cxxbridge1$clean_session(::GGRS::GGRSSession *session) noexcept;
   return GGRS$cxxbridge1$GGRSSession$operator$alignof();
 }
 
-bool setup_ggrs_info(::GGRS::GGRSSessionInfo &info) noexcept {
-  return GGRS$cxxbridge1$setup_ggrs_info(info);
+::std::size_t GGRSSessionBuilder::layout::size() noexcept {
+  return GGRS$cxxbridge1$GGRSSessionBuilder$operator$sizeof();
 }
 
-bool add_player(::GGRS::GGRSSessionInfo &info, ::GGRS::GGRSPlayer player) noexcept {
-  ::rust::ManuallyDrop<::GGRS::GGRSPlayer> player$(::std::move(player));
-  return GGRS$cxxbridge1$add_player(info, &player$.value);
+::std::size_t GGRSSessionBuilder::layout::align() noexcept {
+  return GGRS$cxxbridge1$GGRSSessionBuilder$operator$alignof();
 }
 
-bool setup_p2p_session(::GGRS::GGRSSessionInfo &info, ::std::uint16_t local_port, ::std::uint32_t fps, ::std::uint32_t input_delay, ::std::uint32_t max_prediction_frames) noexcept {
-  return GGRS$cxxbridge1$setup_p2p_session(info, local_port, fps, input_delay, max_prediction_frames);
+::rust::Box<::GGRS::GGRSSessionBuilder> new_session_builder() noexcept {
+  return ::rust::Box<::GGRS::GGRSSessionBuilder>::from_raw(GGRS$cxxbridge1$new_session_builder());
 }
 
-bool setup_spectator_session(::GGRS::GGRSSessionInfo &info, ::std::uint16_t local_port, ::rust::String host, ::std::uint32_t max_frames_behind, ::std::uint32_t catchup_speed) noexcept {
-  return GGRS$cxxbridge1$setup_spectator_session(info, local_port, &host, max_frames_behind, catchup_speed);
+::GGRS::GGRSSessionBuilder &GGRSSessionBuilder::with_num_players(::std::uint32_t num_players) {
+  ::rust::MaybeUninit<::GGRS::GGRSSessionBuilder *> return$;
+  ::rust::repr::PtrLen error$ = GGRS$cxxbridge1$GGRSSessionBuilder$with_num_players(*this, num_players, &return$.value);
+  if (error$.ptr) {
+    throw ::rust::impl<::rust::Error>::error(error$);
+  }
+  return *return$.value;
 }
 
-bool setup_synctest_session(::GGRS::GGRSSessionInfo &info, ::std::uint32_t check_distance, ::std::uint32_t input_delay) noexcept {
-  return GGRS$cxxbridge1$setup_synctest_session(info, check_distance, input_delay);
+::GGRS::GGRSSessionBuilder &GGRSSessionBuilder::with_input_size(::std::uint32_t bytes) {
+  ::rust::MaybeUninit<::GGRS::GGRSSessionBuilder *> return$;
+  ::rust::repr::PtrLen error$ = GGRS$cxxbridge1$GGRSSessionBuilder$with_input_size(*this, bytes, &return$.value);
+  if (error$.ptr) {
+    throw ::rust::impl<::rust::Error>::error(error$);
+  }
+  return *return$.value;
 }
 
-bool set_num_players(::GGRS::GGRSSessionInfo &info, ::std::uint32_t num) noexcept {
-  return GGRS$cxxbridge1$set_num_players(info, num);
+::GGRS::GGRSSessionBuilder &GGRSSessionBuilder::with_fps(::std::uint32_t fps) {
+  ::rust::MaybeUninit<::GGRS::GGRSSessionBuilder *> return$;
+  ::rust::repr::PtrLen error$ = GGRS$cxxbridge1$GGRSSessionBuilder$with_fps(*this, fps, &return$.value);
+  if (error$.ptr) {
+    throw ::rust::impl<::rust::Error>::error(error$);
+  }
+  return *return$.value;
 }
 
-bool set_sparse_saving(::GGRS::GGRSSessionInfo &info, bool enable) noexcept {
-  return GGRS$cxxbridge1$set_sparse_saving(info, enable);
+::GGRS::GGRSSessionBuilder &GGRSSessionBuilder::with_input_delay(::std::uint32_t delay) {
+  ::rust::MaybeUninit<::GGRS::GGRSSessionBuilder *> return$;
+  ::rust::repr::PtrLen error$ = GGRS$cxxbridge1$GGRSSessionBuilder$with_input_delay(*this, delay, &return$.value);
+  if (error$.ptr) {
+    throw ::rust::impl<::rust::Error>::error(error$);
+  }
+  return *return$.value;
 }
 
-::GGRS::GGRSSession *create_session(::GGRS::GGRSSessionInfo &info) {
-  ::rust::MaybeUninit<::GGRS::GGRSSession *> return$;
-  ::rust::repr::PtrLen error$ = GGRS$cxxbridge1$create_session(info, &return$.value);
+::GGRS::GGRSSessionBuilder &GGRSSessionBuilder::with_max_prediction_window(::std::uint32_t window) {
+  ::rust::MaybeUninit<::GGRS::GGRSSessionBuilder *> return$;
+  ::rust::repr::PtrLen error$ = GGRS$cxxbridge1$GGRSSessionBuilder$with_max_prediction_window(*this, window, &return$.value);
   if (error$.ptr) {
     throw ::rust::impl<::rust::Error>::error(error$);
   }
-  return ::std::move(return$.value);
+  return *return$.value;
 }
 
-bool poll_remote_clients(::GGRS::GGRSSession *session) noexcept {
-  return GGRS$cxxbridge1$poll_remote_clients(session);
+::GGRS::GGRSSessionBuilder &GGRSSessionBuilder::with_max_frames_behind(::std::uint32_t max_frames_behind) {
+  ::rust::MaybeUninit<::GGRS::GGRSSessionBuilder *> return$;
+  ::rust::repr::PtrLen error$ = GGRS$cxxbridge1$GGRSSessionBuilder$with_max_frames_behind(*this, max_frames_behind, &return$.value);
+  if (error$.ptr) {
+    throw ::rust::impl<::rust::Error>::error(error$);
+  }
+  return *return$.value;
 }
 
-bool add_local_input(::GGRS::GGRSSession *session, ::std::uint32_t player_handle, ::std::uint32_t input) {
-  ::rust::MaybeUninit<bool> return$;
-  ::rust::repr::PtrLen error$ = GGRS$cxxbridge1$add_local_input(session, player_handle, input, &return$.value);
+::GGRS::GGRSSessionBuilder &GGRSSessionBuilder::with_catchup_speed(::std::uint32_t catchup_speed) {
+  ::rust::MaybeUninit<::GGRS::GGRSSessionBuilder *> return$;
+  ::rust::repr::PtrLen error$ = GGRS$cxxbridge1$GGRSSessionBuilder$with_catchup_speed(*this, catchup_speed, &return$.value);
   if (error$.ptr) {
     throw ::rust::impl<::rust::Error>::error(error$);
   }
-  return ::std::move(return$.value);
+  return *return$.value;
 }
 
-::GGRS::GGRSSessionState get_current_state(::GGRS::GGRSSession *session) noexcept {
-  return GGRS$cxxbridge1$get_current_state(session);
+::GGRS::GGRSSessionBuilder &GGRSSessionBuilder::with_check_distance(::std::uint32_t check_distance) {
+  ::rust::MaybeUninit<::GGRS::GGRSSessionBuilder *> return$;
+  ::rust::repr::PtrLen error$ = GGRS$cxxbridge1$GGRSSessionBuilder$with_check_distance(*this, check_distance, &return$.value);
+  if (error$.ptr) {
+    throw ::rust::impl<::rust::Error>::error(error$);
+  }
+  return *return$.value;
 }
 
-::rust::Vec<::GGRS::GGRSEvent> get_events(::GGRS::GGRSSession *session) noexcept {
-  ::rust::MaybeUninit<::rust::Vec<::GGRS::GGRSEvent>> return$;
-  GGRS$cxxbridge1$get_events(session, &return$.value);
-  return ::std::move(return$.value);
+::GGRS::GGRSSessionBuilder &GGRSSessionBuilder::with_disconnect_timeout(::std::uint64_t timeout_ms) {
+  ::rust::MaybeUninit<::GGRS::GGRSSessionBuilder *> return$;
+  ::rust::repr::PtrLen error$ = GGRS$cxxbridge1$GGRSSessionBuilder$with_disconnect_timeout(*this, timeout_ms, &return$.value);
+  if (error$.ptr) {
+    throw ::rust::impl<::rust::Error>::error(error$);
+  }
+  return *return$.value;
 }
 
-::GGRS::GGRSFrameResult advance_frame(::GGRS::GGRSSession *session) {
-  ::rust::MaybeUninit<::GGRS::GGRSFrameResult> return$;
-  ::rust::repr::PtrLen error$ = GGRS$cxxbridge1$advance_frame(session, &return$.value);
+::GGRS::GGRSSessionBuilder &GGRSSessionBuilder::with_disconnect_notify_delay(::std::uint64_t notify_delay_ms) {
+  ::rust::MaybeUninit<::GGRS::GGRSSessionBuilder *> return$;
+  ::rust::repr::PtrLen error$ = GGRS$cxxbridge1$GGRSSessionBuilder$with_disconnect_notify_delay(*this, notify_delay_ms, &return$.value);
   if (error$.ptr) {
     throw ::rust::impl<::rust::Error>::error(error$);
   }
-  return ::std::move(return$.value);
+  return *return$.value;
 }
 
-::std::int32_t get_frames_ahead(::GGRS::GGRSSession *session) noexcept {
-  return GGRS$cxxbridge1$get_frames_ahead(session);
+::GGRS::GGRSSessionBuilder &GGRSSessionBuilder::with_network_conditions(::GGRS::GGRSNetworkConditions conditions) {
+  ::rust::MaybeUninit<::GGRS::GGRSSessionBuilder *> return$;
+  ::rust::repr::PtrLen error$ = GGRS$cxxbridge1$GGRSSessionBuilder$with_network_conditions(*this, conditions, &return$.value);
+  if (error$.ptr) {
+    throw ::rust::impl<::rust::Error>::error(error$);
+  }
+  return *return$.value;
 }
 
-bool clean_session(::GGRS::GGRSSession *session) noexcept {
-  return GGRS$cxxbridge1$clean_session(session);
+::GGRS::GGRSSessionBuilder &GGRSSessionBuilder::with_sparse_saving_mode(bool enable) {
+  ::rust::MaybeUninit<::GGRS::GGRSSessionBuilder *> return$;
+  ::rust::repr::PtrLen error$ = GGRS$cxxbridge1$GGRSSessionBuilder$with_sparse_saving_mode(*this, enable, &return$.value);
+  if (error$.ptr) {
+    throw ::rust::impl<::rust::Error>::error(error$);
+  }
+  return *return$.value;
 }
 
-::GGRS::GGRSNetworkStats network_stats(::GGRS::GGRSSession *session, ::std::uint32_t player_handle) {
-  ::rust::MaybeUninit<::GGRS::GGRSNetworkStats> return$;
-  ::rust::repr::PtrLen error$ = GGRS$cxxbridge1$network_stats(session, player_handle, &return$.value);
+::GGRS::GGRSSessionBuilder &GGRSSessionBuilder::with_desync_detection_interval(::std::uint32_t interval) {
+  ::rust::MaybeUninit<::GGRS::GGRSSessionBuilder *> return$;
+  ::rust::repr::PtrLen error$ = GGRS$cxxbridge1$GGRSSessionBuilder$with_desync_detection_interval(*this, interval, &return$.value);
   if (error$.ptr) {
     throw ::rust::impl<::rust::Error>::error(error$);
   }
-  return ::std::move(return$.value);
+  return *return$.value;
 }
 
-bool disconnect_player(::GGRS::GGRSSession *session, ::std::uint32_t player_handle) {
-  ::rust::MaybeUninit<bool> return$;
-  ::rust::repr::PtrLen error$ = GGRS$cxxbridge1$disconnect_player(session, player_handle, &return$.value);
+::GGRS::GGRSSessionBuilder &GGRSSessionBuilder::with_desync_report_dir(::rust::Str dir) {
+  ::rust::MaybeUninit<::GGRS::GGRSSessionBuilder *> return$;
+  ::rust::repr::PtrLen error$ = GGRS$cxxbridge1$GGRSSessionBuilder$with_desync_report_dir(*this, dir, &return$.value);
   if (error$.ptr) {
     throw ::rust::impl<::rust::Error>::error(error$);
   }
-  return ::std::move(return$.value);
+  return *return$.value;
 }
-} // namespace GGRS
-
-extern "C" {
-void cxxbridge1$rust_vec$GGRS$GGRSPlayer$new(::rust::Vec<::GGRS::GGRSPlayer> const *ptr) noexcept;
-void cxxbridge1$rust_vec$GGRS$GGRSPlayer$drop(::rust::Vec<::GGRS::GGRSPlayer> *ptr) noexcept;
-::std::size_t cxxbridge1$rust_vec$GGRS$GGRSPlayer$len(::rust::Vec<::GGRS::GGRSPlayer> const *ptr) noexcept;
-::std::size_t cxxbridge1$rust_vec$GGRS$GGRSPlayer$capacity(::rust::Vec<::GGRS::GGRSPlayer> const *ptr) noexcept;
-::GGRS::GGRSPlayer const *cxxbridge1$rust_vec$GGRS$GGRSPlayer$data(::rust::Vec<::GGRS::GGRSPlayer> const *ptr) noexcept;
-void cxxbridge1$rust_vec$GGRS$GGRSPlayer$reserve_total(::rust::Vec<::GGRS::GGRSPlayer> *ptr, ::std::size_t new_cap) noexcept;
-void cxxbridge1$rust_vec$GGRS$GGRSPlayer$set_len(::rust::Vec<::GGRS::GGRSPlayer> *ptr, ::std::size_t len) noexcept;
-void cxxbridge1$rust_vec$GGRS$GGRSPlayer$truncate(::rust::Vec<::GGRS::GGRSPlayer> *ptr, ::std::size_t len) noexcept;
 
-void cxxbridge1$rust_vec$GGRS$GGRSFrameAction$new(::rust::Vec<::GGRS::GGRSFrameAction> const *ptr) noexcept;
-void cxxbridge1$rust_vec$GGRS$GGRSFrameAction$drop(::rust::Vec<::GGRS::GGRSFrameAction> *ptr) noexcept;
-::std::size_t cxxbridge1$rust_vec$GGRS$GGRSFrameAction$len(::rust::Vec<::GGRS::GGRSFrameAction> const *ptr) noexcept;
-::std::size_t cxxbridge1$rust_vec$GGRS$GGRSFrameAction$capacity(::rust::Vec<::GGRS::GGRSFrameAction> const *ptr) noexcept;
-::GGRS::GGRSFrameAction const *cxxbridge1$rust_vec$GGRS$GGRSFrameAction$data(::rust::Vec<::GGRS::GGRSFrameAction> const *ptr) noexcept;
-void cxxbridge1$rust_vec$GGRS$GGRSFrameAction$reserve_total(::rust::Vec<::GGRS::GGRSFrameAction> *ptr, ::std::size_t new_cap) noexcept;
-void cxxbridge1$rust_vec$GGRS$GGRSFrameAction$set_len(::rust::Vec<::GGRS::GGRSFrameAction> *ptr, ::std::size_t len) noexcept;
-void cxxbridge1$rust_vec$GGRS$GGRSFrameAction$truncate(::rust::Vec<::GGRS::GGRSFrameAction> *ptr, ::std::size_t len) noexcept;
+::GGRS::GGRSSessionBuilder &GGRSSessionBuilder::with_desync_report_frames(::std::uint32_t frames) {
+  ::rust::MaybeUninit<::GGRS::GGRSSessionBuilder *> return$;
+  ::rust::repr::PtrLen error$ = GGRS$cxxbridge1$GGRSSessionBuilder$with_desync_report_frames(*this, frames, &return$.value);
+  if (error$.ptr) {
+    throw ::rust::impl<::rust::Error>::error(error$);
+  }
+  return *return$.value;
+}
 
-void cxxbridge1$rust_vec$GGRS$GGRSInput$new(::rust::Vec<::GGRS::GGRSInput> const *ptr) noexcept;
-void cxxbridge1$rust_vec$GGRS$GGRSInput$drop(::rust::Vec<::GGRS::GGRSInput> *ptr) noexcept;
-::std::size_t cxxbridge1$rust_vec$GGRS$GGRSInput$len(::rust::Vec<::GGRS::GGRSInput> const *ptr) noexcept;
-::std::size_t cxxbridge1$rust_vec$GGRS$GGRSInput$capacity(::rust::Vec<::GGRS::GGRSInput> const *ptr) noexcept;
-::GGRS::GGRSInput const *cxxbridge1$rust_vec$GGRS$GGRSInput$data(::rust::Vec<::GGRS::GGRSInput> const *ptr) noexcept;
-void cxxbridge1$rust_vec$GGRS$GGRSInput$reserve_total(::rust::Vec<::GGRS::GGRSInput> *ptr, ::std::size_t new_cap) noexcept;
-void cxxbridge1$rust_vec$GGRS$GGRSInput$set_len(::rust::Vec<::GGRS::GGRSInput> *ptr, ::std::size_t len) noexcept;
-void cxxbridge1$rust_vec$GGRS$GGRSInput$truncate(::rust::Vec<::GGRS::GGRSInput> *ptr, ::std::size_t len) noexcept;
+::GGRS::GGRSSessionBuilder &GGRSSessionBuilder::with_address_preference(::GGRS::GGRSAddressPreference preference) {
+  ::rust::MaybeUninit<::GGRS::GGRSSessionBuilder *> return$;
+  ::rust::repr::PtrLen error$ = GGRS$cxxbridge1$GGRSSessionBuilder$with_address_preference(*this, preference, &return$.value);
+  if (error$.ptr) {
+    throw ::rust::impl<::rust::Error>::error(error$);
+  }
+  return *return$.value;
+}
 
-void cxxbridge1$rust_vec$GGRS$GGRSEvent$new(::rust::Vec<::GGRS::GGRSEvent> const *ptr) noexcept;
-void cxxbridge1$rust_vec$GGRS$GGRSEvent$drop(::rust::Vec<::GGRS::GGRSEvent> *ptr) noexcept;
-::std::size_t cxxbridge1$rust_vec$GGRS$GGRSEvent$len(::rust::Vec<::GGRS::GGRSEvent> const *ptr) noexcept;
-::std::size_t cxxbridge1$rust_vec$GGRS$GGRSEvent$capacity(::rust::Vec<::GGRS::GGRSEvent> const *ptr) noexcept;
-::GGRS::GGRSEvent const *cxxbridge1$rust_vec$GGRS$GGRSEvent$data(::rust::Vec<::GGRS::GGRSEvent> const *ptr) noexcept;
-void cxxbridge1$rust_vec$GGRS$GGRSEvent$reserve_total(::rust::Vec<::GGRS::GGRSEvent> *ptr, ::std::size_t new_cap) noexcept;
-void cxxbridge1$rust_vec$GGRS$GGRSEvent$set_len(::rust::Vec<::GGRS::GGRSEvent> *ptr, ::std::size_t len) noexcept;
-void cxxbridge1$rust_vec$GGRS$GGRSEvent$truncate(::rust::Vec<::GGRS::GGRSEvent> *ptr, ::std::size_t len) noexcept;
-} // extern "C"
+::GGRS::GGRSSessionBuilder &GGRSSessionBuilder::with_pacing_mode(::GGRS::GGRSPacingMode mode) {
+  ::rust::MaybeUninit<::GGRS::GGRSSessionBuilder *> return$;
+  ::rust::repr::PtrLen error$ = GGRS$cxxbridge1$GGRSSessionBuilder$with_pacing_mode(*this, mode, &return$.value);
+  if (error$.ptr) {
+    throw ::rust::impl<::rust::Error>::error(error$);
+  }
+  return *return$.value;
+}
 
-namespace rust {
-inline namespace cxxbridge1 {
-template <>
-Vec<::GGRS::GGRSPlayer>::Vec() noexcept {
-  cxxbridge1$rust_vec$GGRS$GGRSPlayer$new(this);
+::GGRS::GGRSSessionBuilder &GGRSSessionBuilder::with_bind_address(::rust::Str ip) {
+  ::rust::MaybeUninit<::GGRS::GGRSSessionBuilder *> return$;
+  ::rust::repr::PtrLen error$ = GGRS$cxxbridge1$GGRSSessionBuilder$with_bind_address(*this, ip, &return$.value);
+  if (error$.ptr) {
+    throw ::rust::impl<::rust::Error>::error(error$);
+  }
+  return *return$.value;
 }
-template <>
-void Vec<::GGRS::GGRSPlayer>::drop() noexcept {
-  return cxxbridge1$rust_vec$GGRS$GGRSPlayer$drop(this);
+
+::GGRS::GGRSSessionBuilder &GGRSSessionBuilder::add_player(::GGRS::GGRSPlayer player) {
+  ::rust::ManuallyDrop<::GGRS::GGRSPlayer> player$(::std::move(player));
+  ::rust::MaybeUninit<::GGRS::GGRSSessionBuilder *> return$;
+  ::rust::repr::PtrLen error$ = GGRS$cxxbridge1$GGRSSessionBuilder$add_player(*this, &player$.value, &return$.value);
+  if (error$.ptr) {
+    throw ::rust::impl<::rust::Error>::error(error$);
+  }
+  return *return$.value;
 }
-template <>
-::std::size_t Vec<::GGRS::GGRSPlayer>::size() const noexcept {
-  return cxxbridge1$rust_vec$GGRS$GGRSPlayer$len(this);
+
+::rust::Box<::GGRS::GGRSSession> GGRSSessionBuilder::start_p2p_session(::std::uint16_t local_port) {
+  ::rust::MaybeUninit<::rust::Box<::GGRS::GGRSSession>> return$;
+  ::rust::repr::PtrLen error$ = GGRS$cxxbridge1$GGRSSessionBuilder$start_p2p_session(*this, local_port, &return$.value);
+  if (error$.ptr) {
+    throw ::rust::impl<::rust::Error>::error(error$);
+  }
+  return ::std::move(return$.value);
 }
-template <>
-::std::size_t Vec<::GGRS::GGRSPlayer>::capacity() const noexcept {
-  return cxxbridge1$rust_vec$GGRS$GGRSPlayer$capacity(this);
+
+::rust::Box<::GGRS::GGRSSession> GGRSSessionBuilder::start_spectator_session(::std::uint16_t local_port, ::rust::Str host) {
+  ::rust::MaybeUninit<::rust::Box<::GGRS::GGRSSession>> return$;
+  ::rust::repr::PtrLen error$ = GGRS$cxxbridge1$GGRSSessionBuilder$start_spectator_session(*this, local_port, host, &return$.value);
+  if (error$.ptr) {
+    throw ::rust::impl<::rust::Error>::error(error$);
+  }
+  return ::std::move(return$.value);
 }
-template <>
-::GGRS::GGRSPlayer const *Vec<::GGRS::GGRSPlayer>::data() const noexcept {
-  return cxxbridge1$rust_vec$GGRS$GGRSPlayer$data(this);
+
+::rust::Box<::GGRS::GGRSSession> GGRSSessionBuilder::start_synctest_session() {
+  ::rust::MaybeUninit<::rust::Box<::GGRS::GGRSSession>> return$;
+  ::rust::repr::PtrLen error$ = GGRS$cxxbridge1$GGRSSessionBuilder$start_synctest_session(*this, &return$.value);
+  if (error$.ptr) {
+    throw ::rust::impl<::rust::Error>::error(error$);
+  }
+  return ::std::move(return$.value);
 }
-template <>
-void Vec<::GGRS::GGRSPlayer>::reserve_total(::std::size_t new_cap) noexcept {
-  return cxxbridge1$rust_vec$GGRS$GGRSPlayer$reserve_total(this, new_cap);
+
+::rust::Box<::GGRS::GGRSSession> GGRSSessionBuilder::start_p2p_session_with_socket(::std::unique_ptr<::GGRS::GGRSNonBlockingSocket> socket) {
+  ::rust::MaybeUninit<::rust::Box<::GGRS::GGRSSession>> return$;
+  ::rust::repr::PtrLen error$ = GGRS$cxxbridge1$GGRSSessionBuilder$start_p2p_session_with_socket(*this, socket.release(), &return$.value);
+  if (error$.ptr) {
+    throw ::rust::impl<::rust::Error>::error(error$);
+  }
+  return ::std::move(return$.value);
 }
-template <>
-void Vec<::GGRS::GGRSPlayer>::set_len(::std::size_t len) noexcept {
-  return cxxbridge1$rust_vec$GGRS$GGRSPlayer$set_len(this, len);
+
+::rust::Box<::GGRS::GGRSSession> GGRSSessionBuilder::start_spectator_session_with_socket(::rust::Str host, ::std::unique_ptr<::GGRS::GGRSNonBlockingSocket> socket) {
+  ::rust::MaybeUninit<::rust::Box<::GGRS::GGRSSession>> return$;
+  ::rust::repr::PtrLen error$ = GGRS$cxxbridge1$GGRSSessionBuilder$start_spectator_session_with_socket(*this, host, socket.release(), &return$.value);
+  if (error$.ptr) {
+    throw ::rust::impl<::rust::Error>::error(error$);
+  }
+  return ::std::move(return$.value);
 }
-template <>
-void Vec<::GGRS::GGRSPlayer>::truncate(::std::size_t len) {
-  return cxxbridge1$rust_vec$GGRS$GGRSPlayer$truncate(this, len);
+
+::rust::Box<::GGRS::GGRSSession> GGRSSessionBuilder::start_p2p_session_on_loopback(::rust::Str local_name) {
+  ::rust::MaybeUninit<::rust::Box<::GGRS::GGRSSession>> return$;
+  ::rust::repr::PtrLen error$ = GGRS$cxxbridge1$GGRSSessionBuilder$start_p2p_session_on_loopback(*this, local_name, &return$.value);
+  if (error$.ptr) {
+    throw ::rust::impl<::rust::Error>::error(error$);
+  }
+  return ::std::move(return$.value);
+}
+
+::rust::Box<::GGRS::GGRSSession> GGRSSessionBuilder::start_spectator_session_on_loopback(::rust::Str local_name, ::rust::Str host) {
+  ::rust::MaybeUninit<::rust::Box<::GGRS::GGRSSession>> return$;
+  ::rust::repr::PtrLen error$ = GGRS$cxxbridge1$GGRSSessionBuilder$start_spectator_session_on_loopback(*this, local_name, host, &return$.value);
+  if (error$.ptr) {
+    throw ::rust::impl<::rust::Error>::error(error$);
+  }
+  return ::std::move(return$.value);
+}
+
+bool GGRSSession::poll_remote_clients() {
+  ::rust::MaybeUninit<bool> return$;
+  ::rust::repr::PtrLen error$ = GGRS$cxxbridge1$GGRSSession$poll_remote_clients(*this, &return$.value);
+  if (error$.ptr) {
+    throw ::rust::impl<::rust::Error>::error(error$);
+  }
+  return ::std::move(return$.value);
+}
+
+bool GGRSSession::add_local_input(::std::uint32_t player_handle, ::rust::Slice<::std::uint8_t const> input) {
+  ::rust::MaybeUninit<bool> return$;
+  ::rust::repr::PtrLen error$ = GGRS$cxxbridge1$GGRSSession$add_local_input(*this, player_handle, input, &return$.value);
+  if (error$.ptr) {
+    throw ::rust::impl<::rust::Error>::error(error$);
+  }
+  return ::std::move(return$.value);
+}
+
+bool GGRSSession::add_local_inputs(::rust::Slice<::GGRS::GGRSLocalInput const> local_inputs) {
+  ::rust::MaybeUninit<bool> return$;
+  ::rust::repr::PtrLen error$ = GGRS$cxxbridge1$GGRSSession$add_local_inputs(*this, local_inputs, &return$.value);
+  if (error$.ptr) {
+    throw ::rust::impl<::rust::Error>::error(error$);
+  }
+  return ::std::move(return$.value);
+}
+
+::GGRS::GGRSSessionState GGRSSession::get_current_state() {
+  ::rust::MaybeUninit<::GGRS::GGRSSessionState> return$;
+  ::rust::repr::PtrLen error$ = GGRS$cxxbridge1$GGRSSession$get_current_state(*this, &return$.value);
+  if (error$.ptr) {
+    throw ::rust::impl<::rust::Error>::error(error$);
+  }
+  return ::std::move(return$.value);
+}
+
+::rust::Vec<::GGRS::GGRSEvent> GGRSSession::get_events() {
+  ::rust::MaybeUninit<::rust::Vec<::GGRS::GGRSEvent>> return$;
+  ::rust::repr::PtrLen error$ = GGRS$cxxbridge1$GGRSSession$get_events(*this, &return$.value);
+  if (error$.ptr) {
+    throw ::rust::impl<::rust::Error>::error(error$);
+  }
+  return ::std::move(return$.value);
+}
+
+::GGRS::GGRSFrameResult GGRSSession::advance_frame() {
+  ::rust::MaybeUninit<::GGRS::GGRSFrameResult> return$;
+  ::rust::repr::PtrLen error$ = GGRS$cxxbridge1$GGRSSession$advance_frame(*this, &return$.value);
+  if (error$.ptr) {
+    throw ::rust::impl<::rust::Error>::error(error$);
+  }
+  return ::std::move(return$.value);
+}
+
+::GGRS::GGRSTickResult GGRSSession::tick(::rust::Slice<::GGRS::GGRSLocalInput const> local_inputs) {
+  ::rust::MaybeUninit<::GGRS::GGRSTickResult> return$;
+  ::rust::repr::PtrLen error$ = GGRS$cxxbridge1$GGRSSession$tick(*this, local_inputs, &return$.value);
+  if (error$.ptr) {
+    throw ::rust::impl<::rust::Error>::error(error$);
+  }
+  return ::std::move(return$.value);
+}
+
+::GGRS::GGRSFramePacing GGRSSession::frame_pacing() {
+  ::rust::MaybeUninit<::GGRS::GGRSFramePacing> return$;
+  ::rust::repr::PtrLen error$ = GGRS$cxxbridge1$GGRSSession$frame_pacing(*this, &return$.value);
+  if (error$.ptr) {
+    throw ::rust::impl<::rust::Error>::error(error$);
+  }
+  return ::std::move(return$.value);
+}
+
+bool GGRSSession::save_game_state(::std::int32_t frame, ::rust::Slice<::std::uint8_t const> state) {
+  ::rust::MaybeUninit<bool> return$;
+  ::rust::repr::PtrLen error$ = GGRS$cxxbridge1$GGRSSession$save_game_state(*this, frame, state, &return$.value);
+  if (error$.ptr) {
+    throw ::rust::impl<::rust::Error>::error(error$);
+  }
+  return ::std::move(return$.value);
 }
+
+bool GGRSSession::submit_checksum(::std::int32_t frame, ::std::uint64_t checksum_hi, ::std::uint64_t checksum_lo) {
+  ::rust::MaybeUninit<bool> return$;
+  ::rust::repr::PtrLen error$ = GGRS$cxxbridge1$GGRSSession$submit_checksum(*this, frame, checksum_hi, checksum_lo, &return$.value);
+  if (error$.ptr) {
+    throw ::rust::impl<::rust::Error>::error(error$);
+  }
+  return ::std::move(return$.value);
+}
+
+::std::int32_t GGRSSession::get_frames_ahead() {
+  ::rust::MaybeUninit<::std::int32_t> return$;
+  ::rust::repr::PtrLen error$ = GGRS$cxxbridge1$GGRSSession$get_frames_ahead(*this, &return$.value);
+  if (error$.ptr) {
+    throw ::rust::impl<::rust::Error>::error(error$);
+  }
+  return ::std::move(return$.value);
+}
+
+bool GGRSSession::clean_session() noexcept {
+  return GGRS$cxxbridge1$GGRSSession$clean_session(*this);
+}
+
+::GGRS::GGRSNetworkStats GGRSSession::network_stats(::std::uint32_t player_handle) {
+  ::rust::MaybeUninit<::GGRS::GGRSNetworkStats> return$;
+  ::rust::repr::PtrLen error$ = GGRS$cxxbridge1$GGRSSession$network_stats(*this, player_handle, &return$.value);
+  if (error$.ptr) {
+    throw ::rust::impl<::rust::Error>::error(error$);
+  }
+  return ::std::move(return$.value);
+}
+
+bool GGRSSession::disconnect_player(::std::uint32_t player_handle) {
+  ::rust::MaybeUninit<bool> return$;
+  ::rust::repr::PtrLen error$ = GGRS$cxxbridge1$GGRSSession$disconnect_player(*this, player_handle, &return$.value);
+  if (error$.ptr) {
+    throw ::rust::impl<::rust::Error>::error(error$);
+  }
+  return ::std::move(return$.value);
+}
+
+bool GGRSSession::start_recording(::rust::Str path) {
+  ::rust::MaybeUninit<bool> return$;
+  ::rust::repr::PtrLen error$ = GGRS$cxxbridge1$GGRSSession$start_recording(*this, path, &return$.value);
+  if (error$.ptr) {
+    throw ::rust::impl<::rust::Error>::error(error$);
+  }
+  return ::std::move(return$.value);
+}
+
+bool GGRSSession::stop_recording() {
+  ::rust::MaybeUninit<bool> return$;
+  ::rust::repr::PtrLen error$ = GGRS$cxxbridge1$GGRSSession$stop_recording(*this, &return$.value);
+  if (error$.ptr) {
+    throw ::rust::impl<::rust::Error>::error(error$);
+  }
+  return ::std::move(return$.value);
+}
+
+::rust::Box<::GGRS::GGRSSession> start_replay_session(::rust::Str path) {
+  ::rust::MaybeUninit<::rust::Box<::GGRS::GGRSSession>> return$;
+  ::rust::repr::PtrLen error$ = GGRS$cxxbridge1$start_replay_session(path, &return$.value);
+  if (error$.ptr) {
+    throw ::rust::impl<::rust::Error>::error(error$);
+  }
+  return ::std::move(return$.value);
+}
+
+::rust::String GGRSSession::desync_report_path() {
+  ::rust::MaybeUninit<::rust::String> return$;
+  ::rust::repr::PtrLen error$ = GGRS$cxxbridge1$GGRSSession$desync_report_path(*this, &return$.value);
+  if (error$.ptr) {
+    throw ::rust::impl<::rust::Error>::error(error$);
+  }
+  return ::std::move(return$.value);
+}
+
+::rust::String GGRSSession::local_address() {
+  ::rust::MaybeUninit<::rust::String> return$;
+  ::rust::repr::PtrLen error$ = GGRS$cxxbridge1$GGRSSession$local_address(*this, &return$.value);
+  if (error$.ptr) {
+    throw ::rust::impl<::rust::Error>::error(error$);
+  }
+  return ::std::move(return$.value);
+}
+
+bool GGRSSession::set_event_handler(::std::unique_ptr<::GGRS::GGRSEventHandler> handler) {
+  ::rust::MaybeUninit<bool> return$;
+  ::rust::repr::PtrLen error$ = GGRS$cxxbridge1$GGRSSession$set_event_handler(*this, handler.release(), &return$.value);
+  if (error$.ptr) {
+    throw ::rust::impl<::rust::Error>::error(error$);
+  }
+  return ::std::move(return$.value);
+}
+
+::GGRS::GGRSErrorInfo GGRSSessionBuilder::try_with_num_players(::std::uint32_t num_players) noexcept {
+  ::rust::MaybeUninit<::GGRS::GGRSErrorInfo> return$;
+  GGRS$cxxbridge1$GGRSSessionBuilder$try_with_num_players(*this, num_players, &return$.value);
+  return ::std::move(return$.value);
+}
+
+::GGRS::GGRSErrorInfo GGRSSessionBuilder::try_with_input_size(::std::uint32_t bytes) noexcept {
+  ::rust::MaybeUninit<::GGRS::GGRSErrorInfo> return$;
+  GGRS$cxxbridge1$GGRSSessionBuilder$try_with_input_size(*this, bytes, &return$.value);
+  return ::std::move(return$.value);
+}
+
+::GGRS::GGRSErrorInfo GGRSSessionBuilder::try_with_fps(::std::uint32_t fps) noexcept {
+  ::rust::MaybeUninit<::GGRS::GGRSErrorInfo> return$;
+  GGRS$cxxbridge1$GGRSSessionBuilder$try_with_fps(*this, fps, &return$.value);
+  return ::std::move(return$.value);
+}
+
+::GGRS::GGRSErrorInfo GGRSSessionBuilder::try_with_input_delay(::std::uint32_t delay) noexcept {
+  ::rust::MaybeUninit<::GGRS::GGRSErrorInfo> return$;
+  GGRS$cxxbridge1$GGRSSessionBuilder$try_with_input_delay(*this, delay, &return$.value);
+  return ::std::move(return$.value);
+}
+
+::GGRS::GGRSErrorInfo GGRSSessionBuilder::try_with_max_prediction_window(::std::uint32_t window) noexcept {
+  ::rust::MaybeUninit<::GGRS::GGRSErrorInfo> return$;
+  GGRS$cxxbridge1$GGRSSessionBuilder$try_with_max_prediction_window(*this, window, &return$.value);
+  return ::std::move(return$.value);
+}
+
+::GGRS::GGRSErrorInfo GGRSSessionBuilder::try_with_max_frames_behind(::std::uint32_t max_frames_behind) noexcept {
+  ::rust::MaybeUninit<::GGRS::GGRSErrorInfo> return$;
+  GGRS$cxxbridge1$GGRSSessionBuilder$try_with_max_frames_behind(*this, max_frames_behind, &return$.value);
+  return ::std::move(return$.value);
+}
+
+::GGRS::GGRSErrorInfo GGRSSessionBuilder::try_with_catchup_speed(::std::uint32_t catchup_speed) noexcept {
+  ::rust::MaybeUninit<::GGRS::GGRSErrorInfo> return$;
+  GGRS$cxxbridge1$GGRSSessionBuilder$try_with_catchup_speed(*this, catchup_speed, &return$.value);
+  return ::std::move(return$.value);
+}
+
+::GGRS::GGRSErrorInfo GGRSSessionBuilder::try_with_check_distance(::std::uint32_t check_distance) noexcept {
+  ::rust::MaybeUninit<::GGRS::GGRSErrorInfo> return$;
+  GGRS$cxxbridge1$GGRSSessionBuilder$try_with_check_distance(*this, check_distance, &return$.value);
+  return ::std::move(return$.value);
+}
+
+::GGRS::GGRSErrorInfo GGRSSessionBuilder::try_with_disconnect_timeout(::std::uint64_t timeout_ms) noexcept {
+  ::rust::MaybeUninit<::GGRS::GGRSErrorInfo> return$;
+  GGRS$cxxbridge1$GGRSSessionBuilder$try_with_disconnect_timeout(*this, timeout_ms, &return$.value);
+  return ::std::move(return$.value);
+}
+
+::GGRS::GGRSErrorInfo GGRSSessionBuilder::try_with_disconnect_notify_delay(::std::uint64_t notify_delay_ms) noexcept {
+  ::rust::MaybeUninit<::GGRS::GGRSErrorInfo> return$;
+  GGRS$cxxbridge1$GGRSSessionBuilder$try_with_disconnect_notify_delay(*this, notify_delay_ms, &return$.value);
+  return ::std::move(return$.value);
+}
+
+::GGRS::GGRSErrorInfo GGRSSessionBuilder::try_with_network_conditions(::GGRS::GGRSNetworkConditions conditions) noexcept {
+  ::rust::MaybeUninit<::GGRS::GGRSErrorInfo> return$;
+  GGRS$cxxbridge1$GGRSSessionBuilder$try_with_network_conditions(*this, conditions, &return$.value);
+  return ::std::move(return$.value);
+}
+
+::GGRS::GGRSErrorInfo GGRSSessionBuilder::try_with_sparse_saving_mode(bool enable) noexcept {
+  ::rust::MaybeUninit<::GGRS::GGRSErrorInfo> return$;
+  GGRS$cxxbridge1$GGRSSessionBuilder$try_with_sparse_saving_mode(*this, enable, &return$.value);
+  return ::std::move(return$.value);
+}
+
+::GGRS::GGRSErrorInfo GGRSSessionBuilder::try_with_desync_detection_interval(::std::uint32_t interval) noexcept {
+  ::rust::MaybeUninit<::GGRS::GGRSErrorInfo> return$;
+  GGRS$cxxbridge1$GGRSSessionBuilder$try_with_desync_detection_interval(*this, interval, &return$.value);
+  return ::std::move(return$.value);
+}
+
+::GGRS::GGRSErrorInfo GGRSSessionBuilder::try_with_desync_report_dir(::rust::Str dir) noexcept {
+  ::rust::MaybeUninit<::GGRS::GGRSErrorInfo> return$;
+  GGRS$cxxbridge1$GGRSSessionBuilder$try_with_desync_report_dir(*this, dir, &return$.value);
+  return ::std::move(return$.value);
+}
+
+::GGRS::GGRSErrorInfo GGRSSessionBuilder::try_with_desync_report_frames(::std::uint32_t frames) noexcept {
+  ::rust::MaybeUninit<::GGRS::GGRSErrorInfo> return$;
+  GGRS$cxxbridge1$GGRSSessionBuilder$try_with_desync_report_frames(*this, frames, &return$.value);
+  return ::std::move(return$.value);
+}
+
+::GGRS::GGRSErrorInfo GGRSSessionBuilder::try_with_address_preference(::GGRS::GGRSAddressPreference preference) noexcept {
+  ::rust::MaybeUninit<::GGRS::GGRSErrorInfo> return$;
+  GGRS$cxxbridge1$GGRSSessionBuilder$try_with_address_preference(*this, preference, &return$.value);
+  return ::std::move(return$.value);
+}
+
+::GGRS::GGRSErrorInfo GGRSSessionBuilder::try_with_bind_address(::rust::Str ip) noexcept {
+  ::rust::MaybeUninit<::GGRS::GGRSErrorInfo> return$;
+  GGRS$cxxbridge1$GGRSSessionBuilder$try_with_bind_address(*this, ip, &return$.value);
+  return ::std::move(return$.value);
+}
+
+::GGRS::GGRSErrorInfo GGRSSessionBuilder::try_with_pacing_mode(::GGRS::GGRSPacingMode mode) noexcept {
+  ::rust::MaybeUninit<::GGRS::GGRSErrorInfo> return$;
+  GGRS$cxxbridge1$GGRSSessionBuilder$try_with_pacing_mode(*this, mode, &return$.value);
+  return ::std::move(return$.value);
+}
+
+::GGRS::GGRSErrorInfo GGRSSessionBuilder::try_add_player(::GGRS::GGRSPlayer player) noexcept {
+  ::rust::ManuallyDrop<::GGRS::GGRSPlayer> player$(::std::move(player));
+  ::rust::MaybeUninit<::GGRS::GGRSErrorInfo> return$;
+  GGRS$cxxbridge1$GGRSSessionBuilder$try_add_player(*this, &player$.value, &return$.value);
+  return ::std::move(return$.value);
+}
+
+::rust::Box<::GGRS::GGRSSession> GGRSSessionBuilder::try_start_p2p_session(::std::uint16_t local_port, ::GGRS::GGRSErrorInfo &error) noexcept {
+  return ::rust::Box<::GGRS::GGRSSession>::from_raw(GGRS$cxxbridge1$GGRSSessionBuilder$try_start_p2p_session(*this, local_port, error));
+}
+
+::rust::Box<::GGRS::GGRSSession> GGRSSessionBuilder::try_start_spectator_session(::std::uint16_t local_port, ::rust::Str host, ::GGRS::GGRSErrorInfo &error) noexcept {
+  return ::rust::Box<::GGRS::GGRSSession>::from_raw(GGRS$cxxbridge1$GGRSSessionBuilder$try_start_spectator_session(*this, local_port, host, error));
+}
+
+::rust::Box<::GGRS::GGRSSession> GGRSSessionBuilder::try_start_synctest_session(::GGRS::GGRSErrorInfo &error) noexcept {
+  return ::rust::Box<::GGRS::GGRSSession>::from_raw(GGRS$cxxbridge1$GGRSSessionBuilder$try_start_synctest_session(*this, error));
+}
+
+::rust::Box<::GGRS::GGRSSession> GGRSSessionBuilder::try_start_p2p_session_with_socket(::std::unique_ptr<::GGRS::GGRSNonBlockingSocket> socket, ::GGRS::GGRSErrorInfo &error) noexcept {
+  return ::rust::Box<::GGRS::GGRSSession>::from_raw(GGRS$cxxbridge1$GGRSSessionBuilder$try_start_p2p_session_with_socket(*this, socket.release(), error));
+}
+
+::rust::Box<::GGRS::GGRSSession> GGRSSessionBuilder::try_start_spectator_session_with_socket(::rust::Str host, ::std::unique_ptr<::GGRS::GGRSNonBlockingSocket> socket, ::GGRS::GGRSErrorInfo &error) noexcept {
+  return ::rust::Box<::GGRS::GGRSSession>::from_raw(GGRS$cxxbridge1$GGRSSessionBuilder$try_start_spectator_session_with_socket(*this, host, socket.release(), error));
+}
+
+::rust::Box<::GGRS::GGRSSession> GGRSSessionBuilder::try_start_p2p_session_on_loopback(::rust::Str local_name, ::GGRS::GGRSErrorInfo &error) noexcept {
+  return ::rust::Box<::GGRS::GGRSSession>::from_raw(GGRS$cxxbridge1$GGRSSessionBuilder$try_start_p2p_session_on_loopback(*this, local_name, error));
+}
+
+::rust::Box<::GGRS::GGRSSession> GGRSSessionBuilder::try_start_spectator_session_on_loopback(::rust::Str local_name, ::rust::Str host, ::GGRS::GGRSErrorInfo &error) noexcept {
+  return ::rust::Box<::GGRS::GGRSSession>::from_raw(GGRS$cxxbridge1$GGRSSessionBuilder$try_start_spectator_session_on_loopback(*this, local_name, host, error));
+}
+
+::GGRS::GGRSErrorInfo GGRSSession::try_poll_remote_clients() noexcept {
+  ::rust::MaybeUninit<::GGRS::GGRSErrorInfo> return$;
+  GGRS$cxxbridge1$GGRSSession$try_poll_remote_clients(*this, &return$.value);
+  return ::std::move(return$.value);
+}
+
+::GGRS::GGRSErrorInfo GGRSSession::try_add_local_input(::std::uint32_t player_handle, ::rust::Slice<::std::uint8_t const> input) noexcept {
+  ::rust::MaybeUninit<::GGRS::GGRSErrorInfo> return$;
+  GGRS$cxxbridge1$GGRSSession$try_add_local_input(*this, player_handle, input, &return$.value);
+  return ::std::move(return$.value);
+}
+
+::GGRS::GGRSErrorInfo GGRSSession::try_add_local_inputs(::rust::Slice<::GGRS::GGRSLocalInput const> local_inputs) noexcept {
+  ::rust::MaybeUninit<::GGRS::GGRSErrorInfo> return$;
+  GGRS$cxxbridge1$GGRSSession$try_add_local_inputs(*this, local_inputs, &return$.value);
+  return ::std::move(return$.value);
+}
+
+::GGRS::GGRSErrorInfo GGRSSession::try_get_current_state(::GGRS::GGRSSessionState &state) noexcept {
+  ::rust::MaybeUninit<::GGRS::GGRSErrorInfo> return$;
+  GGRS$cxxbridge1$GGRSSession$try_get_current_state(*this, state, &return$.value);
+  return ::std::move(return$.value);
+}
+
+::GGRS::GGRSErrorInfo GGRSSession::try_get_events(::rust::Vec<::GGRS::GGRSEvent> &events) noexcept {
+  ::rust::MaybeUninit<::GGRS::GGRSErrorInfo> return$;
+  GGRS$cxxbridge1$GGRSSession$try_get_events(*this, events, &return$.value);
+  return ::std::move(return$.value);
+}
+
+::GGRS::GGRSErrorInfo GGRSSession::try_advance_frame(::GGRS::GGRSFrameResult &result) noexcept {
+  ::rust::MaybeUninit<::GGRS::GGRSErrorInfo> return$;
+  GGRS$cxxbridge1$GGRSSession$try_advance_frame(*this, result, &return$.value);
+  return ::std::move(return$.value);
+}
+
+::GGRS::GGRSErrorInfo GGRSSession::try_frame_pacing(::GGRS::GGRSFramePacing &pacing) noexcept {
+  ::rust::MaybeUninit<::GGRS::GGRSErrorInfo> return$;
+  GGRS$cxxbridge1$GGRSSession$try_frame_pacing(*this, pacing, &return$.value);
+  return ::std::move(return$.value);
+}
+
+::GGRS::GGRSErrorInfo GGRSSession::try_tick(::rust::Slice<::GGRS::GGRSLocalInput const> local_inputs, ::GGRS::GGRSTickResult &result) noexcept {
+  ::rust::MaybeUninit<::GGRS::GGRSErrorInfo> return$;
+  GGRS$cxxbridge1$GGRSSession$try_tick(*this, local_inputs, result, &return$.value);
+  return ::std::move(return$.value);
+}
+
+::GGRS::GGRSErrorInfo GGRSSession::try_save_game_state(::std::int32_t frame, ::rust::Slice<::std::uint8_t const> state) noexcept {
+  ::rust::MaybeUninit<::GGRS::GGRSErrorInfo> return$;
+  GGRS$cxxbridge1$GGRSSession$try_save_game_state(*this, frame, state, &return$.value);
+  return ::std::move(return$.value);
+}
+
+::GGRS::GGRSErrorInfo GGRSSession::try_submit_checksum(::std::int32_t frame, ::std::uint64_t checksum_hi, ::std::uint64_t checksum_lo) noexcept {
+  ::rust::MaybeUninit<::GGRS::GGRSErrorInfo> return$;
+  GGRS$cxxbridge1$GGRSSession$try_submit_checksum(*this, frame, checksum_hi, checksum_lo, &return$.value);
+  return ::std::move(return$.value);
+}
+
+::GGRS::GGRSErrorInfo GGRSSession::try_get_frames_ahead(::std::int32_t &frames_ahead) noexcept {
+  ::rust::MaybeUninit<::GGRS::GGRSErrorInfo> return$;
+  GGRS$cxxbridge1$GGRSSession$try_get_frames_ahead(*this, frames_ahead, &return$.value);
+  return ::std::move(return$.value);
+}
+
+::GGRS::GGRSErrorInfo GGRSSession::try_network_stats(::std::uint32_t player_handle, ::GGRS::GGRSNetworkStats &stats) noexcept {
+  ::rust::MaybeUninit<::GGRS::GGRSErrorInfo> return$;
+  GGRS$cxxbridge1$GGRSSession$try_network_stats(*this, player_handle, stats, &return$.value);
+  return ::std::move(return$.value);
+}
+
+::GGRS::GGRSErrorInfo GGRSSession::try_disconnect_player(::std::uint32_t player_handle) noexcept {
+  ::rust::MaybeUninit<::GGRS::GGRSErrorInfo> return$;
+  GGRS$cxxbridge1$GGRSSession$try_disconnect_player(*this, player_handle, &return$.value);
+  return ::std::move(return$.value);
+}
+
+::GGRS::GGRSErrorInfo GGRSSession::try_start_recording(::rust::Str path) noexcept {
+  ::rust::MaybeUninit<::GGRS::GGRSErrorInfo> return$;
+  GGRS$cxxbridge1$GGRSSession$try_start_recording(*this, path, &return$.value);
+  return ::std::move(return$.value);
+}
+
+::GGRS::GGRSErrorInfo GGRSSession::try_stop_recording() noexcept {
+  ::rust::MaybeUninit<::GGRS::GGRSErrorInfo> return$;
+  GGRS$cxxbridge1$GGRSSession$try_stop_recording(*this, &return$.value);
+  return ::std::move(return$.value);
+}
+
+::GGRS::GGRSErrorInfo GGRSSession::try_desync_report_path(::rust::String &path) noexcept {
+  ::rust::MaybeUninit<::GGRS::GGRSErrorInfo> return$;
+  GGRS$cxxbridge1$GGRSSession$try_desync_report_path(*this, path, &return$.value);
+  return ::std::move(return$.value);
+}
+
+::GGRS::GGRSErrorInfo GGRSSession::try_local_address(::rust::String &addr) noexcept {
+  ::rust::MaybeUninit<::GGRS::GGRSErrorInfo> return$;
+  GGRS$cxxbridge1$GGRSSession$try_local_address(*this, addr, &return$.value);
+  return ::std::move(return$.value);
+}
+
+::GGRS::GGRSErrorInfo GGRSSession::try_set_event_handler(::std::unique_ptr<::GGRS::GGRSEventHandler> handler) noexcept {
+  ::rust::MaybeUninit<::GGRS::GGRSErrorInfo> return$;
+  GGRS$cxxbridge1$GGRSSession$try_set_event_handler(*this, handler.release(), &return$.value);
+  return ::std::move(return$.value);
+}
+
+::rust::Box<::GGRS::GGRSSession> try_start_replay_session(::rust::Str path, ::GGRS::GGRSErrorInfo &error) noexcept {
+  return ::rust::Box<::GGRS::GGRSSession>::from_raw(GGRS$cxxbridge1$try_start_replay_session(path, error));
+}
+} // namespace GGRS
+
+extern "C" {
+void cxxbridge1$rust_vec$GGRS$GGRSFrameAction$new(::rust::Vec<::GGRS::GGRSFrameAction> const *ptr) noexcept;
+void cxxbridge1$rust_vec$GGRS$GGRSFrameAction$drop(::rust::Vec<::GGRS::GGRSFrameAction> *ptr) noexcept;
+::std::size_t cxxbridge1$rust_vec$GGRS$GGRSFrameAction$len(::rust::Vec<::GGRS::GGRSFrameAction> const *ptr) noexcept;
+::std::size_t cxxbridge1$rust_vec$GGRS$GGRSFrameAction$capacity(::rust::Vec<::GGRS::GGRSFrameAction> const *ptr) noexcept;
+::GGRS::GGRSFrameAction const *cxxbridge1$rust_vec$GGRS$GGRSFrameAction$data(::rust::Vec<::GGRS::GGRSFrameAction> const *ptr) noexcept;
+void cxxbridge1$rust_vec$GGRS$GGRSFrameAction$reserve_total(::rust::Vec<::GGRS::GGRSFrameAction> *ptr, ::std::size_t new_cap) noexcept;
+void cxxbridge1$rust_vec$GGRS$GGRSFrameAction$set_len(::rust::Vec<::GGRS::GGRSFrameAction> *ptr, ::std::size_t len) noexcept;
+void cxxbridge1$rust_vec$GGRS$GGRSFrameAction$truncate(::rust::Vec<::GGRS::GGRSFrameAction> *ptr, ::std::size_t len) noexcept;
+
+void cxxbridge1$rust_vec$GGRS$GGRSChecksumMismatch$new(::rust::Vec<::GGRS::GGRSChecksumMismatch> const *ptr) noexcept;
+void cxxbridge1$rust_vec$GGRS$GGRSChecksumMismatch$drop(::rust::Vec<::GGRS::GGRSChecksumMismatch> *ptr) noexcept;
+::std::size_t cxxbridge1$rust_vec$GGRS$GGRSChecksumMismatch$len(::rust::Vec<::GGRS::GGRSChecksumMismatch> const *ptr) noexcept;
+::std::size_t cxxbridge1$rust_vec$GGRS$GGRSChecksumMismatch$capacity(::rust::Vec<::GGRS::GGRSChecksumMismatch> const *ptr) noexcept;
+::GGRS::GGRSChecksumMismatch const *cxxbridge1$rust_vec$GGRS$GGRSChecksumMismatch$data(::rust::Vec<::GGRS::GGRSChecksumMismatch> const *ptr) noexcept;
+void cxxbridge1$rust_vec$GGRS$GGRSChecksumMismatch$reserve_total(::rust::Vec<::GGRS::GGRSChecksumMismatch> *ptr, ::std::size_t new_cap) noexcept;
+void cxxbridge1$rust_vec$GGRS$GGRSChecksumMismatch$set_len(::rust::Vec<::GGRS::GGRSChecksumMismatch> *ptr, ::std::size_t len) noexcept;
+void cxxbridge1$rust_vec$GGRS$GGRSChecksumMismatch$truncate(::rust::Vec<::GGRS::GGRSChecksumMismatch> *ptr, ::std::size_t len) noexcept;
+
+void cxxbridge1$rust_vec$GGRS$GGRSEvent$new(::rust::Vec<::GGRS::GGRSEvent> const *ptr) noexcept;
+void cxxbridge1$rust_vec$GGRS$GGRSEvent$drop(::rust::Vec<::GGRS::GGRSEvent> *ptr) noexcept;
+::std::size_t cxxbridge1$rust_vec$GGRS$GGRSEvent$len(::rust::Vec<::GGRS::GGRSEvent> const *ptr) noexcept;
+::std::size_t cxxbridge1$rust_vec$GGRS$GGRSEvent$capacity(::rust::Vec<::GGRS::GGRSEvent> const *ptr) noexcept;
+::GGRS::GGRSEvent const *cxxbridge1$rust_vec$GGRS$GGRSEvent$data(::rust::Vec<::GGRS::GGRSEvent> const *ptr) noexcept;
+void cxxbridge1$rust_vec$GGRS$GGRSEvent$reserve_total(::rust::Vec<::GGRS::GGRSEvent> *ptr, ::std::size_t new_cap) noexcept;
+void cxxbridge1$rust_vec$GGRS$GGRSEvent$set_len(::rust::Vec<::GGRS::GGRSEvent> *ptr, ::std::size_t len) noexcept;
+void cxxbridge1$rust_vec$GGRS$GGRSEvent$truncate(::rust::Vec<::GGRS::GGRSEvent> *ptr, ::std::size_t len) noexcept;
+
+void cxxbridge1$rust_vec$GGRS$GGRSInput$new(::rust::Vec<::GGRS::GGRSInput> const *ptr) noexcept;
+void cxxbridge1$rust_vec$GGRS$GGRSInput$drop(::rust::Vec<::GGRS::GGRSInput> *ptr) noexcept;
+::std::size_t cxxbridge1$rust_vec$GGRS$GGRSInput$len(::rust::Vec<::GGRS::GGRSInput> const *ptr) noexcept;
+::std::size_t cxxbridge1$rust_vec$GGRS$GGRSInput$capacity(::rust::Vec<::GGRS::GGRSInput> const *ptr) noexcept;
+::GGRS::GGRSInput const *cxxbridge1$rust_vec$GGRS$GGRSInput$data(::rust::Vec<::GGRS::GGRSInput> const *ptr) noexcept;
+void cxxbridge1$rust_vec$GGRS$GGRSInput$reserve_total(::rust::Vec<::GGRS::GGRSInput> *ptr, ::std::size_t new_cap) noexcept;
+void cxxbridge1$rust_vec$GGRS$GGRSInput$set_len(::rust::Vec<::GGRS::GGRSInput> *ptr, ::std::size_t len) noexcept;
+void cxxbridge1$rust_vec$GGRS$GGRSInput$truncate(::rust::Vec<::GGRS::GGRSInput> *ptr, ::std::size_t len) noexcept;
+
+void cxxbridge1$rust_vec$GGRS$GGRSReceivedMessage$new(::rust::Vec<::GGRS::GGRSReceivedMessage> const *ptr) noexcept;
+void cxxbridge1$rust_vec$GGRS$GGRSReceivedMessage$drop(::rust::Vec<::GGRS::GGRSReceivedMessage> *ptr) noexcept;
+::std::size_t cxxbridge1$rust_vec$GGRS$GGRSReceivedMessage$len(::rust::Vec<::GGRS::GGRSReceivedMessage> const *ptr) noexcept;
+::std::size_t cxxbridge1$rust_vec$GGRS$GGRSReceivedMessage$capacity(::rust::Vec<::GGRS::GGRSReceivedMessage> const *ptr) noexcept;
+::GGRS::GGRSReceivedMessage const *cxxbridge1$rust_vec$GGRS$GGRSReceivedMessage$data(::rust::Vec<::GGRS::GGRSReceivedMessage> const *ptr) noexcept;
+void cxxbridge1$rust_vec$GGRS$GGRSReceivedMessage$reserve_total(::rust::Vec<::GGRS::GGRSReceivedMessage> *ptr, ::std::size_t new_cap) noexcept;
+void cxxbridge1$rust_vec$GGRS$GGRSReceivedMessage$set_len(::rust::Vec<::GGRS::GGRSReceivedMessage> *ptr, ::std::size_t len) noexcept;
+void cxxbridge1$rust_vec$GGRS$GGRSReceivedMessage$truncate(::rust::Vec<::GGRS::GGRSReceivedMessage> *ptr, ::std::size_t len) noexcept;
+
+::GGRS::GGRSSessionBuilder *cxxbridge1$box$GGRS$GGRSSessionBuilder$alloc() noexcept;
+void cxxbridge1$box$GGRS$GGRSSessionBuilder$dealloc(::GGRS::GGRSSessionBuilder *) noexcept;
+void cxxbridge1$box$GGRS$GGRSSessionBuilder$drop(::rust::Box<::GGRS::GGRSSessionBuilder> *ptr) noexcept;
+
+::GGRS::GGRSSession *cxxbridge1$box$GGRS$GGRSSession$alloc() noexcept;
+void cxxbridge1$box$GGRS$GGRSSession$dealloc(::GGRS::GGRSSession *) noexcept;
+void cxxbridge1$box$GGRS$GGRSSession$drop(::rust::Box<::GGRS::GGRSSession> *ptr) noexcept;
+
+static_assert(::rust::detail::is_complete<::GGRS::GGRSNonBlockingSocket>::value, "definition of GGRSNonBlockingSocket is required");
+static_assert(sizeof(::std::unique_ptr<::GGRS::GGRSNonBlockingSocket>) == sizeof(void *), "");
+static_assert(alignof(::std::unique_ptr<::GGRS::GGRSNonBlockingSocket>) == alignof(void *), "");
+void cxxbridge1$unique_ptr$GGRS$GGRSNonBlockingSocket$null(::std::unique_ptr<::GGRS::GGRSNonBlockingSocket> *ptr) noexcept {
+  ::new (ptr) ::std::unique_ptr<::GGRS::GGRSNonBlockingSocket>();
+}
+void cxxbridge1$unique_ptr$GGRS$GGRSNonBlockingSocket$raw(::std::unique_ptr<::GGRS::GGRSNonBlockingSocket> *ptr, ::GGRS::GGRSNonBlockingSocket *raw) noexcept {
+  ::new (ptr) ::std::unique_ptr<::GGRS::GGRSNonBlockingSocket>(raw);
+}
+::GGRS::GGRSNonBlockingSocket const *cxxbridge1$unique_ptr$GGRS$GGRSNonBlockingSocket$get(::std::unique_ptr<::GGRS::GGRSNonBlockingSocket> const &ptr) noexcept {
+  return ptr.get();
+}
+::GGRS::GGRSNonBlockingSocket *cxxbridge1$unique_ptr$GGRS$GGRSNonBlockingSocket$release(::std::unique_ptr<::GGRS::GGRSNonBlockingSocket> &ptr) noexcept {
+  return ptr.release();
+}
+void cxxbridge1$unique_ptr$GGRS$GGRSNonBlockingSocket$drop(::std::unique_ptr<::GGRS::GGRSNonBlockingSocket> *ptr) noexcept {
+  ::rust::deleter_if<::rust::detail::is_complete<::GGRS::GGRSNonBlockingSocket>::value>{}(ptr);
+}
+
+static_assert(::rust::detail::is_complete<::GGRS::GGRSEventHandler>::value, "definition of GGRSEventHandler is required");
+static_assert(sizeof(::std::unique_ptr<::GGRS::GGRSEventHandler>) == sizeof(void *), "");
+static_assert(alignof(::std::unique_ptr<::GGRS::GGRSEventHandler>) == alignof(void *), "");
+void cxxbridge1$unique_ptr$GGRS$GGRSEventHandler$null(::std::unique_ptr<::GGRS::GGRSEventHandler> *ptr) noexcept {
+  ::new (ptr) ::std::unique_ptr<::GGRS::GGRSEventHandler>();
+}
+void cxxbridge1$unique_ptr$GGRS$GGRSEventHandler$raw(::std::unique_ptr<::GGRS::GGRSEventHandler> *ptr, ::GGRS::GGRSEventHandler *raw) noexcept {
+  ::new (ptr) ::std::unique_ptr<::GGRS::GGRSEventHandler>(raw);
+}
+::GGRS::GGRSEventHandler const *cxxbridge1$unique_ptr$GGRS$GGRSEventHandler$get(::std::unique_ptr<::GGRS::GGRSEventHandler> const &ptr) noexcept {
+  return ptr.get();
+}
+::GGRS::GGRSEventHandler *cxxbridge1$unique_ptr$GGRS$GGRSEventHandler$release(::std::unique_ptr<::GGRS::GGRSEventHandler> &ptr) noexcept {
+  return ptr.release();
+}
+void cxxbridge1$unique_ptr$GGRS$GGRSEventHandler$drop(::std::unique_ptr<::GGRS::GGRSEventHandler> *ptr) noexcept {
+  ::rust::deleter_if<::rust::detail::is_complete<::GGRS::GGRSEventHandler>::value>{}(ptr);
+}
+} // extern "C"
+
+namespace rust {
+inline namespace cxxbridge1 {
 template <>
 Vec<::GGRS::GGRSFrameAction>::Vec() noexcept {
   cxxbridge1$rust_vec$GGRS$GGRSFrameAction$new(this);
@@ -500,36 +1631,36 @@ void Vec<::GGRS::GGRSFrameAction>::truncate(::std::size_t len) {
   return cxxbridge1$rust_vec$GGRS$GGRSFrameAction$truncate(this, len);
 }
 template <>
-Vec<::GGRS::GGRSInput>::Vec() noexcept {
-  cxxbridge1$rust_vec$GGRS$GGRSInput$new(this);
+Vec<::GGRS::GGRSChecksumMismatch>::Vec() noexcept {
+  cxxbridge1$rust_vec$GGRS$GGRSChecksumMismatch$new(this);
 }
 template <>
-void Vec<::GGRS::GGRSInput>::drop() noexcept {
-  return cxxbridge1$rust_vec$GGRS$GGRSInput$drop(this);
+void Vec<::GGRS::GGRSChecksumMismatch>::drop() noexcept {
+  return cxxbridge1$rust_vec$GGRS$GGRSChecksumMismatch$drop(this);
 }
 template <>
-::std::size_t Vec<::GGRS::GGRSInput>::size() const noexcept {
-  return cxxbridge1$rust_vec$GGRS$GGRSInput$len(this);
+::std::size_t Vec<::GGRS::GGRSChecksumMismatch>::size() const noexcept {
+  return cxxbridge1$rust_vec$GGRS$GGRSChecksumMismatch$len(this);
 }
 template <>
-::std::size_t Vec<::GGRS::GGRSInput>::capacity() const noexcept {
-  return cxxbridge1$rust_vec$GGRS$GGRSInput$capacity(this);
+::std::size_t Vec<::GGRS::GGRSChecksumMismatch>::capacity() const noexcept {
+  return cxxbridge1$rust_vec$GGRS$GGRSChecksumMismatch$capacity(this);
 }
 template <>
-::GGRS::GGRSInput const *Vec<::GGRS::GGRSInput>::data() const noexcept {
-  return cxxbridge1$rust_vec$GGRS$GGRSInput$data(this);
+::GGRS::GGRSChecksumMismatch const *Vec<::GGRS::GGRSChecksumMismatch>::data() const noexcept {
+  return cxxbridge1$rust_vec$GGRS$GGRSChecksumMismatch$data(this);
 }
 template <>
-void Vec<::GGRS::GGRSInput>::reserve_total(::std::size_t new_cap) noexcept {
-  return cxxbridge1$rust_vec$GGRS$GGRSInput$reserve_total(this, new_cap);
+void Vec<::GGRS::GGRSChecksumMismatch>::reserve_total(::std::size_t new_cap) noexcept {
+  return cxxbridge1$rust_vec$GGRS$GGRSChecksumMismatch$reserve_total(this, new_cap);
 }
 template <>
-void Vec<::GGRS::GGRSInput>::set_len(::std::size_t len) noexcept {
-  return cxxbridge1$rust_vec$GGRS$GGRSInput$set_len(this, len);
+void Vec<::GGRS::GGRSChecksumMismatch>::set_len(::std::size_t len) noexcept {
+  return cxxbridge1$rust_vec$GGRS$GGRSChecksumMismatch$set_len(this, len);
 }
 template <>
-void Vec<::GGRS::GGRSInput>::truncate(::std::size_t len) {
-  return cxxbridge1$rust_vec$GGRS$GGRSInput$truncate(this, len);
+void Vec<::GGRS::GGRSChecksumMismatch>::truncate(::std::size_t len) {
+  return cxxbridge1$rust_vec$GGRS$GGRSChecksumMismatch$truncate(this, len);
 }
 template <>
 Vec<::GGRS::GGRSEvent>::Vec() noexcept {
@@ -563,5 +1694,93 @@ template <>
 void Vec<::GGRS::GGRSEvent>::truncate(::std::size_t len) {
   return cxxbridge1$rust_vec$GGRS$GGRSEvent$truncate(this, len);
 }
+template <>
+Vec<::GGRS::GGRSInput>::Vec() noexcept {
+  cxxbridge1$rust_vec$GGRS$GGRSInput$new(this);
+}
+template <>
+void Vec<::GGRS::GGRSInput>::drop() noexcept {
+  return cxxbridge1$rust_vec$GGRS$GGRSInput$drop(this);
+}
+template <>
+::std::size_t Vec<::GGRS::GGRSInput>::size() const noexcept {
+  return cxxbridge1$rust_vec$GGRS$GGRSInput$len(this);
+}
+template <>
+::std::size_t Vec<::GGRS::GGRSInput>::capacity() const noexcept {
+  return cxxbridge1$rust_vec$GGRS$GGRSInput$capacity(this);
+}
+template <>
+::GGRS::GGRSInput const *Vec<::GGRS::GGRSInput>::data() const noexcept {
+  return cxxbridge1$rust_vec$GGRS$GGRSInput$data(this);
+}
+template <>
+void Vec<::GGRS::GGRSInput>::reserve_total(::std::size_t new_cap) noexcept {
+  return cxxbridge1$rust_vec$GGRS$GGRSInput$reserve_total(this, new_cap);
+}
+template <>
+void Vec<::GGRS::GGRSInput>::set_len(::std::size_t len) noexcept {
+  return cxxbridge1$rust_vec$GGRS$GGRSInput$set_len(this, len);
+}
+template <>
+void Vec<::GGRS::GGRSInput>::truncate(::std::size_t len) {
+  return cxxbridge1$rust_vec$GGRS$GGRSInput$truncate(this, len);
+}
+template <>
+Vec<::GGRS::GGRSReceivedMessage>::Vec() noexcept {
+  cxxbridge1$rust_vec$GGRS$GGRSReceivedMessage$new(this);
+}
+template <>
+void Vec<::GGRS::GGRSReceivedMessage>::drop() noexcept {
+  return cxxbridge1$rust_vec$GGRS$GGRSReceivedMessage$drop(this);
+}
+template <>
+::std::size_t Vec<::GGRS::GGRSReceivedMessage>::size() const noexcept {
+  return cxxbridge1$rust_vec$GGRS$GGRSReceivedMessage$len(this);
+}
+template <>
+::std::size_t Vec<::GGRS::GGRSReceivedMessage>::capacity() const noexcept {
+  return cxxbridge1$rust_vec$GGRS$GGRSReceivedMessage$capacity(this);
+}
+template <>
+::GGRS::GGRSReceivedMessage const *Vec<::GGRS::GGRSReceivedMessage>::data() const noexcept {
+  return cxxbridge1$rust_vec$GGRS$GGRSReceivedMessage$data(this);
+}
+template <>
+void Vec<::GGRS::GGRSReceivedMessage>::reserve_total(::std::size_t new_cap) noexcept {
+  return cxxbridge1$rust_vec$GGRS$GGRSReceivedMessage$reserve_total(this, new_cap);
+}
+template <>
+void Vec<::GGRS::GGRSReceivedMessage>::set_len(::std::size_t len) noexcept {
+  return cxxbridge1$rust_vec$GGRS$GGRSReceivedMessage$set_len(this, len);
+}
+template <>
+void Vec<::GGRS::GGRSReceivedMessage>::truncate(::std::size_t len) {
+  return cxxbridge1$rust_vec$GGRS$GGRSReceivedMessage$truncate(this, len);
+}
+template <>
+::GGRS::GGRSSessionBuilder *Box<::GGRS::GGRSSessionBuilder>::allocation::alloc() noexcept {
+  return cxxbridge1$box$GGRS$GGRSSessionBuilder$alloc();
+}
+template <>
+void Box<::GGRS::GGRSSessionBuilder>::allocation::dealloc(::GGRS::GGRSSessionBuilder *ptr) noexcept {
+  cxxbridge1$box$GGRS$GGRSSessionBuilder$dealloc(ptr);
+}
+template <>
+void Box<::GGRS::GGRSSessionBuilder>::drop() noexcept {
+  cxxbridge1$box$GGRS$GGRSSessionBuilder$drop(this);
+}
+template <>
+::GGRS::GGRSSession *Box<::GGRS::GGRSSession>::allocation::alloc() noexcept {
+  return cxxbridge1$box$GGRS$GGRSSession$alloc();
+}
+template <>
+void Box<::GGRS::GGRSSession>::allocation::dealloc(::GGRS::GGRSSession *ptr) noexcept {
+  cxxbridge1$box$GGRS$GGRSSession$dealloc(ptr);
+}
+template <>
+void Box<::GGRS::GGRSSession>::drop() noexcept {
+  cxxbridge1$box$GGRS$GGRSSession$drop(this);
+}
 } // namespace cxxbridge1
 } // namespace rust
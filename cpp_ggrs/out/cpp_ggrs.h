@@ -1,47 +1,41 @@
 #pragma once
+#include "cpp_ggrs/include/ggrs_socket.h"
+#include "cpp_ggrs/include/ggrs_events.h"
 #include "rust/cxx.h"
+#include <memory>
 
 namespace GGRS {
-  struct GGRSSessionInfo;
   struct GGRSPlayer;
   enum class GGRSPlayerType : ::std::uint8_t;
-  enum class GGRSSessionType : ::std::uint8_t;
+  enum class GGRSAddressPreference : ::std::uint8_t;
+  enum class GGRSPacingMode : ::std::uint8_t;
   enum class GGRSSessionState : ::std::uint8_t;
   enum class GGRSEventType : ::std::uint8_t;
   struct GGRSEventInfo;
   struct GGRSEvent;
   struct GGRSFrameResult;
+  struct GGRSFramePacing;
+  struct GGRSLocalInput;
+  struct GGRSTickResult;
+  struct GGRSChecksumMismatch;
   struct GGRSFrameAction;
   enum class GGRSFrameActionType : ::std::uint8_t;
   struct GGRSFrameActionInfo;
   struct GGRSInput;
   enum class GGRSInputStatus : ::std::uint8_t;
+  enum class GGRSErrorCode : ::std::uint8_t;
+  struct GGRSErrorInfo;
+  struct GGRSReceivedMessage;
+  struct GGRSNetworkConditions;
+  struct GGRSDuration;
   struct GGRSNetworkStats;
+  using GGRSNonBlockingSocket = ::GGRS::GGRSNonBlockingSocket;
+  using GGRSEventHandler = ::GGRS::GGRSEventHandler;
   struct GGRSSession;
+  struct GGRSSessionBuilder;
 }
 
 namespace GGRS {
-#ifndef CXXBRIDGE1_STRUCT_GGRS$GGRSSessionInfo
-#define CXXBRIDGE1_STRUCT_GGRS$GGRSSessionInfo
-struct GGRSSessionInfo final {
-  bool session_started;
-  ::GGRS::GGRSSessionType session_type;
-  ::std::uint32_t num_players;
-  ::std::uint32_t fps;
-  ::std::uint32_t input_delay;
-  ::std::uint32_t max_prediction_frames;
-  ::std::uint32_t max_frames_behind;
-  ::std::uint32_t catchup_speed;
-  ::std::uint32_t check_distance;
-  ::std::uint16_t local_port;
-  ::rust::String host;
-  bool sparse_saving;
-  ::rust::Vec<::GGRS::GGRSPlayer> players;
-
-  using IsRelocatable = ::std::true_type;
-};
-#endif // CXXBRIDGE1_STRUCT_GGRS$GGRSSessionInfo
-
 #ifndef CXXBRIDGE1_STRUCT_GGRS$GGRSPlayer
 #define CXXBRIDGE1_STRUCT_GGRS$GGRSPlayer
 struct GGRSPlayer final {
@@ -62,15 +56,21 @@ enum class GGRSPlayerType : ::std::uint8_t {
 };
 #endif // CXXBRIDGE1_ENUM_GGRS$GGRSPlayerType
 
-#ifndef CXXBRIDGE1_ENUM_GGRS$GGRSSessionType
-#define CXXBRIDGE1_ENUM_GGRS$GGRSSessionType
-enum class GGRSSessionType : ::std::uint8_t {
-  NotSet = 0,
-  Peer2Peer = 1,
-  Spectator = 2,
-  Synctest = 3,
+#ifndef CXXBRIDGE1_ENUM_GGRS$GGRSAddressPreference
+#define CXXBRIDGE1_ENUM_GGRS$GGRSAddressPreference
+enum class GGRSAddressPreference : ::std::uint8_t {
+  IPv4 = 0,
+  IPv6 = 1,
+};
+#endif // CXXBRIDGE1_ENUM_GGRS$GGRSAddressPreference
+
+#ifndef CXXBRIDGE1_ENUM_GGRS$GGRSPacingMode
+#define CXXBRIDGE1_ENUM_GGRS$GGRSPacingMode
+enum class GGRSPacingMode : ::std::uint8_t {
+  FixedStep = 0,
+  VariableRefresh = 1,
 };
-#endif // CXXBRIDGE1_ENUM_GGRS$GGRSSessionType
+#endif // CXXBRIDGE1_ENUM_GGRS$GGRSPacingMode
 
 #ifndef CXXBRIDGE1_ENUM_GGRS$GGRSSessionState
 #define CXXBRIDGE1_ENUM_GGRS$GGRSSessionState
@@ -90,17 +90,35 @@ enum class GGRSEventType : ::std::uint8_t {
   NetworkInterrupted = 4,
   NetworkResumed = 5,
   WaitRecommendation = 6,
+  DesyncDetected = 7,
 };
 #endif // CXXBRIDGE1_ENUM_GGRS$GGRSEventType
 
+#ifndef CXXBRIDGE1_STRUCT_GGRS$GGRSDuration
+#define CXXBRIDGE1_STRUCT_GGRS$GGRSDuration
+struct GGRSDuration final {
+  ::std::uint64_t ms;
+  ::std::uint64_t us;
+
+  using IsRelocatable = ::std::true_type;
+};
+#endif // CXXBRIDGE1_STRUCT_GGRS$GGRSDuration
+
 #ifndef CXXBRIDGE1_STRUCT_GGRS$GGRSEventInfo
 #define CXXBRIDGE1_STRUCT_GGRS$GGRSEventInfo
 struct GGRSEventInfo final {
   ::rust::String addr;
   ::std::uint32_t total;
   ::std::uint32_t count;
-  ::std::uint64_t disconnect_timeout;
+  ::GGRS::GGRSDuration disconnect_timeout;
   ::std::uint32_t skip_frames;
+  ::std::int32_t frame;
+  ::std::uint64_t local_checksum_hi;
+  ::std::uint64_t local_checksum_lo;
+  ::std::uint64_t remote_checksum_hi;
+  ::std::uint64_t remote_checksum_lo;
+  ::rust::Vec<::std::uint32_t> player_handles;
+  ::GGRS::GGRSPlayerType player_type;
 
   using IsRelocatable = ::std::true_type;
 };
@@ -121,16 +139,68 @@ struct GGRSEvent final {
 struct GGRSFrameResult final {
   bool skip_frame;
   ::rust::Vec<::GGRS::GGRSFrameAction> actions;
+  ::rust::Vec<::GGRS::GGRSChecksumMismatch> checksum_mismatches;
 
   using IsRelocatable = ::std::true_type;
 };
 #endif // CXXBRIDGE1_STRUCT_GGRS$GGRSFrameResult
 
+#ifndef CXXBRIDGE1_STRUCT_GGRS$GGRSFramePacing
+#define CXXBRIDGE1_STRUCT_GGRS$GGRSFramePacing
+struct GGRSFramePacing final {
+  bool skip_frame;
+  ::GGRS::GGRSDuration sleep;
+  ::GGRS::GGRSDuration frame_duration;
+  ::std::int32_t frames_ahead;
+
+  using IsRelocatable = ::std::true_type;
+};
+#endif // CXXBRIDGE1_STRUCT_GGRS$GGRSFramePacing
+
+#ifndef CXXBRIDGE1_STRUCT_GGRS$GGRSLocalInput
+#define CXXBRIDGE1_STRUCT_GGRS$GGRSLocalInput
+struct GGRSLocalInput final {
+  ::std::uint32_t player_handle;
+  ::rust::Vec<::std::uint8_t> input;
+
+  using IsRelocatable = ::std::true_type;
+};
+#endif // CXXBRIDGE1_STRUCT_GGRS$GGRSLocalInput
+
+#ifndef CXXBRIDGE1_STRUCT_GGRS$GGRSTickResult
+#define CXXBRIDGE1_STRUCT_GGRS$GGRSTickResult
+struct GGRSTickResult final {
+  ::GGRS::GGRSSessionState state;
+  ::rust::Vec<::GGRS::GGRSEvent> events;
+  bool skip_frame;
+  ::rust::Vec<::GGRS::GGRSFrameAction> actions;
+  ::rust::Vec<::GGRS::GGRSChecksumMismatch> checksum_mismatches;
+
+  using IsRelocatable = ::std::true_type;
+};
+#endif // CXXBRIDGE1_STRUCT_GGRS$GGRSTickResult
+
+#ifndef CXXBRIDGE1_STRUCT_GGRS$GGRSChecksumMismatch
+#define CXXBRIDGE1_STRUCT_GGRS$GGRSChecksumMismatch
+struct GGRSChecksumMismatch final {
+  ::std::int32_t frame;
+  bool has_original_checksum;
+  ::std::uint64_t original_checksum_hi;
+  ::std::uint64_t original_checksum_lo;
+  bool has_resimulated_checksum;
+  ::std::uint64_t resimulated_checksum_hi;
+  ::std::uint64_t resimulated_checksum_lo;
+
+  using IsRelocatable = ::std::true_type;
+};
+#endif // CXXBRIDGE1_STRUCT_GGRS$GGRSChecksumMismatch
+
 #ifndef CXXBRIDGE1_STRUCT_GGRS$GGRSFrameActionInfo
 #define CXXBRIDGE1_STRUCT_GGRS$GGRSFrameActionInfo
 struct GGRSFrameActionInfo final {
   ::std::int32_t frame;
   ::rust::Vec<::GGRS::GGRSInput> inputs;
+  ::rust::Vec<::std::uint8_t> state;
 
   using IsRelocatable = ::std::true_type;
 };
@@ -158,7 +228,7 @@ enum class GGRSFrameActionType : ::std::uint8_t {
 #ifndef CXXBRIDGE1_STRUCT_GGRS$GGRSInput
 #define CXXBRIDGE1_STRUCT_GGRS$GGRSInput
 struct GGRSInput final {
-  ::std::uint32_t input;
+  ::rust::Vec<::std::uint8_t> input;
   ::GGRS::GGRSInputStatus status;
 
   using IsRelocatable = ::std::true_type;
@@ -174,11 +244,75 @@ enum class GGRSInputStatus : ::std::uint8_t {
 };
 #endif // CXXBRIDGE1_ENUM_GGRS$GGRSInputStatus
 
+#ifndef CXXBRIDGE1_ENUM_GGRS$GGRSErrorCode
+#define CXXBRIDGE1_ENUM_GGRS$GGRSErrorCode
+enum class GGRSErrorCode : ::std::uint8_t {
+  Ok = 0,
+  PredictionThreshold = 1,
+  InvalidRequest = 2,
+  MismatchedChecksum = 3,
+  NotSynchronized = 4,
+  SpectatorTooFarBehind = 5,
+  SocketCreationFailed = 6,
+  PlayerDisconnected = 7,
+  DecodingError = 8,
+  BindFailed = 9,
+  AddressParseFailed = 10,
+  InvalidConfiguration = 11,
+  InvalidInputSize = 12,
+  InvalidSocket = 13,
+  NoPendingSave = 14,
+  UnsupportedOperation = 15,
+  SessionClosed = 16,
+  Panic = 17,
+  SessionPoisoned = 18,
+  ReplayIoFailed = 19,
+  InvalidReplay = 20,
+  ReplayFinished = 21,
+  DesyncReportFailed = 22,
+  EventHandlerFailed = 23,
+};
+#endif // CXXBRIDGE1_ENUM_GGRS$GGRSErrorCode
+
+#ifndef CXXBRIDGE1_STRUCT_GGRS$GGRSErrorInfo
+#define CXXBRIDGE1_STRUCT_GGRS$GGRSErrorInfo
+struct GGRSErrorInfo final {
+  ::GGRS::GGRSErrorCode code;
+  ::rust::String message;
+
+  using IsRelocatable = ::std::true_type;
+};
+#endif // CXXBRIDGE1_STRUCT_GGRS$GGRSErrorInfo
+
+#ifndef CXXBRIDGE1_STRUCT_GGRS$GGRSReceivedMessage
+#define CXXBRIDGE1_STRUCT_GGRS$GGRSReceivedMessage
+struct GGRSReceivedMessage final {
+  ::rust::String addr;
+  ::rust::Vec<::std::uint8_t> msg;
+
+  using IsRelocatable = ::std::true_type;
+};
+#endif // CXXBRIDGE1_STRUCT_GGRS$GGRSReceivedMessage
+
+#ifndef CXXBRIDGE1_STRUCT_GGRS$GGRSNetworkConditions
+#define CXXBRIDGE1_STRUCT_GGRS$GGRSNetworkConditions
+struct GGRSNetworkConditions final {
+  ::std::uint32_t latency_ms;
+  ::std::uint32_t jitter_ms;
+  float packet_loss;
+  float duplication;
+  float reordering;
+  ::std::uint64_t seed;
+
+  using IsRelocatable = ::std::true_type;
+};
+#endif // CXXBRIDGE1_STRUCT_GGRS$GGRSNetworkConditions
+
 #ifndef CXXBRIDGE1_STRUCT_GGRS$GGRSNetworkStats
 #define CXXBRIDGE1_STRUCT_GGRS$GGRSNetworkStats
 struct GGRSNetworkStats final {
   ::std::uint32_t send_queue_len;
-  ::std::uint64_t ping;
+  ::GGRS::GGRSDuration ping;
   ::std::uint64_t kbps_sent;
   ::std::int32_t local_frames_behind;
   ::std::int32_t remote_frames_behind;
@@ -190,6 +324,43 @@ struct GGRSNetworkStats final {
 #ifndef CXXBRIDGE1_STRUCT_GGRS$GGRSSession
 #define CXXBRIDGE1_STRUCT_GGRS$GGRSSession
 struct GGRSSession final : public ::rust::Opaque {
+  bool poll_remote_clients();
+  bool add_local_input(::std::uint32_t player_handle, ::rust::Slice<::std::uint8_t const> input);
+  bool add_local_inputs(::rust::Slice<::GGRS::GGRSLocalInput const> local_inputs);
+  ::GGRS::GGRSSessionState get_current_state();
+  ::rust::Vec<::GGRS::GGRSEvent> get_events();
+  ::GGRS::GGRSFrameResult advance_frame();
+  ::GGRS::GGRSTickResult tick(::rust::Slice<::GGRS::GGRSLocalInput const> local_inputs);
+  ::GGRS::GGRSFramePacing frame_pacing();
+  bool save_game_state(::std::int32_t frame, ::rust::Slice<::std::uint8_t const> state);
+  bool submit_checksum(::std::int32_t frame, ::std::uint64_t checksum_hi, ::std::uint64_t checksum_lo);
+  ::std::int32_t get_frames_ahead();
+  bool clean_session() noexcept;
+  ::GGRS::GGRSNetworkStats network_stats(::std::uint32_t player_handle);
+  bool disconnect_player(::std::uint32_t player_handle);
+  bool start_recording(::rust::Str path);
+  bool stop_recording();
+  ::rust::String desync_report_path();
+  ::rust::String local_address();
+  bool set_event_handler(::std::unique_ptr<::GGRS::GGRSEventHandler> handler);
+  ::GGRS::GGRSErrorInfo try_poll_remote_clients() noexcept;
+  ::GGRS::GGRSErrorInfo try_add_local_input(::std::uint32_t player_handle, ::rust::Slice<::std::uint8_t const> input) noexcept;
+  ::GGRS::GGRSErrorInfo try_add_local_inputs(::rust::Slice<::GGRS::GGRSLocalInput const> local_inputs) noexcept;
+  ::GGRS::GGRSErrorInfo try_get_current_state(::GGRS::GGRSSessionState &state) noexcept;
+  ::GGRS::GGRSErrorInfo try_get_events(::rust::Vec<::GGRS::GGRSEvent> &events) noexcept;
+  ::GGRS::GGRSErrorInfo try_advance_frame(::GGRS::GGRSFrameResult &result) noexcept;
+  ::GGRS::GGRSErrorInfo try_frame_pacing(::GGRS::GGRSFramePacing &pacing) noexcept;
+  ::GGRS::GGRSErrorInfo try_tick(::rust::Slice<::GGRS::GGRSLocalInput const> local_inputs, ::GGRS::GGRSTickResult &result) noexcept;
+  ::GGRS::GGRSErrorInfo try_save_game_state(::std::int32_t frame, ::rust::Slice<::std::uint8_t const> state) noexcept;
+  ::GGRS::GGRSErrorInfo try_submit_checksum(::std::int32_t frame, ::std::uint64_t checksum_hi, ::std::uint64_t checksum_lo) noexcept;
+  ::GGRS::GGRSErrorInfo try_get_frames_ahead(::std::int32_t &frames_ahead) noexcept;
+  ::GGRS::GGRSErrorInfo try_network_stats(::std::uint32_t player_handle, ::GGRS::GGRSNetworkStats &stats) noexcept;
+  ::GGRS::GGRSErrorInfo try_disconnect_player(::std::uint32_t player_handle) noexcept;
+  ::GGRS::GGRSErrorInfo try_start_recording(::rust::Str path) noexcept;
+  ::GGRS::GGRSErrorInfo try_stop_recording() noexcept;
+  ::GGRS::GGRSErrorInfo try_desync_report_path(::rust::String &path) noexcept;
+  ::GGRS::GGRSErrorInfo try_local_address(::rust::String &addr) noexcept;
+  ::GGRS::GGRSErrorInfo try_set_event_handler(::std::unique_ptr<::GGRS::GGRSEventHandler> handler) noexcept;
   ~GGRSSession() = delete;
 
 private:
@@ -201,37 +372,75 @@ private:
 };
 #endif // CXXBRIDGE1_STRUCT_GGRS$GGRSSession
 
-bool setup_ggrs_info(::GGRS::GGRSSessionInfo &info) noexcept;
-
-bool add_player(::GGRS::GGRSSessionInfo &info, ::GGRS::GGRSPlayer player) noexcept;
-
-bool setup_p2p_session(::GGRS::GGRSSessionInfo &info, ::std::uint16_t local_port, ::std::uint32_t fps, ::std::uint32_t input_delay, ::std::uint32_t max_prediction_frames) noexcept;
-
-bool setup_spectator_session(::GGRS::GGRSSessionInfo &info, ::std::uint16_t local_port, ::rust::String host, ::std::uint32_t max_frames_behind, ::std::uint32_t catchup_speed) noexcept;
+#ifndef CXXBRIDGE1_STRUCT_GGRS$GGRSSessionBuilder
+#define CXXBRIDGE1_STRUCT_GGRS$GGRSSessionBuilder
+struct GGRSSessionBuilder final : public ::rust::Opaque {
+  ::GGRS::GGRSSessionBuilder &with_num_players(::std::uint32_t num_players);
+  ::GGRS::GGRSSessionBuilder &with_input_size(::std::uint32_t bytes);
+  ::GGRS::GGRSSessionBuilder &with_fps(::std::uint32_t fps);
+  ::GGRS::GGRSSessionBuilder &with_input_delay(::std::uint32_t delay);
+  ::GGRS::GGRSSessionBuilder &with_max_prediction_window(::std::uint32_t window);
+  ::GGRS::GGRSSessionBuilder &with_max_frames_behind(::std::uint32_t max_frames_behind);
+  ::GGRS::GGRSSessionBuilder &with_catchup_speed(::std::uint32_t catchup_speed);
+  ::GGRS::GGRSSessionBuilder &with_check_distance(::std::uint32_t check_distance);
+  ::GGRS::GGRSSessionBuilder &with_disconnect_timeout(::std::uint64_t timeout_ms);
+  ::GGRS::GGRSSessionBuilder &with_disconnect_notify_delay(::std::uint64_t notify_delay_ms);
+  ::GGRS::GGRSSessionBuilder &with_network_conditions(::GGRS::GGRSNetworkConditions conditions);
+  ::GGRS::GGRSSessionBuilder &with_sparse_saving_mode(bool enable);
+  ::GGRS::GGRSSessionBuilder &with_desync_detection_interval(::std::uint32_t interval);
+  ::GGRS::GGRSSessionBuilder &with_desync_report_dir(::rust::Str dir);
+  ::GGRS::GGRSSessionBuilder &with_desync_report_frames(::std::uint32_t frames);
+  ::GGRS::GGRSSessionBuilder &with_address_preference(::GGRS::GGRSAddressPreference preference);
+  ::GGRS::GGRSSessionBuilder &with_pacing_mode(::GGRS::GGRSPacingMode mode);
+  ::GGRS::GGRSSessionBuilder &with_bind_address(::rust::Str ip);
+  ::GGRS::GGRSSessionBuilder &add_player(::GGRS::GGRSPlayer player);
+  ::rust::Box<::GGRS::GGRSSession> start_p2p_session(::std::uint16_t local_port);
+  ::rust::Box<::GGRS::GGRSSession> start_spectator_session(::std::uint16_t local_port, ::rust::Str host);
+  ::rust::Box<::GGRS::GGRSSession> start_synctest_session();
+  ::rust::Box<::GGRS::GGRSSession> start_p2p_session_with_socket(::std::unique_ptr<::GGRS::GGRSNonBlockingSocket> socket);
+  ::rust::Box<::GGRS::GGRSSession> start_spectator_session_with_socket(::rust::Str host, ::std::unique_ptr<::GGRS::GGRSNonBlockingSocket> socket);
+  ::rust::Box<::GGRS::GGRSSession> start_p2p_session_on_loopback(::rust::Str local_name);
+  ::rust::Box<::GGRS::GGRSSession> start_spectator_session_on_loopback(::rust::Str local_name, ::rust::Str host);
+  ::GGRS::GGRSErrorInfo try_with_num_players(::std::uint32_t num_players) noexcept;
+  ::GGRS::GGRSErrorInfo try_with_input_size(::std::uint32_t bytes) noexcept;
+  ::GGRS::GGRSErrorInfo try_with_fps(::std::uint32_t fps) noexcept;
+  ::GGRS::GGRSErrorInfo try_with_input_delay(::std::uint32_t delay) noexcept;
+  ::GGRS::GGRSErrorInfo try_with_max_prediction_window(::std::uint32_t window) noexcept;
+  ::GGRS::GGRSErrorInfo try_with_max_frames_behind(::std::uint32_t max_frames_behind) noexcept;
+  ::GGRS::GGRSErrorInfo try_with_catchup_speed(::std::uint32_t catchup_speed) noexcept;
+  ::GGRS::GGRSErrorInfo try_with_check_distance(::std::uint32_t check_distance) noexcept;
+  ::GGRS::GGRSErrorInfo try_with_disconnect_timeout(::std::uint64_t timeout_ms) noexcept;
+  ::GGRS::GGRSErrorInfo try_with_disconnect_notify_delay(::std::uint64_t notify_delay_ms) noexcept;
+  ::GGRS::GGRSErrorInfo try_with_network_conditions(::GGRS::GGRSNetworkConditions conditions) noexcept;
+  ::GGRS::GGRSErrorInfo try_with_sparse_saving_mode(bool enable) noexcept;
+  ::GGRS::GGRSErrorInfo try_with_desync_detection_interval(::std::uint32_t interval) noexcept;
+  ::GGRS::GGRSErrorInfo try_with_desync_report_dir(::rust::Str dir) noexcept;
+  ::GGRS::GGRSErrorInfo try_with_desync_report_frames(::std::uint32_t frames) noexcept;
+  ::GGRS::GGRSErrorInfo try_with_address_preference(::GGRS::GGRSAddressPreference preference) noexcept;
+  ::GGRS::GGRSErrorInfo try_with_bind_address(::rust::Str ip) noexcept;
+  ::GGRS::GGRSErrorInfo try_with_pacing_mode(::GGRS::GGRSPacingMode mode) noexcept;
+  ::GGRS::GGRSErrorInfo try_add_player(::GGRS::GGRSPlayer player) noexcept;
+  ::rust::Box<::GGRS::GGRSSession> try_start_p2p_session(::std::uint16_t local_port, ::GGRS::GGRSErrorInfo &error) noexcept;
+  ::rust::Box<::GGRS::GGRSSession> try_start_spectator_session(::std::uint16_t local_port, ::rust::Str host, ::GGRS::GGRSErrorInfo &error) noexcept;
+  ::rust::Box<::GGRS::GGRSSession> try_start_synctest_session(::GGRS::GGRSErrorInfo &error) noexcept;
+  ::rust::Box<::GGRS::GGRSSession> try_start_p2p_session_with_socket(::std::unique_ptr<::GGRS::GGRSNonBlockingSocket> socket, ::GGRS::GGRSErrorInfo &error) noexcept;
+  ::rust::Box<::GGRS::GGRSSession> try_start_spectator_session_with_socket(::rust::Str host, ::std::unique_ptr<::GGRS::GGRSNonBlockingSocket> socket, ::GGRS::GGRSErrorInfo &error) noexcept;
+  ::rust::Box<::GGRS::GGRSSession> try_start_p2p_session_on_loopback(::rust::Str local_name, ::GGRS::GGRSErrorInfo &error) noexcept;
+  ::rust::Box<::GGRS::GGRSSession> try_start_spectator_session_on_loopback(::rust::Str local_name, ::rust::Str host, ::GGRS::GGRSErrorInfo &error) noexcept;
+  ~GGRSSessionBuilder() = delete;
 
-bool setup_synctest_session(::GGRS::GGRSSessionInfo &info, ::std::uint32_t check_distance, ::std::uint32_t input_delay) noexcept;
-
-bool set_num_players(::GGRS::GGRSSessionInfo &info, ::std::uint32_t num) noexcept;
-
-bool set_sparse_saving(::GGRS::GGRSSessionInfo &info, bool enable) noexcept;
-
-::GGRS::GGRSSession *create_session(::GGRS::GGRSSessionInfo &info);
-
-bool poll_remote_clients(::GGRS::GGRSSession *session) noexcept;
-
-bool add_local_input(::GGRS::GGRSSession *session, ::std::uint32_t player_handle, ::std::uint32_t input);
-
-::GGRS::GGRSSessionState get_current_state(::GGRS::GGRSSession *session) noexcept;
-
-::rust::Vec<::GGRS::GGRSEvent> get_events(::GGRS::GGRSSession *session) noexcept;
-
-::GGRS::GGRSFrameResult advance_frame(::GGRS::GGRSSession *session);
-
-::std::int32_t get_frames_ahead(::GGRS::GGRSSession *session) noexcept;
+private:
+  friend ::rust::layout;
+  struct layout {
+    static ::std::size_t size() noexcept;
+    static ::std::size_t align() noexcept;
+  };
+};
+#endif // CXXBRIDGE1_STRUCT_GGRS$GGRSSessionBuilder
 
-bool clean_session(::GGRS::GGRSSession *session) noexcept;
+::rust::Box<::GGRS::GGRSSessionBuilder> new_session_builder() noexcept;
 
-::GGRS::GGRSNetworkStats network_stats(::GGRS::GGRSSession *session, ::std::uint32_t player_handle);
+::rust::Box<::GGRS::GGRSSession> start_replay_session(::rust::Str path);
 
-bool disconnect_player(::GGRS::GGRSSession *session, ::std::uint32_t player_handle);
+::rust::Box<::GGRS::GGRSSession> try_start_replay_session(::rust::Str path, ::GGRS::GGRSErrorInfo &error) noexcept;
 } // namespace GGRS
@@ -0,0 +1,167 @@
+// inspects replays written by GGRSSession::stop_recording without starting the game.
+//   ggrs_replay info <replay>          prints the players, an input histogram and the recorded checksums
+//   ggrs_replay diff <replay> <replay> reports the first frame where two recordings of a match diverge
+use std::{collections::HashMap, env, process};
+
+use cpp_ggrs::wrapper::{GGRSInputStatus, GGRSPlayerType};
+use cpp_ggrs::GGRSReplay;
+
+const USAGE: &str = "usage: ggrs_replay info <replay>\n       ggrs_replay diff <replay> <replay>";
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let code = match args
+        .iter()
+        .map(String::as_str)
+        .collect::<Vec<_>>()
+        .as_slice()
+    {
+        ["info", path] => info(path),
+        ["diff", first, second] => diff(first, second),
+        _ => {
+            eprintln!("{}", USAGE);
+            2
+        }
+    };
+    process::exit(code);
+}
+
+fn load(path: &str) -> Option<GGRSReplay> {
+    match GGRSReplay::read_from(path) {
+        Ok(replay) => Some(replay),
+        Err(err) => {
+            eprintln!("could not read {}: {}", path, err);
+            None
+        }
+    }
+}
+
+fn info(path: &str) -> i32 {
+    let replay = match load(path) {
+        Some(it) => it,
+        None => return 2,
+    };
+
+    println!("frames: {}", replay.frames().len());
+    println!("players: {}", replay.num_players());
+    println!("input size: {} bytes", replay.input_size());
+    for player in replay.players() {
+        let player_type = match player.player_type {
+            GGRSPlayerType::Local => "local",
+            GGRSPlayerType::Remote => "remote",
+            _ => "spectator",
+        };
+        println!(
+            "  handle {}: {} {}",
+            player.player_handle, player_type, player.socket_addr
+        );
+    }
+
+    // how often every distinct input was used, most common first
+    for handle in 0..replay.num_players() as usize {
+        let mut histogram: HashMap<&[u8], usize> = HashMap::new();
+        let mut disconnected = 0;
+        for inputs in replay.frames() {
+            let input = &inputs[handle];
+            if input.status == GGRSInputStatus::Disconnected {
+                disconnected += 1;
+            } else {
+                *histogram.entry(&input.input).or_default() += 1;
+            }
+        }
+        let mut histogram: Vec<_> = histogram.into_iter().collect();
+        histogram.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(b.0)));
+
+        println!("inputs of player {}:", handle);
+        for (input, count) in histogram {
+            println!("  {} x{}", hex(input), count);
+        }
+        if disconnected > 0 {
+            println!("  disconnected x{}", disconnected);
+        }
+    }
+
+    let checksums: Vec<_> = (0..replay.frames().len() as i32)
+        .filter_map(|frame| replay.checksum(frame).map(|checksum| (frame, checksum)))
+        .collect();
+    println!("checksums: {}", checksums.len());
+    for (frame, checksum) in checksums {
+        println!("  frame {}: {:032x}", frame, checksum);
+    }
+    0
+}
+
+fn diff(first_path: &str, second_path: &str) -> i32 {
+    let (first, second) = match (load(first_path), load(second_path)) {
+        (Some(first), Some(second)) => (first, second),
+        _ => return 2,
+    };
+    if first.num_players() != second.num_players() || first.input_size() != second.input_size() {
+        println!(
+            "the replays are of different matches: {} players with {} byte inputs vs {} players with {} byte inputs",
+            first.num_players(),
+            first.input_size(),
+            second.num_players(),
+            second.input_size()
+        );
+        return 1;
+    }
+
+    let frame = match first.first_divergence(&second) {
+        Some(it) => it,
+        None => {
+            println!("identical, {} frames", first.frames().len());
+            return 0;
+        }
+    };
+    println!("first divergence at frame {}", frame);
+    let index = frame as usize;
+    match (first.frames().get(index), second.frames().get(index)) {
+        (Some(first_inputs), Some(second_inputs)) => {
+            for (handle, (a, b)) in first_inputs.iter().zip(second_inputs).enumerate() {
+                if a.input != b.input || a.status != b.status {
+                    println!(
+                        "  player {}: {} vs {}",
+                        handle,
+                        describe(&a.input, a.status),
+                        describe(&b.input, b.status)
+                    );
+                }
+            }
+            if first.checksum(frame) != second.checksum(frame) {
+                println!(
+                    "  checksum: {} vs {}",
+                    describe_checksum(first.checksum(frame)),
+                    describe_checksum(second.checksum(frame))
+                );
+            }
+        }
+        _ => println!(
+            "  {} has {} frames, {} has {} frames",
+            first_path,
+            first.frames().len(),
+            second_path,
+            second.frames().len()
+        ),
+    }
+    1
+}
+
+// a frame the game never submitted a checksum for has none, not a checksum of zero
+fn describe_checksum(checksum: Option<u128>) -> String {
+    match checksum {
+        Some(checksum) => format!("{:032x}", checksum),
+        None => "none".to_string(),
+    }
+}
+
+fn describe(input: &[u8], status: GGRSInputStatus) -> String {
+    if status == GGRSInputStatus::Disconnected {
+        return "disconnected".to_string();
+    }
+    hex(input)
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
@@ -0,0 +1,119 @@
+use std::{cell::RefCell, collections::BTreeMap, rc::Rc};
+
+use ggrs::{Frame, NULL_FRAME};
+
+use crate::socket::GGRSChecksumChannel;
+
+// how many reported checksums are kept around for remote reports that arrive late
+const KEPT_CHECKSUMS: usize = 32;
+
+// a confirmed frame where a remote peer ended up with a different state than this one
+pub struct GGRSDesync {
+    pub frame: Frame,
+    pub addr: String,
+    pub local_checksum: u128,
+    pub remote_checksum: u128,
+}
+
+// compares the state of confirmed frames with every remote peer of a p2p session. the checksums come from
+// submit_checksum, every interval-th frame is reported to the peers once nothing can roll it back anymore.
+// reports travel over the unreliable transport, a lost one only means that frame is not compared
+pub struct GGRSDesyncDetector {
+    interval: u32,
+    remotes: Vec<String>,
+    channel: Rc<RefCell<GGRSChecksumChannel>>,
+    // latest checksum of frames that may still be resimulated
+    pending: BTreeMap<Frame, u128>,
+    // final checksums and the remotes that have not reported theirs yet
+    reported: BTreeMap<Frame, (u128, Vec<String>)>,
+    // remote reports for frames that are not final here yet
+    early: Vec<(String, Frame, u128)>,
+    // checksums up to this frame are final and have been reported
+    final_frame: Frame,
+    // the confirmed frame of the previous update, becomes final with the next one
+    confirmed_frame: Frame,
+}
+
+impl GGRSDesyncDetector {
+    pub fn new(interval: u32, remotes: Vec<String>) -> Self {
+        Self {
+            interval,
+            remotes,
+            channel: Rc::default(),
+            pending: BTreeMap::new(),
+            reported: BTreeMap::new(),
+            early: Vec::new(),
+            final_frame: NULL_FRAME,
+            confirmed_frame: NULL_FRAME,
+        }
+    }
+
+    pub fn channel(&self) -> Rc<RefCell<GGRSChecksumChannel>> {
+        self.channel.clone()
+    }
+
+    pub fn submit(&mut self, frame: Frame, checksum: u128) {
+        if frame > self.final_frame && (frame as u32).is_multiple_of(self.interval) {
+            self.pending.insert(frame, checksum);
+        }
+    }
+
+    // called once per advance_frame with the frame ggrs confirmed during it. the saves ggrs requested in the
+    // same call are submitted afterwards, so only frames confirmed by the previous call are final
+    pub fn update(&mut self, confirmed_frame: Frame) -> Vec<GGRSDesync> {
+        let final_frame = self.confirmed_frame;
+        self.final_frame = final_frame;
+        self.confirmed_frame = confirmed_frame.max(final_frame);
+
+        let mut channel = self.channel.borrow_mut();
+        while let Some(entry) = self.pending.first_entry() {
+            if *entry.key() > final_frame {
+                break;
+            }
+            let (frame, checksum) = entry.remove_entry();
+            for addr in &self.remotes {
+                channel.outgoing.push((addr.clone(), frame, checksum));
+            }
+            self.reported
+                .insert(frame, (checksum, self.remotes.clone()));
+        }
+        while self.reported.len() > KEPT_CHECKSUMS {
+            self.reported.pop_first();
+        }
+
+        let mut desyncs = Vec::new();
+        let mut received = std::mem::take(&mut self.early);
+        received.append(&mut channel.incoming);
+        for (addr, frame, remote_checksum) in received {
+            if frame > final_frame {
+                // unknown senders are not worth holding on to
+                if self.remotes.contains(&addr) {
+                    self.early.push((addr, frame, remote_checksum));
+                }
+                continue;
+            }
+            // frames that were never reported here or compared already, duplicates included
+            let Some((local_checksum, waiting)) = self.reported.get_mut(&frame) else {
+                continue;
+            };
+            let Some(index) = waiting.iter().position(|remote| *remote == addr) else {
+                continue;
+            };
+            waiting.swap_remove(index);
+            if *local_checksum != remote_checksum {
+                desyncs.push(GGRSDesync {
+                    frame,
+                    addr,
+                    local_checksum: *local_checksum,
+                    remote_checksum,
+                });
+            }
+        }
+        // a peer can not be far ahead of the confirmed frame, anything beyond that is dropped
+        let ahead = (KEPT_CHECKSUMS as u32).saturating_mul(self.interval);
+        let horizon = final_frame.saturating_add(ahead.min(Frame::MAX as u32) as Frame);
+        self.early.retain(|(_, frame, _)| *frame <= horizon);
+        desyncs.sort_by_key(|desync| desync.frame);
+        desyncs
+    }
+}
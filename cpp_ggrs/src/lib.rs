@@ -1,38 +1,55 @@
 use std::{
+    any::Any,
+    collections::HashMap,
     fmt::{self, Display},
-    net::SocketAddr,
+    fs, io,
+    net::{IpAddr, SocketAddr},
+    panic::{self, AssertUnwindSafe},
+    pin::Pin,
+    time::{Duration, Instant},
 };
 
+use bytemuck::{Pod, Zeroable};
+use cxx::UniquePtr;
 use ggrs::{
-    Config, GGRSEvent, GGRSRequest, NetworkStats, P2PSession, PlayerHandle, PlayerType,
-    SessionBuilder, SessionState, SpectatorSession, SyncTestSession, UdpNonBlockingSocket,
+    Config, Frame, GGRSEvent, GGRSRequest, GameStateCell, NetworkStats, P2PSession, PlayerHandle,
+    PlayerType, SessionBuilder, SessionState, SpectatorSession, SyncTestSession,
 };
 
 use wrapper::{
-    GGRSEventType, GGRSFrameAction, GGRSFrameActionInfo, GGRSFrameActionType, GGRSFrameResult,
-    GGRSInput, GGRSInputStatus, GGRSNetworkStats, GGRSPlayer, GGRSPlayerType, GGRSSessionInfo,
-    GGRSSessionState, GGRSSessionType,
+    GGRSAddressPreference, GGRSChecksumMismatch, GGRSDuration, GGRSErrorCode, GGRSErrorInfo,
+    GGRSEventHandler, GGRSEventType, GGRSFrameAction, GGRSFrameActionInfo, GGRSFrameActionType,
+    GGRSFramePacing, GGRSFrameResult, GGRSInput, GGRSInputStatus, GGRSLocalInput,
+    GGRSNetworkConditions, GGRSNetworkStats, GGRSNonBlockingSocket, GGRSPacingMode, GGRSPlayer,
+    GGRSPlayerType, GGRSSessionState, GGRSTickResult,
 };
 
-#[cxx::bridge(namespace = "GGRS")]
-mod wrapper {
+use desync::{GGRSDesync, GGRSDesyncDetector};
+use replay::{GGRSReplayPlayback, GGRSReplayRecorder};
+use report::GGRSDesyncReporter;
+use socket::{
+    GGRSCustomSocket, GGRSLoopbackSocket, GGRSSessionSocket, GGRSSimulatedSocket, GGRSTransport,
+    GGRSUdpSocket,
+};
 
-    struct GGRSSessionInfo {
-        session_started: bool,
-        session_type: GGRSSessionType,
-        num_players: u32,
-        fps: u32,
-        input_delay: u32,
-        max_prediction_frames: u32,
-        max_frames_behind: u32,
-        catchup_speed: u32,
-        check_distance: u32,
-        local_port: u16,
-        host: String,
-        sparse_saving: bool,
-        players: Vec<GGRSPlayer>,
-    }
+mod desync;
+mod pacing;
+mod replay;
+mod report;
+mod socket;
+#[cfg(feature = "testing")]
+pub mod testing;
+
+pub use pacing::GGRSFramePacer;
+pub use replay::GGRSReplay;
+
+// cxx needs the explicit lifetimes on the chained builder methods. the event callbacks mirror the
+// event info fields one by one
+#[allow(clippy::needless_lifetimes, clippy::too_many_arguments)]
+#[cxx::bridge(namespace = "GGRS")]
+pub mod wrapper {
 
+    #[derive(Clone)]
     struct GGRSPlayer {
         player_handle: u32,
         player_type: GGRSPlayerType,
@@ -45,11 +62,16 @@ mod wrapper {
         Spectator,
     }
 
-    enum GGRSSessionType {
-        NotSet,
-        Peer2Peer,
-        Spectator,
-        Synctest,
+    // the address family picked when a hostname resolves to both. literal addresses are used as given
+    enum GGRSAddressPreference {
+        IPv4,
+        IPv6,
+    }
+
+    // how frame_pacing keeps a frame loop from running ahead of its peers
+    enum GGRSPacingMode {
+        FixedStep, // the loop runs at the session fps and skips simulating a frame now and then
+        VariableRefresh, // the loop stretches its frames instead and sleeps for as long as it is told
     }
 
     enum GGRSSessionState {
@@ -65,14 +87,25 @@ mod wrapper {
         NetworkInterrupted,
         NetworkResumed,
         WaitRecommendation,
+        DesyncDetected, // a remote peer reported a different checksum for a confirmed frame
     }
 
     struct GGRSEventInfo {
         addr: String,
         total: u32,
         count: u32,
-        disconnect_timeout: u64, // should be 128 but its not supported yet by cxx. might lead to inaccuracies.
+        disconnect_timeout: GGRSDuration,
         skip_frames: u32,
+        // DesyncDetected only, addr is the peer that disagrees
+        frame: i32,
+        local_checksum_hi: u64,
+        local_checksum_lo: u64,
+        remote_checksum_hi: u64,
+        remote_checksum_lo: u64,
+        // the players registered at addr. a spectator session lists every player for its host.
+        // empty for WaitRecommendation, player_type is only meaningful when there are handles
+        player_handles: Vec<u32>,
+        player_type: GGRSPlayerType,
     }
 
     struct GGRSEvent {
@@ -80,9 +113,46 @@ mod wrapper {
         event_info: GGRSEventInfo,
     }
 
+    #[derive(Default)]
     struct GGRSFrameResult {
         skip_frame: bool,
         actions: Vec<GGRSFrameAction>,
+        // only filled by a synctest session that found a desync. ggrs can't get past it, every later
+        // advance_frame fails with MismatchedChecksum
+        checksum_mismatches: Vec<GGRSChecksumMismatch>,
+    }
+
+    struct GGRSFramePacing {
+        skip_frame: bool, // fixed step only: draw the frame, but neither add input nor advance
+        sleep: GGRSDuration, // wait this long before running the frame
+        frame_duration: GGRSDuration, // 1 / fps, longer while a variable refresh loop slows down
+        frames_ahead: i32,
+    }
+
+    struct GGRSLocalInput {
+        player_handle: u32,
+        input: Vec<u8>,
+    }
+
+    // one frame of a session, see tick. the frame fields stay empty while the session is synchronizing
+    struct GGRSTickResult {
+        state: GGRSSessionState,
+        events: Vec<GGRSEvent>, // empty with an event handler, it got them during the tick
+        skip_frame: bool,
+        actions: Vec<GGRSFrameAction>,
+        checksum_mismatches: Vec<GGRSChecksumMismatch>,
+    }
+
+    // checksums are u128 in ggrs, cxx has no 128 bit integers so they are split into a high and low half.
+    // a save without a submitted checksum has none, its halves are 0 and its has_ flag is false
+    struct GGRSChecksumMismatch {
+        frame: i32,
+        has_original_checksum: bool,
+        original_checksum_hi: u64,
+        original_checksum_lo: u64,
+        has_resimulated_checksum: bool,
+        resimulated_checksum_hi: u64,
+        resimulated_checksum_lo: u64,
     }
 
     struct GGRSFrameAction {
@@ -96,13 +166,16 @@ mod wrapper {
         AdvanceFrame,
     }
 
+    #[derive(Default)]
     struct GGRSFrameActionInfo {
-        frame: i32,
+        frame: i32, // for AdvanceFrame the frame the inputs belong to
         inputs: Vec<GGRSInput>,
+        state: Vec<u8>, // only filled for LoadGameState. holds the bytes given to save_game_state for that frame
     }
 
+    #[derive(Clone)]
     struct GGRSInput {
-        input: u32,
+        input: Vec<u8>, // input_size_bytes long
         status: GGRSInputStatus,
     }
 
@@ -112,72 +185,482 @@ mod wrapper {
         Disconnected,
     }
 
+    #[derive(Debug)]
+    enum GGRSErrorCode {
+        Ok,
+        // errors reported by ggrs itself
+        PredictionThreshold,
+        InvalidRequest,
+        MismatchedChecksum,
+        NotSynchronized,
+        SpectatorTooFarBehind,
+        SocketCreationFailed,
+        PlayerDisconnected,
+        DecodingError,
+        // errors of this wrapper
+        BindFailed,
+        AddressParseFailed,
+        InvalidConfiguration,
+        InvalidInputSize,
+        InvalidSocket,
+        NoPendingSave,
+        UnsupportedOperation,
+        SessionClosed,
+        Panic,
+        SessionPoisoned,
+        ReplayIoFailed,
+        InvalidReplay,
+        ReplayFinished,
+        DesyncReportFailed,
+        EventHandlerFailed,
+    }
+
+    struct GGRSErrorInfo {
+        code: GGRSErrorCode,
+        message: String, // empty when code is Ok
+    }
+
+    struct GGRSReceivedMessage {
+        addr: String,
+        msg: Vec<u8>,
+    }
+
+    // degrades the outgoing traffic of a session to reproduce bad connections locally.
+    // chances are between 0 and 1, the same seed always drops and delays the same messages
+    #[derive(Clone, Copy)]
+    struct GGRSNetworkConditions {
+        latency_ms: u32, // one way, added to every message this session sends
+        jitter_ms: u32,  // random extra delay between 0 and jitter_ms
+        packet_loss: f32,
+        duplication: f32,
+        reordering: f32, // reordered messages are held back by another latency + jitter
+        seed: u64,
+    }
+
+    // ggrs measures time as u128 milliseconds, cxx has no 128 bit integers. both units are given explicitly
+    // and saturate at the u64 maximum instead of wrapping. us is exactly ms * 1000 only for durations ggrs
+    // reported in milliseconds, the frame pacer fills in the sub millisecond part as well
+    #[derive(Clone, Copy, Default)]
+    struct GGRSDuration {
+        ms: u64,
+        us: u64,
+    }
+
     struct GGRSNetworkStats {
         send_queue_len: u32,
-        ping: u64, // should be 128 but its not supported yet by cxx idk if it ever will. might lead to inaccuracies.
+        ping: GGRSDuration, // round trip time
         kbps_sent: u64,
         local_frames_behind: i32,
         remote_frames_behind: i32,
     }
 
+    unsafe extern "C++" {
+        include!("cpp_ggrs/include/ggrs_socket.h");
+
+        type GGRSNonBlockingSocket;
+        fn send_to(self: Pin<&mut GGRSNonBlockingSocket>, msg: &[u8], addr: &str) -> Result<()>;
+        fn receive_all_messages(
+            self: Pin<&mut GGRSNonBlockingSocket>,
+        ) -> Result<Vec<GGRSReceivedMessage>>;
+    }
+
+    unsafe extern "C++" {
+        include!("cpp_ggrs/include/ggrs_events.h");
+
+        type GGRSEventHandler;
+        fn on_synchronizing(
+            self: Pin<&mut GGRSEventHandler>,
+            addr: &str,
+            player_handles: &[u32],
+            player_type: GGRSPlayerType,
+            count: u32,
+            total: u32,
+        ) -> Result<()>;
+        fn on_synchronized(
+            self: Pin<&mut GGRSEventHandler>,
+            addr: &str,
+            player_handles: &[u32],
+            player_type: GGRSPlayerType,
+        ) -> Result<()>;
+        fn on_network_interrupted(
+            self: Pin<&mut GGRSEventHandler>,
+            addr: &str,
+            player_handles: &[u32],
+            player_type: GGRSPlayerType,
+            disconnect_timeout: &GGRSDuration,
+        ) -> Result<()>;
+        fn on_network_resumed(
+            self: Pin<&mut GGRSEventHandler>,
+            addr: &str,
+            player_handles: &[u32],
+            player_type: GGRSPlayerType,
+        ) -> Result<()>;
+        fn on_disconnected(
+            self: Pin<&mut GGRSEventHandler>,
+            addr: &str,
+            player_handles: &[u32],
+            player_type: GGRSPlayerType,
+        ) -> Result<()>;
+        fn on_wait_recommendation(self: Pin<&mut GGRSEventHandler>, skip_frames: u32)
+            -> Result<()>;
+        fn on_desync_detected(
+            self: Pin<&mut GGRSEventHandler>,
+            frame: i32,
+            addr: &str,
+            player_handles: &[u32],
+            player_type: GGRSPlayerType,
+            local_checksum_hi: u64,
+            local_checksum_lo: u64,
+            remote_checksum_hi: u64,
+            remote_checksum_lo: u64,
+        ) -> Result<()>;
+    }
+
     extern "Rust" {
         type GGRSSession;
+        type GGRSSessionBuilder;
         // I like when my outwards facing functions return something. helps with testing
-        // setup functions and helpers
-        fn setup_ggrs_info(info: &mut GGRSSessionInfo) -> bool;
-        fn add_player(info: &mut GGRSSessionInfo, player: GGRSPlayer) -> bool;
-        fn setup_p2p_session(
-            info: &mut GGRSSessionInfo,
-            local_port: u16,
+        // session configuration. every call is checked right away and the builder is left untouched when it fails
+        // the methods return the builder so they can be chained. cxx only allows the lifetime of the returned
+        // reference on unsafe functions, calling them is perfectly safe
+        // every option of ggrs::SessionBuilder is mirrored here, durations are given in milliseconds
+        fn new_session_builder() -> Box<GGRSSessionBuilder>;
+        unsafe fn with_num_players<'a>(
+            self: &'a mut GGRSSessionBuilder,
+            num_players: u32,
+        ) -> Result<&'a mut GGRSSessionBuilder>;
+        unsafe fn with_input_size<'a>(
+            self: &'a mut GGRSSessionBuilder,
+            bytes: u32,
+        ) -> Result<&'a mut GGRSSessionBuilder>;
+        unsafe fn with_fps<'a>(
+            self: &'a mut GGRSSessionBuilder,
             fps: u32,
-            input_delay: u32,
-            max_prediction_frames: u32,
-        ) -> bool;
-        fn setup_spectator_session(
-            info: &mut GGRSSessionInfo,
-            local_port: u16,
-            host: String,
+        ) -> Result<&'a mut GGRSSessionBuilder>;
+        unsafe fn with_input_delay<'a>(
+            self: &'a mut GGRSSessionBuilder,
+            delay: u32,
+        ) -> Result<&'a mut GGRSSessionBuilder>;
+        unsafe fn with_max_prediction_window<'a>(
+            self: &'a mut GGRSSessionBuilder,
+            window: u32,
+        ) -> Result<&'a mut GGRSSessionBuilder>;
+        unsafe fn with_max_frames_behind<'a>(
+            self: &'a mut GGRSSessionBuilder,
             max_frames_behind: u32,
+        ) -> Result<&'a mut GGRSSessionBuilder>;
+        unsafe fn with_catchup_speed<'a>(
+            self: &'a mut GGRSSessionBuilder,
             catchup_speed: u32,
-        ) -> bool;
-        fn setup_synctest_session(
-            info: &mut GGRSSessionInfo,
+        ) -> Result<&'a mut GGRSSessionBuilder>;
+        unsafe fn with_check_distance<'a>(
+            self: &'a mut GGRSSessionBuilder,
             check_distance: u32,
-            input_delay: u32,
-        ) -> bool;
-        fn set_num_players(info: &mut GGRSSessionInfo, num: u32) -> bool;
-        fn set_sparse_saving(info: &mut GGRSSessionInfo, enable: bool) -> bool;
-        // session creation and event handling
-        // since boxes don't want to work with cxx i have to use unsafe raw pointers.
-        // looking for better solutions...
-        fn create_session(info: &mut GGRSSessionInfo) -> Result<*mut GGRSSession>;
-        unsafe fn poll_remote_clients(mut session: *mut GGRSSession) -> bool;
-        unsafe fn add_local_input(
-            mut session: *mut GGRSSession,
+        ) -> Result<&'a mut GGRSSessionBuilder>;
+        // the notify delay has to stay below the timeout. the pair is checked when the session starts
+        unsafe fn with_disconnect_timeout<'a>(
+            self: &'a mut GGRSSessionBuilder,
+            timeout_ms: u64,
+        ) -> Result<&'a mut GGRSSessionBuilder>;
+        unsafe fn with_disconnect_notify_delay<'a>(
+            self: &'a mut GGRSSessionBuilder,
+            notify_delay_ms: u64,
+        ) -> Result<&'a mut GGRSSessionBuilder>;
+        unsafe fn with_network_conditions<'a>(
+            self: &'a mut GGRSSessionBuilder,
+            conditions: GGRSNetworkConditions,
+        ) -> Result<&'a mut GGRSSessionBuilder>;
+        unsafe fn with_sparse_saving_mode<'a>(
+            self: &'a mut GGRSSessionBuilder,
+            enable: bool,
+        ) -> Result<&'a mut GGRSSessionBuilder>;
+        // p2p sessions exchange the checksum of every interval-th confirmed frame with their peers, 0 turns it off
+        unsafe fn with_desync_detection_interval<'a>(
+            self: &'a mut GGRSSessionBuilder,
+            interval: u32,
+        ) -> Result<&'a mut GGRSSessionBuilder>;
+        // the first desync or synctest mismatch of a session is dumped into a new directory below dir together
+        // with the inputs and saved states of the last frames. an empty dir turns it off
+        unsafe fn with_desync_report_dir<'a>(
+            self: &'a mut GGRSSessionBuilder,
+            dir: &str,
+        ) -> Result<&'a mut GGRSSessionBuilder>;
+        unsafe fn with_desync_report_frames<'a>(
+            self: &'a mut GGRSSessionBuilder,
+            frames: u32,
+        ) -> Result<&'a mut GGRSSessionBuilder>;
+        // player and host addresses are "ip:port" or "hostname:port", hostnames are looked up once when the
+        // session starts
+        unsafe fn with_address_preference<'a>(
+            self: &'a mut GGRSSessionBuilder,
+            preference: GGRSAddressPreference,
+        ) -> Result<&'a mut GGRSSessionBuilder>;
+        unsafe fn with_pacing_mode<'a>(
+            self: &'a mut GGRSSessionBuilder,
+            mode: GGRSPacingMode,
+        ) -> Result<&'a mut GGRSSessionBuilder>;
+        // the local ip the udp socket binds to, picks the network interface. empty binds to every interface
+        unsafe fn with_bind_address<'a>(
+            self: &'a mut GGRSSessionBuilder,
+            ip: &str,
+        ) -> Result<&'a mut GGRSSessionBuilder>;
+        unsafe fn add_player<'a>(
+            self: &'a mut GGRSSessionBuilder,
+            player: GGRSPlayer,
+        ) -> Result<&'a mut GGRSSessionBuilder>;
+        // session creation. the returned box owns the session, dropping it frees everything.
+        // the builder stays usable, so a failed start can be fixed and retried
+        fn start_p2p_session(
+            self: &mut GGRSSessionBuilder,
+            local_port: u16,
+        ) -> Result<Box<GGRSSession>>;
+        fn start_spectator_session(
+            self: &mut GGRSSessionBuilder,
+            local_port: u16,
+            host: &str,
+        ) -> Result<Box<GGRSSession>>;
+        fn start_synctest_session(self: &mut GGRSSessionBuilder) -> Result<Box<GGRSSession>>;
+        // same as above but sends and receives through the given socket instead of binding local_port
+        fn start_p2p_session_with_socket(
+            self: &mut GGRSSessionBuilder,
+            socket: UniquePtr<GGRSNonBlockingSocket>,
+        ) -> Result<Box<GGRSSession>>;
+        fn start_spectator_session_with_socket(
+            self: &mut GGRSSessionBuilder,
+            host: &str,
+            socket: UniquePtr<GGRSNonBlockingSocket>,
+        ) -> Result<Box<GGRSSession>>;
+        // same as above but over an in-process loopback, the session is reachable under local_name.
+        // remote players and the host are addressed by the names their sessions were started with
+        fn start_p2p_session_on_loopback(
+            self: &mut GGRSSessionBuilder,
+            local_name: &str,
+        ) -> Result<Box<GGRSSession>>;
+        fn start_spectator_session_on_loopback(
+            self: &mut GGRSSessionBuilder,
+            local_name: &str,
+            host: &str,
+        ) -> Result<Box<GGRSSession>>;
+        // session methods. all of them fail with an error once clean_session has been called
+        fn poll_remote_clients(self: &mut GGRSSession) -> Result<bool>;
+        fn add_local_input(
+            self: &mut GGRSSession,
             player_handle: u32,
-            input: u32,
+            input: &[u8],
+        ) -> Result<bool>;
+        // one input for every local player at once, checked against the registered local players first
+        fn add_local_inputs(
+            self: &mut GGRSSession,
+            local_inputs: &[GGRSLocalInput],
+        ) -> Result<bool>;
+        fn get_current_state(self: &mut GGRSSession) -> Result<GGRSSessionState>;
+        fn get_events(self: &mut GGRSSession) -> Result<Vec<GGRSEvent>>;
+        fn advance_frame(self: &mut GGRSSession) -> Result<GGRSFrameResult>;
+        // the whole frame loop in one call: polls the network, adds the local inputs and advances once the
+        // session is running, then takes the events or hands them to the event handler. a failed tick leaves
+        // the events for the next one
+        fn tick(self: &mut GGRSSession, local_inputs: &[GGRSLocalInput]) -> Result<GGRSTickResult>;
+        // call once at the start of every iteration of the frame loop. combines how far this peer runs
+        // ahead, the WaitRecommendation events seen so far and the fps into what this frame should do.
+        // it already acts on every recommendation, a loop that paces itself with it must not skip frames for
+        // WaitRecommendation events as well
+        fn frame_pacing(self: &mut GGRSSession) -> Result<GGRSFramePacing>;
+        fn save_game_state(self: &mut GGRSSession, frame: i32, state: &[u8]) -> Result<bool>;
+        fn submit_checksum(
+            self: &mut GGRSSession,
+            frame: i32,
+            checksum_hi: u64,
+            checksum_lo: u64,
         ) -> Result<bool>;
-        unsafe fn get_current_state(mut session: *mut GGRSSession) -> GGRSSessionState;
-        unsafe fn get_events(mut session: *mut GGRSSession) -> Vec<GGRSEvent>;
-        unsafe fn advance_frame(mut session: *mut GGRSSession) -> Result<GGRSFrameResult>;
-        unsafe fn get_frames_ahead(mut session: *mut GGRSSession) -> i32;
-        unsafe fn clean_session(session: *mut GGRSSession) -> bool;
-        unsafe fn network_stats(
-            mut session: *mut GGRSSession,
+        fn get_frames_ahead(self: &mut GGRSSession) -> Result<i32>;
+        // releases the ggrs session and its socket without waiting for the box to be dropped
+        fn clean_session(self: &mut GGRSSession) -> bool;
+        fn network_stats(self: &mut GGRSSession, player_handle: u32) -> Result<GGRSNetworkStats>;
+        fn disconnect_player(self: &mut GGRSSession, player_handle: u32) -> Result<bool>;
+        // replays. recording has to start before the first frame, stop_recording writes the file.
+        // a replay session hands out the recorded inputs through advance_frame without any network
+        fn start_recording(self: &mut GGRSSession, path: &str) -> Result<bool>;
+        fn stop_recording(self: &mut GGRSSession) -> Result<bool>;
+        fn start_replay_session(path: &str) -> Result<Box<GGRSSession>>;
+        // the directory the desync report of this session was written to, empty while there is none
+        fn desync_report_path(self: &mut GGRSSession) -> Result<String>;
+        // the address the session's socket is bound to, "ip:port" for udp with the port the os picked when
+        // the session was started on port 0. sessions with a c++ socket don't know theirs
+        fn local_address(self: &mut GGRSSession) -> Result<String>;
+        // events are delivered to the handler during poll_remote_clients from now on and get_events stays
+        // empty, it leaves them for the next poll. a null handler goes back to get_events
+        fn set_event_handler(
+            self: &mut GGRSSession,
+            handler: UniquePtr<GGRSEventHandler>,
+        ) -> Result<bool>;
+        // non throwing variants of the calls above. values are written to the out parameter on success.
+        // on failure the try_start functions hand back an already cleaned up session
+        fn try_with_num_players(self: &mut GGRSSessionBuilder, num_players: u32) -> GGRSErrorInfo;
+        fn try_with_input_size(self: &mut GGRSSessionBuilder, bytes: u32) -> GGRSErrorInfo;
+        fn try_with_fps(self: &mut GGRSSessionBuilder, fps: u32) -> GGRSErrorInfo;
+        fn try_with_input_delay(self: &mut GGRSSessionBuilder, delay: u32) -> GGRSErrorInfo;
+        fn try_with_max_prediction_window(
+            self: &mut GGRSSessionBuilder,
+            window: u32,
+        ) -> GGRSErrorInfo;
+        fn try_with_max_frames_behind(
+            self: &mut GGRSSessionBuilder,
+            max_frames_behind: u32,
+        ) -> GGRSErrorInfo;
+        fn try_with_catchup_speed(
+            self: &mut GGRSSessionBuilder,
+            catchup_speed: u32,
+        ) -> GGRSErrorInfo;
+        fn try_with_check_distance(
+            self: &mut GGRSSessionBuilder,
+            check_distance: u32,
+        ) -> GGRSErrorInfo;
+        fn try_with_disconnect_timeout(
+            self: &mut GGRSSessionBuilder,
+            timeout_ms: u64,
+        ) -> GGRSErrorInfo;
+        fn try_with_disconnect_notify_delay(
+            self: &mut GGRSSessionBuilder,
+            notify_delay_ms: u64,
+        ) -> GGRSErrorInfo;
+        fn try_with_network_conditions(
+            self: &mut GGRSSessionBuilder,
+            conditions: GGRSNetworkConditions,
+        ) -> GGRSErrorInfo;
+        fn try_with_sparse_saving_mode(
+            self: &mut GGRSSessionBuilder,
+            enable: bool,
+        ) -> GGRSErrorInfo;
+        fn try_with_desync_detection_interval(
+            self: &mut GGRSSessionBuilder,
+            interval: u32,
+        ) -> GGRSErrorInfo;
+        fn try_with_desync_report_dir(self: &mut GGRSSessionBuilder, dir: &str) -> GGRSErrorInfo;
+        fn try_with_desync_report_frames(
+            self: &mut GGRSSessionBuilder,
+            frames: u32,
+        ) -> GGRSErrorInfo;
+        fn try_with_address_preference(
+            self: &mut GGRSSessionBuilder,
+            preference: GGRSAddressPreference,
+        ) -> GGRSErrorInfo;
+        fn try_with_bind_address(self: &mut GGRSSessionBuilder, ip: &str) -> GGRSErrorInfo;
+        fn try_with_pacing_mode(
+            self: &mut GGRSSessionBuilder,
+            mode: GGRSPacingMode,
+        ) -> GGRSErrorInfo;
+        fn try_add_player(self: &mut GGRSSessionBuilder, player: GGRSPlayer) -> GGRSErrorInfo;
+        fn try_start_p2p_session(
+            self: &mut GGRSSessionBuilder,
+            local_port: u16,
+            error: &mut GGRSErrorInfo,
+        ) -> Box<GGRSSession>;
+        fn try_start_spectator_session(
+            self: &mut GGRSSessionBuilder,
+            local_port: u16,
+            host: &str,
+            error: &mut GGRSErrorInfo,
+        ) -> Box<GGRSSession>;
+        fn try_start_synctest_session(
+            self: &mut GGRSSessionBuilder,
+            error: &mut GGRSErrorInfo,
+        ) -> Box<GGRSSession>;
+        fn try_start_p2p_session_with_socket(
+            self: &mut GGRSSessionBuilder,
+            socket: UniquePtr<GGRSNonBlockingSocket>,
+            error: &mut GGRSErrorInfo,
+        ) -> Box<GGRSSession>;
+        fn try_start_spectator_session_with_socket(
+            self: &mut GGRSSessionBuilder,
+            host: &str,
+            socket: UniquePtr<GGRSNonBlockingSocket>,
+            error: &mut GGRSErrorInfo,
+        ) -> Box<GGRSSession>;
+        fn try_start_p2p_session_on_loopback(
+            self: &mut GGRSSessionBuilder,
+            local_name: &str,
+            error: &mut GGRSErrorInfo,
+        ) -> Box<GGRSSession>;
+        fn try_start_spectator_session_on_loopback(
+            self: &mut GGRSSessionBuilder,
+            local_name: &str,
+            host: &str,
+            error: &mut GGRSErrorInfo,
+        ) -> Box<GGRSSession>;
+        fn try_poll_remote_clients(self: &mut GGRSSession) -> GGRSErrorInfo;
+        fn try_add_local_input(
+            self: &mut GGRSSession,
             player_handle: u32,
-        ) -> Result<GGRSNetworkStats>;
-        unsafe fn disconnect_player(
-            mut session: *mut GGRSSession,
+            input: &[u8],
+        ) -> GGRSErrorInfo;
+        fn try_add_local_inputs(
+            self: &mut GGRSSession,
+            local_inputs: &[GGRSLocalInput],
+        ) -> GGRSErrorInfo;
+        fn try_get_current_state(
+            self: &mut GGRSSession,
+            state: &mut GGRSSessionState,
+        ) -> GGRSErrorInfo;
+        fn try_get_events(self: &mut GGRSSession, events: &mut Vec<GGRSEvent>) -> GGRSErrorInfo;
+        fn try_advance_frame(self: &mut GGRSSession, result: &mut GGRSFrameResult)
+            -> GGRSErrorInfo;
+        fn try_frame_pacing(self: &mut GGRSSession, pacing: &mut GGRSFramePacing) -> GGRSErrorInfo;
+        fn try_tick(
+            self: &mut GGRSSession,
+            local_inputs: &[GGRSLocalInput],
+            result: &mut GGRSTickResult,
+        ) -> GGRSErrorInfo;
+        fn try_save_game_state(self: &mut GGRSSession, frame: i32, state: &[u8]) -> GGRSErrorInfo;
+        fn try_submit_checksum(
+            self: &mut GGRSSession,
+            frame: i32,
+            checksum_hi: u64,
+            checksum_lo: u64,
+        ) -> GGRSErrorInfo;
+        fn try_get_frames_ahead(self: &mut GGRSSession, frames_ahead: &mut i32) -> GGRSErrorInfo;
+        fn try_network_stats(
+            self: &mut GGRSSession,
             player_handle: u32,
-        ) -> Result<bool>;
+            stats: &mut GGRSNetworkStats,
+        ) -> GGRSErrorInfo;
+        fn try_disconnect_player(self: &mut GGRSSession, player_handle: u32) -> GGRSErrorInfo;
+        fn try_start_recording(self: &mut GGRSSession, path: &str) -> GGRSErrorInfo;
+        fn try_stop_recording(self: &mut GGRSSession) -> GGRSErrorInfo;
+        fn try_desync_report_path(self: &mut GGRSSession, path: &mut String) -> GGRSErrorInfo;
+        fn try_local_address(self: &mut GGRSSession, addr: &mut String) -> GGRSErrorInfo;
+        fn try_set_event_handler(
+            self: &mut GGRSSession,
+            handler: UniquePtr<GGRSEventHandler>,
+        ) -> GGRSErrorInfo;
+        fn try_start_replay_session(path: &str, error: &mut GGRSErrorInfo) -> Box<GGRSSession>;
+    }
+}
+
+// the largest input a session can be configured with through input_size_bytes
+const MAX_INPUT_SIZE: usize = 64;
+// ggrs keeps this many frames of input per player and panics on an input delay that doesn't fit
+const INPUT_QUEUE_LENGTH: usize = 128;
+
+// fixed size input sent over the network. only the first input_size_bytes are used, the rest stays zeroed
+#[repr(C)]
+#[derive(Copy, Clone, PartialEq, Pod, Zeroable)]
+pub struct GGRSInputBytes {
+    bytes: [u8; MAX_INPUT_SIZE],
+}
+
+impl GGRSInputBytes {
+    fn from_slice(input: &[u8]) -> Self {
+        let mut result = Self::zeroed();
+        result.bytes[..input.len()].copy_from_slice(input);
+        result
     }
 }
 
 pub struct GGRSConfig;
 impl Config for GGRSConfig {
-    type Input = u32; // good for now
-    type State = u8; // good for now not going to use this anyways just gonna supply the user the frame number of action
-    type Address = SocketAddr; // maybe add a way that people can change their socket type. we'll see later.
+    type Input = GGRSInputBytes;
+    type State = Vec<u8>; // serialized game state supplied by the user through save_game_state
+    type Address = String; // opaque to ggrs, each transport decides what its addresses look like
 }
 
 impl Default for GGRSFrameAction {
@@ -189,20 +672,14 @@ impl Default for GGRSFrameAction {
     }
 }
 
-impl Default for GGRSFrameActionInfo {
-    fn default() -> Self {
-        Self {
-            frame: 0,
-            inputs: Vec::new(),
-        }
-    }
-}
-
-impl Default for GGRSFrameResult {
+impl Default for GGRSTickResult {
     fn default() -> Self {
         Self {
+            state: GGRSSessionState::Synchronizing,
+            events: Vec::new(),
             skip_frame: false,
             actions: Vec::new(),
+            checksum_mismatches: Vec::new(),
         }
     }
 }
@@ -215,49 +692,386 @@ impl Default for wrapper::GGRSEvent {
                 addr: "".to_string(),
                 total: 0,
                 count: 0,
-                disconnect_timeout: 0,
+                disconnect_timeout: GGRSDuration::default(),
                 skip_frames: 0,
+                frame: 0,
+                local_checksum_hi: 0,
+                local_checksum_lo: 0,
+                remote_checksum_hi: 0,
+                remote_checksum_lo: 0,
+                player_handles: Vec::new(),
+                player_type: GGRSPlayerType::Remote,
             },
         }
     }
 }
 
-impl Default for GGRSSession {
-    fn default() -> Self {
-        GGRSSession::NotSet
+// an event as the session collected it, before it is translated for get_events or an event handler
+enum GGRSSessionEvent {
+    Ggrs(GGRSEvent<GGRSConfig>),
+    Desync(GGRSDesync),
+}
+
+impl GGRSSessionEvent {
+    fn dispatch(
+        self,
+        handler: Pin<&mut GGRSEventHandler>,
+        peers: &HashMap<String, GGRSPeer>,
+    ) -> Result<(), cxx::Exception> {
+        let peer = |addr: &str| match peers.get(addr) {
+            Some(peer) => (peer.player_handles.clone(), peer.player_type),
+            None => (Vec::new(), GGRSPlayerType::Remote),
+        };
+        match self {
+            GGRSSessionEvent::Ggrs(event) => match event {
+                GGRSEvent::Synchronizing { addr, total, count } => {
+                    let (handles, player_type) = peer(&addr);
+                    handler.on_synchronizing(&addr, &handles, player_type, count, total)
+                }
+                GGRSEvent::Synchronized { addr } => {
+                    let (handles, player_type) = peer(&addr);
+                    handler.on_synchronized(&addr, &handles, player_type)
+                }
+                GGRSEvent::Disconnected { addr } => {
+                    let (handles, player_type) = peer(&addr);
+                    handler.on_disconnected(&addr, &handles, player_type)
+                }
+                GGRSEvent::NetworkInterrupted {
+                    addr,
+                    disconnect_timeout,
+                } => {
+                    let (handles, player_type) = peer(&addr);
+                    handler.on_network_interrupted(
+                        &addr,
+                        &handles,
+                        player_type,
+                        &GGRSDuration::from_millis(disconnect_timeout),
+                    )
+                }
+                GGRSEvent::NetworkResumed { addr } => {
+                    let (handles, player_type) = peer(&addr);
+                    handler.on_network_resumed(&addr, &handles, player_type)
+                }
+                GGRSEvent::WaitRecommendation { skip_frames } => {
+                    handler.on_wait_recommendation(skip_frames)
+                }
+            },
+            GGRSSessionEvent::Desync(desync) => {
+                let (handles, player_type) = peer(&desync.addr);
+                let (local_checksum_hi, local_checksum_lo) = split_checksum(desync.local_checksum);
+                let (remote_checksum_hi, remote_checksum_lo) =
+                    split_checksum(desync.remote_checksum);
+                handler.on_desync_detected(
+                    desync.frame,
+                    &desync.addr,
+                    &handles,
+                    player_type,
+                    local_checksum_hi,
+                    local_checksum_lo,
+                    remote_checksum_hi,
+                    remote_checksum_lo,
+                )
+            }
+        }
+    }
+}
+
+// the players behind an address of a network session, events only name the address
+#[derive(Clone)]
+struct GGRSPeer {
+    player_handles: Vec<u32>,
+    player_type: GGRSPlayerType,
+}
+
+impl wrapper::GGRSEvent {
+    fn desync_detected(desync: GGRSDesync) -> Self {
+        let mut ev = Self::default();
+        let (local_checksum_hi, local_checksum_lo) = split_checksum(desync.local_checksum);
+        let (remote_checksum_hi, remote_checksum_lo) = split_checksum(desync.remote_checksum);
+        ev.event_type = GGRSEventType::DesyncDetected;
+        ev.event_info.addr = desync.addr;
+        ev.event_info.frame = desync.frame;
+        ev.event_info.local_checksum_hi = local_checksum_hi;
+        ev.event_info.local_checksum_lo = local_checksum_lo;
+        ev.event_info.remote_checksum_hi = remote_checksum_hi;
+        ev.event_info.remote_checksum_lo = remote_checksum_lo;
+        ev
     }
 }
 
-pub enum GGRSSession {
-    NotSet,
+// a spectator session is more than twice the size of the others, so it lives on the heap
+#[derive(Default)]
+pub enum GGRSSessionKind {
+    #[default]
+    Closed, // the session has been cleaned up
     Peer2Peer(P2PSession<GGRSConfig>),
-    Spectator(SpectatorSession<GGRSConfig>),
+    Spectator(Box<SpectatorSession<GGRSConfig>>),
     Synctest(SyncTestSession<GGRSConfig>),
+    Replay(GGRSReplayPlayback),
 }
 
-impl Default for GGRSSessionInfo {
-    fn default() -> Self {
+// how many frames of first-seen checksums a synctest session remembers
+const CHECKSUM_HISTORY_FRAMES: i32 = 128;
+
+#[derive(Default)]
+pub struct GGRSSession {
+    kind: GGRSSessionKind,
+    info: GGRSSessionInfo,
+    // the frame the next AdvanceFrame action advances from, followed through the load and advance requests
+    current_frame: Frame,
+    // cells handed out by the last advance_frame call, waiting for the user to fill them with save_game_state
+    pending_saves: HashMap<Frame, GGRSPendingSave>,
+    // first checksum seen for every frame. only used by synctest sessions to name the mismatching frames
+    checksum_history: HashMap<Frame, Option<u128>>,
+    // the frame a synctest session found a mismatch at, it never advances again
+    mismatch_frame: Option<Frame>,
+    recorder: Option<GGRSReplayRecorder>,
+    // only set for p2p sessions with desync detection turned on
+    desync: Option<GGRSDesyncDetector>,
+    // desyncs waiting for get_events or the event handler
+    desync_events: Vec<GGRSDesync>,
+    // set_event_handler replaces get_events with these callbacks
+    event_handler: Option<UniquePtr<GGRSEventHandler>>,
+    // resolved address of every remote peer and spectator, empty without a network
+    peers: HashMap<String, GGRSPeer>,
+    pacer: GGRSFramePacer,
+    // where the transport is bound to, only known for network sessions
+    local_address: Option<String>,
+    // only set for p2p and synctest sessions with a desync report dir
+    reporter: Option<GGRSDesyncReporter>,
+    // set once a call panicked. ggrs may have been left half way through an update so the session is not trusted anymore
+    poisoned: bool,
+}
+
+struct GGRSPendingSave {
+    cell: GameStateCell<Vec<u8>>,
+    checksum: Option<u128>,
+}
+
+impl GGRSSession {
+    fn new(kind: GGRSSessionKind, info: GGRSSessionInfo) -> Self {
+        let mut reporter = None;
+        if !info.desync_report_dir.is_empty()
+            && matches!(
+                kind,
+                GGRSSessionKind::Peer2Peer(_) | GGRSSessionKind::Synctest(_)
+            )
+        {
+            reporter = Some(GGRSDesyncReporter::new(
+                &info.desync_report_dir,
+                info.desync_report_frames,
+            ));
+        }
         Self {
-            session_started: false,
-            session_type: GGRSSessionType::NotSet,
-            num_players: 2, // amount of local and remote players (non-spectator)
-            fps: 60,        // FPS defines the expected update frequency of this session
-            input_delay: 0, // local player input delay
-            max_prediction_frames: 8,
-            max_frames_behind: 10, // If the spectator is more than this amount of frames behind, it will advance the game two steps at a time to catch up
-            catchup_speed: 1, // The amount of frames the spectator advances in a single step if too far behind
-            check_distance: 2, // distance where the synctest is triggerd
-            local_port: 1234,
-            sparse_saving: false, // enable encourage a more conservative saving pattern.
-            host: String::new(),
-            players: Vec::new(),
+            reporter,
+            pacer: GGRSFramePacer::new(info.pacing_mode, info.fps),
+            kind,
+            info,
+            current_frame: 0,
+            recorder: None,
+            desync: None,
+            desync_events: Vec::new(),
+            event_handler: None,
+            peers: HashMap::new(),
+            local_address: None,
+            pending_saves: HashMap::new(),
+            checksum_history: HashMap::new(),
+            mismatch_frame: None,
+            poisoned: false,
+        }
+    }
+
+    // runs a session call, a panic inside of it poisons the session instead of unwinding into c++
+    fn guard<T>(&mut self, call: impl FnOnce(&mut Self) -> Result<T, Error>) -> Result<T, Error> {
+        if self.poisoned {
+            return Err(Error::session_poisoned());
+        }
+        let result = catch_panic(|| call(self));
+        if let Err(err) = &result {
+            if err.code == GGRSErrorCode::Panic {
+                self.poisoned = true;
+            }
+        }
+        result
+    }
+
+    // everything that happened since the events were last taken, ggrs events first
+    fn take_events(&mut self) -> Result<Vec<GGRSSessionEvent>, Error> {
+        let mut events = Vec::new();
+        match &mut self.kind {
+            GGRSSessionKind::Closed => return Err(Error::session_closed()),
+            GGRSSessionKind::Peer2Peer(sess) => {
+                events.extend(sess.events().map(GGRSSessionEvent::Ggrs));
+            }
+            GGRSSessionKind::Spectator(sess) => {
+                events.extend(sess.events().map(GGRSSessionEvent::Ggrs));
+            }
+            GGRSSessionKind::Synctest(_) | GGRSSessionKind::Replay(_) => (),
+        }
+        events.extend(self.desync_events.drain(..).map(GGRSSessionEvent::Desync));
+        for event in &events {
+            if let GGRSSessionEvent::Ggrs(GGRSEvent::WaitRecommendation { skip_frames }) = event {
+                self.pacer.wait_recommendation(*skip_frames);
+            }
+        }
+        Ok(events)
+    }
+
+    fn poll(&mut self) -> Result<(), Error> {
+        match &mut self.kind {
+            GGRSSessionKind::Closed => return Err(Error::session_closed()),
+            GGRSSessionKind::Peer2Peer(sess) => sess.poll_remote_clients(),
+            GGRSSessionKind::Spectator(sess) => sess.poll_remote_clients(),
+            GGRSSessionKind::Synctest(_) | GGRSSessionKind::Replay(_) => (),
+        }
+        Ok(())
+    }
+
+    // every event is delivered even when a callback throws, the first exception is reported
+    fn dispatch_events(&mut self, events: Vec<GGRSSessionEvent>) -> Result<(), Error> {
+        let handler = match self.event_handler.as_mut() {
+            Some(it) => it,
+            None => return Ok(()),
+        };
+        let mut failed = None;
+        for event in events {
+            if let Err(err) = event.dispatch(handler.pin_mut(), &self.peers) {
+                failed.get_or_insert(err);
+            }
+        }
+        match failed {
+            Some(err) => Err(Error {
+                code: GGRSErrorCode::EventHandlerFailed,
+                msg: format!("Error event handler threw: {}", err.what()),
+            }),
+            None => Ok(()),
+        }
+    }
+
+    fn fill_event_players(&self, ev: &mut wrapper::GGRSEvent) {
+        if let Some(peer) = self.peers.get(&ev.event_info.addr) {
+            ev.event_info.player_handles = peer.player_handles.clone();
+            ev.event_info.player_type = peer.player_type;
+        }
+    }
+
+    fn check_input_size(&self, player_handle: u32, input: &[u8]) -> Result<(), Error> {
+        let input_size = self.info.input_size_bytes as usize;
+        if input.len() != input_size {
+            return Err(Error {
+                code: GGRSErrorCode::InvalidInputSize,
+                msg: format!(
+                    "Error input of player {} has {} bytes, the session expects: {}",
+                    player_handle,
+                    input.len(),
+                    input_size
+                ),
+            });
+        }
+        Ok(())
+    }
+
+    fn add_input(&mut self, player_handle: u32, input: &[u8]) -> Result<(), Error> {
+        let input = GGRSInputBytes::from_slice(input);
+        // ggrs also refuses input while synchronizing, so pass its reason on instead of guessing
+        let added = match &mut self.kind {
+            GGRSSessionKind::Closed => return Err(Error::session_closed()),
+            GGRSSessionKind::Peer2Peer(sess) => sess.add_local_input(player_handle as usize, input),
+            GGRSSessionKind::Spectator(_) | GGRSSessionKind::Replay(_) => Ok(()),
+            GGRSSessionKind::Synctest(sess) => sess.add_local_input(player_handle as usize, input),
+        };
+        added.map_err(Error::from)
+    }
+
+    fn check_open(&self) -> Result<(), Error> {
+        if let GGRSSessionKind::Closed = self.kind {
+            return Err(Error::session_closed());
+        }
+        Ok(())
+    }
+
+    // compares the checksums of the saves fulfilled since the last advance_frame against the first ones seen
+    fn compare_checksums(&mut self) -> Vec<GGRSChecksumMismatch> {
+        let mut mismatches = Vec::new();
+        if !matches!(self.kind, GGRSSessionKind::Synctest(_)) {
+            return mismatches;
+        }
+        for (&frame, pending) in &self.pending_saves {
+            match self.checksum_history.get(&frame) {
+                Some(&original) if original != pending.checksum => {
+                    mismatches.push(GGRSChecksumMismatch::new(frame, original, pending.checksum));
+                }
+                Some(_) => (),
+                None => {
+                    self.checksum_history.insert(frame, pending.checksum);
+                }
+            }
+        }
+        if let Some(&newest) = self.pending_saves.keys().max() {
+            self.checksum_history
+                .retain(|&frame, _| frame > newest - CHECKSUM_HISTORY_FRAMES);
+        }
+        mismatches.sort_by_key(|m| m.frame);
+        mismatches
+    }
+}
+
+impl GGRSChecksumMismatch {
+    fn new(frame: Frame, original: Option<u128>, resimulated: Option<u128>) -> Self {
+        let (original_checksum_hi, original_checksum_lo) = split_checksum(original.unwrap_or(0));
+        let (resimulated_checksum_hi, resimulated_checksum_lo) =
+            split_checksum(resimulated.unwrap_or(0));
+        Self {
+            frame,
+            has_original_checksum: original.is_some(),
+            original_checksum_hi,
+            original_checksum_lo,
+            has_resimulated_checksum: resimulated.is_some(),
+            resimulated_checksum_hi,
+            resimulated_checksum_lo,
+        }
+    }
+
+    pub fn original_checksum(&self) -> Option<u128> {
+        self.has_original_checksum
+            .then(|| join_checksum(self.original_checksum_hi, self.original_checksum_lo))
+    }
+
+    pub fn resimulated_checksum(&self) -> Option<u128> {
+        self.has_resimulated_checksum
+            .then(|| join_checksum(self.resimulated_checksum_hi, self.resimulated_checksum_lo))
+    }
+}
+
+// everything c++ can call goes through this, unwinding across the ffi boundary aborts the process
+fn catch_panic<T>(call: impl FnOnce() -> Result<T, Error>) -> Result<T, Error> {
+    match panic::catch_unwind(AssertUnwindSafe(call)) {
+        Ok(result) => result,
+        Err(payload) => Err(Error::panic(payload)),
+    }
+}
+
+fn split_checksum(checksum: u128) -> (u64, u64) {
+    ((checksum >> 64) as u64, checksum as u64)
+}
+
+fn join_checksum(checksum_hi: u64, checksum_lo: u64) -> u128 {
+    ((checksum_hi as u128) << 64) | checksum_lo as u128
+}
+
+impl GGRSDuration {
+    pub fn from_millis(ms: u128) -> Self {
+        Self {
+            ms: u64::try_from(ms).unwrap_or(u64::MAX),
+            us: u64::try_from(ms.saturating_mul(1000)).unwrap_or(u64::MAX),
         }
     }
 }
+
 impl GGRSNetworkStats {
     pub fn new(
         send_queue_len: u32,
-        ping: u64,
+        ping: GGRSDuration,
         kbps_sent: u64,
         local_frames_behind: i32,
         remote_frames_behind: i32,
@@ -272,493 +1086,1533 @@ impl GGRSNetworkStats {
     }
 }
 
-impl GGRSSessionInfo {
-    fn setup(&mut self) {
-        let tmp = GGRSSessionInfo::default();
-        // set default values
-        self.session_type = tmp.session_type;
-        self.num_players = tmp.num_players;
-        self.fps = tmp.fps;
-        self.input_delay = tmp.input_delay;
-        self.max_prediction_frames = tmp.max_prediction_frames;
-        self.max_frames_behind = tmp.max_frames_behind;
-        self.catchup_speed = tmp.catchup_speed;
-        self.check_distance = tmp.check_distance;
-        self.local_port = tmp.local_port;
-        self.sparse_saving = tmp.sparse_saving;
-        self.host = String::new();
-        self.players = Vec::new();
-        self.session_started = tmp.session_started;
-    }
-
-    fn set_num_players(&mut self, num_players: u32) {
-        self.num_players = num_players;
-    }
-
-    fn set_sparse_saving(&mut self, enable: bool) {
-        self.sparse_saving = enable;
-    }
-
-    fn add_player(&mut self, player: GGRSPlayer) {
-        self.players.push(player);
-    }
+// configuration collected by a GGRSSessionBuilder. the session type is only picked by the start call
+#[derive(Clone)]
+pub struct GGRSSessionInfo {
+    num_players: u32,
+    fps: u32,
+    input_delay: u32,
+    input_size_bytes: u32,
+    max_prediction_frames: u32,
+    max_frames_behind: u32,
+    catchup_speed: u32,
+    check_distance: u32,
+    disconnect_timeout_ms: u64,
+    disconnect_notify_delay_ms: u64,
+    sparse_saving: bool,
+    desync_detection_interval: u32,
+    desync_report_dir: String,
+    desync_report_frames: u32,
+    address_preference: GGRSAddressPreference,
+    bind_address: Option<IpAddr>,
+    pacing_mode: GGRSPacingMode,
+    network_conditions: Option<GGRSNetworkConditions>,
+    players: Vec<GGRSPlayer>,
 }
 
-// outward facing functions
-
-fn setup_ggrs_info(info: &mut GGRSSessionInfo) -> bool {
-    // should be called before any other function to set default values.
-    info.setup();
-    return true;
+#[derive(Clone, Copy, PartialEq)]
+enum GGRSSessionType {
+    Peer2Peer,
+    Spectator,
+    Synctest,
 }
 
-fn add_player(info: &mut GGRSSessionInfo, player: GGRSPlayer) -> bool {
-    if info.session_type != GGRSSessionType::NotSet {
-        info.add_player(player);
-        return true;
+impl Default for GGRSSessionInfo {
+    fn default() -> Self {
+        Self {
+            num_players: 2,      // amount of local and remote players (non-spectator)
+            fps: 60,             // FPS defines the expected update frequency of this session
+            input_delay: 0,      // local player input delay
+            input_size_bytes: 4, // size of a single players input, at most MAX_INPUT_SIZE
+            max_prediction_frames: 8,
+            max_frames_behind: 10, // If the spectator is more than this amount of frames behind, it will advance the game two steps at a time to catch up
+            catchup_speed: 1, // The amount of frames the spectator advances in a single step if too far behind
+            check_distance: 2, // distance where the synctest is triggerd
+            disconnect_timeout_ms: 2000, // remote peers that stay silent this long are disconnected
+            disconnect_notify_delay_ms: 500, // silence before a NetworkInterrupted event is sent
+            sparse_saving: false, // enable encourage a more conservative saving pattern.
+            desync_detection_interval: 0, // peers do not compare checksums
+            desync_report_dir: String::new(), // desyncs are not dumped
+            desync_report_frames: 120, // frames of inputs and states a desync report looks back
+            address_preference: GGRSAddressPreference::IPv4,
+            bind_address: None, // every interface, ipv6 only when a peer needs it
+            pacing_mode: GGRSPacingMode::FixedStep,
+            network_conditions: None, // the real network is used as is
+            players: Vec::new(),
+        }
     }
-    return false;
 }
 
-fn setup_p2p_session(
-    info: &mut GGRSSessionInfo,
-    local_port: u16,
-    fps: u32,
-    input_delay: u32,
-    max_prediction_frames: u32,
-) -> bool {
-    if info.session_type == GGRSSessionType::NotSet {
-        info.session_type = GGRSSessionType::Peer2Peer;
-        info.max_prediction_frames = max_prediction_frames;
-        info.input_delay = input_delay;
-        info.local_port = local_port;
-        info.fps = fps;
-        return true;
+impl GGRSSessionInfo {
+    // resolves the address of every remote player, spectator and the host once, so ggrs and the session agree
+    // on them even when a hostname resolves differently later
+    fn resolve_addresses(
+        &self,
+        host: &str,
+        resolve_address: impl Fn(&str) -> Result<String, String>,
+    ) -> Result<HashMap<String, String>, Error> {
+        let mut resolved = HashMap::new();
+        for player in &self.players {
+            if player.player_type == GGRSPlayerType::Local
+                || resolved.contains_key(&player.socket_addr)
+            {
+                continue;
+            }
+            match resolve_address(&player.socket_addr) {
+                Ok(addr) => resolved.insert(player.socket_addr.clone(), addr),
+                Err(reason) => {
+                    return Err(Error {
+                        code: GGRSErrorCode::AddressParseFailed,
+                        msg: format!(
+                            "Error resolving address of player {} \"{}\": {}",
+                            player.player_handle, player.socket_addr, reason
+                        ),
+                    })
+                }
+            };
+        }
+        if !host.is_empty() && !resolved.contains_key(host) {
+            match resolve_address(host) {
+                Ok(addr) => resolved.insert(host.to_string(), addr),
+                Err(reason) => {
+                    return Err(Error {
+                        code: GGRSErrorCode::AddressParseFailed,
+                        msg: format!("Error resolving host address \"{}\": {}", host, reason),
+                    })
+                }
+            };
+        }
+        Ok(resolved)
     }
-    return false;
-}
 
-fn setup_spectator_session(
-    info: &mut GGRSSessionInfo,
-    local_port: u16,
-    host: String,
-    max_frames_behind: u32,
-    catchup_speed: u32,
-) -> bool {
-    if info.session_type == GGRSSessionType::NotSet {
-        info.session_type = GGRSSessionType::Spectator;
-        info.max_frames_behind = max_frames_behind;
-        info.catchup_speed = catchup_speed;
-        info.local_port = local_port;
-        info.host = host;
-        return true;
+    // every peer a p2p session talks to, several remote players may share one
+    fn remote_addresses(&self, resolve_address: impl Fn(&str) -> Option<String>) -> Vec<String> {
+        let mut addrs: Vec<String> = Vec::new();
+        for player in &self.players {
+            if player.player_type != GGRSPlayerType::Remote {
+                continue;
+            }
+            if let Some(addr) = resolve_address(&player.socket_addr) {
+                if !addrs.contains(&addr) {
+                    addrs.push(addr);
+                }
+            }
+        }
+        addrs
     }
-    return false;
-}
 
-fn setup_synctest_session(
-    info: &mut GGRSSessionInfo,
-    check_distance: u32,
-    input_delay: u32,
-) -> bool {
-    if info.session_type == GGRSSessionType::NotSet {
-        info.session_type = GGRSSessionType::Synctest;
-        info.check_distance = check_distance;
-        info.input_delay = input_delay;
-        return true;
+    // groups the registered players by resolved address. the host of a spectator session sends the inputs
+    // of every player, so all of them are listed for it
+    fn peers(
+        &self,
+        resolve_address: impl Fn(&str) -> Option<String>,
+        host_addr: &str,
+    ) -> HashMap<String, GGRSPeer> {
+        let mut peers: HashMap<String, GGRSPeer> = HashMap::new();
+        if !host_addr.is_empty() {
+            let peer = GGRSPeer {
+                player_handles: (0..self.num_players).collect(),
+                player_type: GGRSPlayerType::Remote,
+            };
+            peers.insert(host_addr.to_string(), peer);
+            return peers;
+        }
+        for player in &self.players {
+            if player.player_type == GGRSPlayerType::Local {
+                continue;
+            }
+            let Some(addr) = resolve_address(&player.socket_addr) else {
+                continue;
+            };
+            let peer = peers.entry(addr).or_insert_with(|| GGRSPeer {
+                player_handles: Vec::new(),
+                player_type: player.player_type,
+            });
+            peer.player_handles.push(player.player_handle);
+        }
+        for peer in peers.values_mut() {
+            peer.player_handles.sort_unstable();
+        }
+        peers
     }
-    return false;
-}
 
-fn set_num_players(info: &mut GGRSSessionInfo, num: u32) -> bool {
-    // should be set before calling any session setup
-    if info.session_type == GGRSSessionType::NotSet {
-        info.set_num_players(num);
-        return true;
+    // ggrs subtracts the notify delay from the timeout and would panic on an underflow. checked once the
+    // session starts, so the two can be set in any order
+    fn check_disconnect_timing(&self) -> Result<(), Error> {
+        if self.disconnect_notify_delay_ms >= self.disconnect_timeout_ms {
+            return Err(Error {
+                code: GGRSErrorCode::InvalidConfiguration,
+                msg: format!(
+                    "Error disconnect notify delay ({} ms) must be shorter than the disconnect timeout ({} ms)",
+                    self.disconnect_notify_delay_ms, self.disconnect_timeout_ms
+                ),
+            });
+        }
+        Ok(())
     }
-    return false;
-}
 
-fn set_sparse_saving(info: &mut GGRSSessionInfo, enable: bool) -> bool {
-    // should be set before calling any session setup
-    if info.session_type == GGRSSessionType::NotSet {
-        info.set_sparse_saving(enable);
-        return true;
-    }
-    return false;
-}
+    // hands the whole configuration to a ggrs builder, which does most of the validation for us
+    fn ggrs_builder(
+        &self,
+        resolve_address: impl Fn(&str) -> Option<String>,
+    ) -> Result<SessionBuilder<GGRSConfig>, Error> {
+        if self.input_size_bytes == 0 || self.input_size_bytes as usize > MAX_INPUT_SIZE {
+            return Err(Error {
+                code: GGRSErrorCode::InvalidConfiguration,
+                msg: format!(
+                    "Error input size must be between 1 and {} bytes, got: {}",
+                    MAX_INPUT_SIZE, self.input_size_bytes
+                ),
+            });
+        }
 
-fn create_session(info: &mut GGRSSessionInfo) -> Result<*mut GGRSSession, Error> {
-    if info.session_type != GGRSSessionType::NotSet && !info.session_started {
         let mut sess_build = SessionBuilder::<GGRSConfig>::new()
-            .with_num_players(info.num_players as usize)
-            .with_sparse_saving_mode(info.sparse_saving);
+            .with_num_players(self.num_players as usize)
+            .with_sparse_saving_mode(self.sparse_saving)
+            .with_input_delay(self.input_delay as usize)
+            .with_max_prediction_window(self.max_prediction_frames as usize)
+            .with_check_distance(self.check_distance as usize)
+            .with_disconnect_timeout(Duration::from_millis(self.disconnect_timeout_ms))
+            .with_disconnect_notify_delay(Duration::from_millis(self.disconnect_notify_delay_ms));
+
+        sess_build = match sess_build.with_fps(self.fps as usize) {
+            Ok(it) => it,
+            Err(err) => return Err(Error::invalid_configuration(err)),
+        };
+
+        sess_build = match sess_build.with_max_frames_behind(self.max_frames_behind as usize) {
+            Ok(it) => it,
+            Err(err) => return Err(Error::invalid_configuration(err)),
+        };
+
+        sess_build = match sess_build.with_catchup_speed(self.catchup_speed as usize) {
+            Ok(it) => it,
+            Err(err) => return Err(Error::invalid_configuration(err)),
+        };
 
         // add players
-        for p in &info.players {
-            match p.player_type {
-                GGRSPlayerType::Local => {
-                    sess_build = match sess_build
-                        .add_player(PlayerType::Local, p.player_handle as PlayerHandle)
-                    {
-                        Ok(it) => it,
-                        Err(err) => {
-                            return Err(Error {
-                                msg: err.to_string(),
-                            })
-                        }
-                    };
-                }
-                GGRSPlayerType::Remote => {
-                    let sock: SocketAddr = match p.socket_addr.parse() {
-                        Ok(it) => it,
-                        Err(_) => {
-                            return Err(Error {
-                                msg: "Error parsing remote player address".to_string(),
-                            })
-                        }
-                    };
-
-                    sess_build = match sess_build
-                        .add_player(PlayerType::Remote(sock), p.player_handle as PlayerHandle)
-                    {
-                        Ok(it) => it,
-                        Err(err) => {
-                            return Err(Error {
-                                msg: err.to_string(),
-                            })
-                        }
-                    };
-                }
-                GGRSPlayerType::Spectator => {
-                    let sock: SocketAddr = match p.socket_addr.parse() {
-                        Ok(it) => it,
-                        Err(_) => {
-                            return Err(Error {
-                                msg: "Error parsing remote player address".to_string(),
-                            })
-                        }
-                    };
-
-                    sess_build = match sess_build
-                        .add_player(PlayerType::Spectator(sock), p.player_handle as PlayerHandle)
-                    {
-                        Ok(it) => it,
-                        Err(err) => {
+        for p in &self.players {
+            let player_type = match p.player_type {
+                GGRSPlayerType::Local => PlayerType::Local,
+                GGRSPlayerType::Remote | GGRSPlayerType::Spectator => {
+                    let sock = match resolve_address(&p.socket_addr) {
+                        Some(it) => it,
+                        None => {
                             return Err(Error {
-                                msg: err.to_string(),
+                                code: GGRSErrorCode::AddressParseFailed,
+                                msg: format!(
+                                    "Error parsing address of player {}: {}",
+                                    p.player_handle, p.socket_addr
+                                ),
                             })
                         }
                     };
+                    if p.player_type == GGRSPlayerType::Remote {
+                        PlayerType::Remote(sock)
+                    } else {
+                        PlayerType::Spectator(sock)
+                    }
                 }
                 _ => {
                     return Err(Error {
+                        code: GGRSErrorCode::InvalidConfiguration,
                         msg: "Error unsupported player type".to_string(),
                     })
                 }
-            }
+            };
+            sess_build = match sess_build.add_player(player_type, p.player_handle as PlayerHandle) {
+                Ok(it) => it,
+                Err(err) => return Err(Error::invalid_configuration(err)),
+            };
         }
-        let session: GGRSSession;
-        // create the session
-        match info.session_type {
-            GGRSSessionType::Peer2Peer => {
-                sess_build = match sess_build.with_fps(info.fps as usize) {
-                    Ok(it) => it,
-                    Err(err) => {
-                        return Err(Error {
-                            msg: err.to_string(),
-                        })
-                    }
-                };
-
-                sess_build = sess_build
-                    .with_input_delay(info.input_delay as usize)
-                    .with_max_prediction_window(info.max_prediction_frames as usize);
+        Ok(sess_build)
+    }
+}
 
-                let sock = match UdpNonBlockingSocket::bind_to_port(info.local_port) {
-                    Ok(it) => it,
-                    Err(_) => {
-                        return Err(Error {
-                            msg: format!("Error Couldnt bind to port: {}", info.local_port),
-                        })
-                    }
-                };
+#[derive(Default)]
+pub struct GGRSSessionBuilder {
+    info: GGRSSessionInfo,
+}
 
-                session = GGRSSession::Peer2Peer(match sess_build.start_p2p_session(sock) {
-                    Ok(it) => it,
-                    Err(err) => {
-                        return Err(Error {
-                            msg: err.to_string(),
-                        })
-                    }
+// outward facing functions
+
+pub fn new_session_builder() -> Box<GGRSSessionBuilder> {
+    Box::default()
+}
+
+impl GGRSSessionBuilder {
+    pub fn with_num_players(&mut self, num_players: u32) -> Result<&mut Self, Error> {
+        self.update(|info| {
+            if num_players == 0 {
+                return Err(Error {
+                    code: GGRSErrorCode::InvalidConfiguration,
+                    msg: "Error a session needs at least one player".to_string(),
                 });
             }
-            GGRSSessionType::Spectator => {
-                sess_build =
-                    match sess_build.with_max_frames_behind(info.max_frames_behind as usize) {
-                        Ok(it) => it,
-                        Err(err) => {
-                            return Err(Error {
-                                msg: err.to_string(),
-                            })
-                        }
-                    };
+            info.num_players = num_players;
+            Ok(())
+        })
+    }
 
-                sess_build = match sess_build.with_catchup_speed(info.catchup_speed as usize) {
-                    Ok(it) => it,
-                    Err(err) => {
-                        return Err(Error {
-                            msg: err.to_string(),
-                        })
-                    }
-                };
+    pub fn with_input_size(&mut self, bytes: u32) -> Result<&mut Self, Error> {
+        self.update(|info| {
+            info.input_size_bytes = bytes;
+            Ok(())
+        })
+    }
 
-                let sock = match UdpNonBlockingSocket::bind_to_port(info.local_port) {
-                    Ok(it) => it,
-                    Err(_) => {
-                        return Err(Error {
-                            msg: format!("Error Couldnt bind to port: {}", info.local_port),
-                        })
-                    }
-                };
+    pub fn with_fps(&mut self, fps: u32) -> Result<&mut Self, Error> {
+        self.update(|info| {
+            info.fps = fps;
+            Ok(())
+        })
+    }
 
-                let host_addr: SocketAddr = match info.host.parse() {
-                    Ok(it) => it,
-                    Err(_) => {
-                        return Err(Error {
-                            msg: "Error parsing host player address".to_string(),
-                        })
-                    }
-                };
+    pub fn with_input_delay(&mut self, delay: u32) -> Result<&mut Self, Error> {
+        self.update(|info| {
+            if delay as usize >= INPUT_QUEUE_LENGTH {
+                return Err(Error {
+                    code: GGRSErrorCode::InvalidConfiguration,
+                    msg: format!(
+                        "Error input delay must be below {} frames, got: {}",
+                        INPUT_QUEUE_LENGTH, delay
+                    ),
+                });
+            }
+            info.input_delay = delay;
+            Ok(())
+        })
+    }
+
+    pub fn with_max_prediction_window(&mut self, window: u32) -> Result<&mut Self, Error> {
+        self.update(|info| {
+            info.max_prediction_frames = window;
+            Ok(())
+        })
+    }
+
+    pub fn with_max_frames_behind(&mut self, max_frames_behind: u32) -> Result<&mut Self, Error> {
+        self.update(|info| {
+            info.max_frames_behind = max_frames_behind;
+            Ok(())
+        })
+    }
+
+    pub fn with_catchup_speed(&mut self, catchup_speed: u32) -> Result<&mut Self, Error> {
+        self.update(|info| {
+            info.catchup_speed = catchup_speed;
+            Ok(())
+        })
+    }
+
+    pub fn with_check_distance(&mut self, check_distance: u32) -> Result<&mut Self, Error> {
+        self.update(|info| {
+            info.check_distance = check_distance;
+            Ok(())
+        })
+    }
+
+    pub fn with_disconnect_timeout(&mut self, timeout_ms: u64) -> Result<&mut Self, Error> {
+        self.update(|info| {
+            info.disconnect_timeout_ms = timeout_ms;
+            Ok(())
+        })
+    }
 
-                session =
-                    GGRSSession::Spectator(sess_build.start_spectator_session(host_addr, sock));
+    pub fn with_disconnect_notify_delay(
+        &mut self,
+        notify_delay_ms: u64,
+    ) -> Result<&mut Self, Error> {
+        self.update(|info| {
+            info.disconnect_notify_delay_ms = notify_delay_ms;
+            Ok(())
+        })
+    }
+
+    pub fn with_network_conditions(
+        &mut self,
+        conditions: GGRSNetworkConditions,
+    ) -> Result<&mut Self, Error> {
+        self.update(|info| {
+            for (name, chance) in [
+                ("packet loss", conditions.packet_loss),
+                ("duplication", conditions.duplication),
+                ("reordering", conditions.reordering),
+            ] {
+                if !(0.0..=1.0).contains(&chance) {
+                    return Err(Error {
+                        code: GGRSErrorCode::InvalidConfiguration,
+                        msg: format!("Error {} must be between 0 and 1, got: {}", name, chance),
+                    });
+                }
             }
-            GGRSSessionType::Synctest => {
-                sess_build = sess_build
-                    .with_check_distance(info.check_distance as usize)
-                    .with_input_delay(info.input_delay as usize);
+            info.network_conditions = Some(conditions);
+            Ok(())
+        })
+    }
 
-                session = GGRSSession::Synctest(match sess_build.start_synctest_session() {
-                    Ok(it) => it,
-                    Err(err) => {
-                        return Err(Error {
-                            msg: err.to_string(),
-                        })
-                    }
+    pub fn with_sparse_saving_mode(&mut self, enable: bool) -> Result<&mut Self, Error> {
+        self.update(|info| {
+            info.sparse_saving = enable;
+            Ok(())
+        })
+    }
+
+    pub fn with_desync_detection_interval(&mut self, interval: u32) -> Result<&mut Self, Error> {
+        self.update(|info| {
+            info.desync_detection_interval = interval;
+            Ok(())
+        })
+    }
+
+    pub fn with_desync_report_dir(&mut self, dir: &str) -> Result<&mut Self, Error> {
+        self.update(|info| {
+            info.desync_report_dir = dir.to_string();
+            Ok(())
+        })
+    }
+
+    pub fn with_address_preference(
+        &mut self,
+        preference: GGRSAddressPreference,
+    ) -> Result<&mut Self, Error> {
+        self.update(|info| {
+            if preference != GGRSAddressPreference::IPv4
+                && preference != GGRSAddressPreference::IPv6
+            {
+                return Err(Error {
+                    code: GGRSErrorCode::InvalidConfiguration,
+                    msg: "Error unsupported address preference".to_string(),
                 });
             }
-            _ => {
+            info.address_preference = preference;
+            Ok(())
+        })
+    }
+
+    pub fn with_pacing_mode(&mut self, mode: GGRSPacingMode) -> Result<&mut Self, Error> {
+        self.update(|info| {
+            if mode != GGRSPacingMode::FixedStep && mode != GGRSPacingMode::VariableRefresh {
                 return Err(Error {
-                    msg: "Error unsupported session type".to_string(),
-                })
+                    code: GGRSErrorCode::InvalidConfiguration,
+                    msg: "Error unsupported pacing mode".to_string(),
+                });
             }
-        }
-        info.session_started = true;
-        // return the created session
-        return Ok(Box::into_raw(Box::new(session)));
+            info.pacing_mode = mode;
+            Ok(())
+        })
     }
-    Err(Error {
-        msg: "Error session already started or session type not set".to_string(),
-    })
-}
 
-#[allow(unused_assignments)]
-fn poll_remote_clients(mut session: *mut GGRSSession) -> bool {
-    let mut sess = unsafe { Box::from_raw(session) };
-    match sess.as_mut() {
-        GGRSSession::NotSet => (),
-        GGRSSession::Peer2Peer(sess) => sess.poll_remote_clients(),
-        GGRSSession::Spectator(sess) => sess.poll_remote_clients(),
-        GGRSSession::Synctest(_) => (),
+    pub fn with_bind_address(&mut self, ip: &str) -> Result<&mut Self, Error> {
+        self.update(|info| {
+            if ip.is_empty() {
+                info.bind_address = None;
+                return Ok(());
+            }
+            match ip.parse::<IpAddr>() {
+                Ok(it) => info.bind_address = Some(it),
+                Err(_) => {
+                    return Err(Error {
+                        code: GGRSErrorCode::AddressParseFailed,
+                        msg: format!("Error bind address is not an ip address: \"{}\"", ip),
+                    })
+                }
+            }
+            Ok(())
+        })
     }
-    session = Box::into_raw(sess);
-    return true;
-}
 
-#[allow(unused_assignments)]
-fn add_local_input(
-    mut session: *mut GGRSSession,
-    player_handle: u32,
-    input: u32,
-) -> Result<bool, Error> {
-    let mut sess = unsafe { Box::from_raw(session) };
-    let mut has_failed = false;
+    pub fn with_desync_report_frames(&mut self, frames: u32) -> Result<&mut Self, Error> {
+        self.update(|info| {
+            if frames == 0 {
+                return Err(Error {
+                    code: GGRSErrorCode::InvalidConfiguration,
+                    msg: "Error a desync report needs at least one frame".to_string(),
+                });
+            }
+            info.desync_report_frames = frames;
+            Ok(())
+        })
+    }
 
-    match sess.as_mut() {
-        GGRSSession::NotSet => (),
-        GGRSSession::Peer2Peer(sess) => {
-            if sess.add_local_input(player_handle as usize, input).is_err() {
-                has_failed = true;
+    pub fn add_player(&mut self, player: GGRSPlayer) -> Result<&mut Self, Error> {
+        self.update(|info| {
+            if player.player_type != GGRSPlayerType::Local && player.socket_addr.is_empty() {
+                return Err(Error {
+                    code: GGRSErrorCode::AddressParseFailed,
+                    msg: format!("Error player {} has no address", player.player_handle),
+                });
             }
-        }
-        GGRSSession::Spectator(_) => (),
-        GGRSSession::Synctest(sess) => {
-            if sess.add_local_input(player_handle as usize, input).is_err() {
-                has_failed = true;
+            info.players.push(player);
+            Ok(())
+        })
+    }
+
+    pub fn start_p2p_session(&mut self, local_port: u16) -> Result<Box<GGRSSession>, Error> {
+        self.start(GGRSSessionType::Peer2Peer, "", |addrs| {
+            bind_udp_socket(self.info.bind_address, local_port, addrs)
+        })
+    }
+
+    pub fn start_spectator_session(
+        &mut self,
+        local_port: u16,
+        host: &str,
+    ) -> Result<Box<GGRSSession>, Error> {
+        self.start(GGRSSessionType::Spectator, host, |addrs| {
+            bind_udp_socket(self.info.bind_address, local_port, addrs)
+        })
+    }
+
+    pub fn start_synctest_session(&mut self) -> Result<Box<GGRSSession>, Error> {
+        // a synctest session never touches the network, the transport is only there to resolve addresses
+        self.start::<GGRSUdpSocket>(GGRSSessionType::Synctest, "", |_| {
+            unreachable!("synctest sessions do not bind a socket")
+        })
+    }
+
+    pub fn start_p2p_session_with_socket(
+        &mut self,
+        socket: UniquePtr<GGRSNonBlockingSocket>,
+    ) -> Result<Box<GGRSSession>, Error> {
+        self.start(GGRSSessionType::Peer2Peer, "", |_| custom_socket(socket))
+    }
+
+    pub fn start_spectator_session_with_socket(
+        &mut self,
+        host: &str,
+        socket: UniquePtr<GGRSNonBlockingSocket>,
+    ) -> Result<Box<GGRSSession>, Error> {
+        self.start(GGRSSessionType::Spectator, host, |_| custom_socket(socket))
+    }
+
+    pub fn start_p2p_session_on_loopback(
+        &mut self,
+        local_name: &str,
+    ) -> Result<Box<GGRSSession>, Error> {
+        self.start(GGRSSessionType::Peer2Peer, "", |_| {
+            bind_loopback_socket(local_name)
+        })
+    }
+
+    pub fn start_spectator_session_on_loopback(
+        &mut self,
+        local_name: &str,
+        host: &str,
+    ) -> Result<Box<GGRSSession>, Error> {
+        self.start(GGRSSessionType::Spectator, host, |_| {
+            bind_loopback_socket(local_name)
+        })
+    }
+
+    // applies a change to a copy of the configuration and only keeps it when ggrs accepts the result
+    fn update(
+        &mut self,
+        change: impl FnOnce(&mut GGRSSessionInfo) -> Result<(), Error>,
+    ) -> Result<&mut Self, Error> {
+        catch_panic(|| {
+            let mut info = self.info.clone();
+            change(&mut info)?;
+            // addresses are checked by the transport once the session starts
+            info.ggrs_builder(|addr| Some(addr.to_string()))?;
+            self.info = info;
+            Ok(())
+        })?;
+        Ok(self)
+    }
+
+    fn start<S: GGRSTransport>(
+        &self,
+        session_type: GGRSSessionType,
+        host: &str,
+        bind_socket: impl FnOnce(&[String]) -> Result<S, Error>,
+    ) -> Result<Box<GGRSSession>, Error> {
+        catch_panic(|| {
+            self.info.check_disconnect_timing()?;
+            let preference = self.info.address_preference;
+            let resolved = self
+                .info
+                .resolve_addresses(host, |addr| S::resolve_address(addr, preference))?;
+            let resolve = |addr: &str| resolved.get(addr).cloned();
+            let sess_build = self.info.ggrs_builder(resolve)?;
+            let session: GGRSSessionKind;
+            let mut desync = None;
+            let mut peers = HashMap::new();
+            let mut local_address = None;
+            // create the session
+            match session_type {
+                GGRSSessionType::Peer2Peer | GGRSSessionType::Spectator => {
+                    let mut host_addr = String::new();
+                    if session_type == GGRSSessionType::Spectator {
+                        host_addr = match resolve(host) {
+                            Some(it) => it,
+                            None => {
+                                return Err(Error {
+                                    code: GGRSErrorCode::AddressParseFailed,
+                                    msg: "Error a spectator session needs a host address"
+                                        .to_string(),
+                                })
+                            }
+                        };
+                    }
+
+                    let interval = self.info.desync_detection_interval;
+                    if session_type == GGRSSessionType::Peer2Peer && interval > 0 {
+                        desync = Some(GGRSDesyncDetector::new(
+                            interval,
+                            self.info.remote_addresses(resolve),
+                        ));
+                    }
+                    let checksums = desync.as_ref().map(GGRSDesyncDetector::channel);
+
+                    peers = self.info.peers(resolve, &host_addr);
+                    let addrs: Vec<String> = resolved.values().cloned().collect();
+                    let sock = bind_socket(&addrs)?;
+                    local_address = sock.local_address();
+
+                    session = match self.info.network_conditions {
+                        Some(conditions) => start_network_session(
+                            sess_build,
+                            session_type,
+                            host_addr,
+                            GGRSSessionSocket::new(
+                                GGRSSimulatedSocket::new(sock, conditions),
+                                checksums,
+                            ),
+                        )?,
+                        None => start_network_session(
+                            sess_build,
+                            session_type,
+                            host_addr,
+                            GGRSSessionSocket::new(sock, checksums),
+                        )?,
+                    };
+                }
+                GGRSSessionType::Synctest => {
+                    session =
+                        GGRSSessionKind::Synctest(match sess_build.start_synctest_session() {
+                            Ok(it) => it,
+                            Err(err) => return Err(Error::invalid_configuration(err)),
+                        });
+                }
             }
-        }
+            // return the created session
+            let mut session = GGRSSession::new(session, self.info.clone());
+            session.desync = desync;
+            session.peers = peers;
+            session.local_address = local_address;
+            Ok(Box::new(session))
+        })
     }
+}
 
-    session = Box::into_raw(sess);
+fn start_network_session<S: GGRSTransport>(
+    sess_build: SessionBuilder<GGRSConfig>,
+    session_type: GGRSSessionType,
+    host_addr: String,
+    sock: GGRSSessionSocket<S>,
+) -> Result<GGRSSessionKind, Error> {
+    if session_type == GGRSSessionType::Spectator {
+        return Ok(GGRSSessionKind::Spectator(Box::new(
+            sess_build.start_spectator_session(host_addr, sock),
+        )));
+    }
+    match sess_build.start_p2p_session(sock) {
+        Ok(it) => Ok(GGRSSessionKind::Peer2Peer(it)),
+        Err(err) => Err(Error::invalid_configuration(err)),
+    }
+}
 
-    if has_failed {
-        return Err(Error {
-            msg: "Error player is not a local player".to_string(),
-        });
+// without a bind address an ipv6 socket is only bound when a peer needs one, an ipv4 socket works everywhere
+fn bind_udp_socket(
+    bind_address: Option<IpAddr>,
+    local_port: u16,
+    addrs: &[String],
+) -> Result<GGRSUdpSocket, Error> {
+    let ip = bind_address.unwrap_or_else(|| {
+        GGRSUdpSocket::any_address(
+            addrs
+                .iter()
+                .any(|addr| addr.parse::<SocketAddr>().is_ok_and(|addr| addr.is_ipv6())),
+        )
+    });
+    let addr = SocketAddr::new(ip, local_port);
+    match GGRSUdpSocket::bind(addr) {
+        Ok(it) => Ok(it),
+        Err(err) => Err(Error {
+            code: GGRSErrorCode::BindFailed,
+            msg: format!("Error Couldnt bind to {}: {}", addr, err),
+        }),
     }
-    Ok(true)
 }
 
-#[allow(unused_assignments)]
-fn get_current_state(mut session: *mut GGRSSession) -> GGRSSessionState {
-    let mut sess = unsafe { Box::from_raw(session) };
-    let mut state = SessionState::Synchronizing;
+fn bind_loopback_socket(local_name: &str) -> Result<GGRSLoopbackSocket, Error> {
+    match GGRSLoopbackSocket::bind(local_name) {
+        Some(it) => Ok(it),
+        None => Err(Error {
+            code: GGRSErrorCode::BindFailed,
+            msg: format!("Error loopback name is empty or in use: {}", local_name),
+        }),
+    }
+}
+
+fn custom_socket(socket: UniquePtr<GGRSNonBlockingSocket>) -> Result<GGRSCustomSocket, Error> {
+    match GGRSCustomSocket::new(socket) {
+        Some(it) => Ok(it),
+        None => Err(Error {
+            code: GGRSErrorCode::InvalidSocket,
+            msg: "Error socket is null".to_string(),
+        }),
+    }
+}
 
-    match sess.as_mut() {
-        GGRSSession::NotSet => (),
-        GGRSSession::Peer2Peer(sess) => {
-            state = sess.current_state();
+fn try_session(
+    session: Result<Box<GGRSSession>, Error>,
+    error: &mut GGRSErrorInfo,
+) -> Box<GGRSSession> {
+    match session {
+        Ok(session) => {
+            *error = GGRSErrorInfo::ok();
+            session
         }
-        GGRSSession::Spectator(sess) => {
-            state = sess.current_state();
+        Err(err) => {
+            *error = GGRSErrorInfo::from(err);
+            Box::default()
         }
-        GGRSSession::Synctest(_) => (),
     }
+}
 
-    session = Box::into_raw(sess);
-    let ggrs_state: GGRSSessionState;
+// session methods exposed to c++. every call borrows the session, so a failing call never frees it
+impl GGRSSession {
+    pub fn poll_remote_clients(&mut self) -> Result<bool, Error> {
+        self.guard(|sess| {
+            sess.poll()?;
+            if sess.event_handler.is_some() {
+                let events = sess.take_events()?;
+                sess.dispatch_events(events)?;
+            }
+            Ok(true)
+        })
+    }
 
-    match state {
-        SessionState::Synchronizing => ggrs_state = GGRSSessionState::Synchronizing,
-        SessionState::Running => ggrs_state = GGRSSessionState::Running,
+    pub fn add_local_input(&mut self, player_handle: u32, input: &[u8]) -> Result<bool, Error> {
+        self.guard(|sess| {
+            sess.check_input_size(player_handle, input)?;
+            sess.add_input(player_handle, input)?;
+            Ok(true)
+        })
     }
 
-    return ggrs_state;
-}
+    // every local player gets exactly one input, nothing is added unless all of them are fine
+    pub fn add_local_inputs(&mut self, local_inputs: &[GGRSLocalInput]) -> Result<bool, Error> {
+        self.guard(|sess| {
+            sess.check_open()?;
+            let mut given: Vec<u32> = Vec::new();
+            for local_input in local_inputs {
+                let handle = local_input.player_handle;
+                let player = sess.info.players.iter().find(|p| p.player_handle == handle);
+                let problem = match player {
+                    None => Some("is not a registered player"),
+                    Some(p) if p.player_type != GGRSPlayerType::Local => {
+                        Some("is not a local player")
+                    }
+                    Some(_) if given.contains(&handle) => Some("got more than one input"),
+                    Some(_) => None,
+                };
+                if let Some(problem) = problem {
+                    return Err(Error {
+                        code: GGRSErrorCode::InvalidRequest,
+                        msg: format!("Error player {} {}", handle, problem),
+                    });
+                }
+                sess.check_input_size(handle, &local_input.input)?;
+                given.push(handle);
+            }
+            for player in &sess.info.players {
+                if player.player_type == GGRSPlayerType::Local
+                    && !given.contains(&player.player_handle)
+                {
+                    return Err(Error {
+                        code: GGRSErrorCode::InvalidRequest,
+                        msg: format!("Error no input for local player {}", player.player_handle),
+                    });
+                }
+            }
+            for local_input in local_inputs {
+                sess.add_input(local_input.player_handle, &local_input.input)?;
+            }
+            Ok(true)
+        })
+    }
 
-#[allow(unused_assignments)]
-fn get_events(mut session: *mut GGRSSession) -> Vec<wrapper::GGRSEvent> {
-    let mut sess = unsafe { Box::from_raw(session) };
-    let mut ggrs_events = Vec::new();
-    match sess.as_mut() {
-        GGRSSession::NotSet => (),
-        GGRSSession::Peer2Peer(sess) => {
-            for ev in sess.events() {
-                ggrs_events.push(ev);
+    pub fn get_current_state(&mut self) -> Result<GGRSSessionState, Error> {
+        self.guard(|sess| {
+            let state = match &sess.kind {
+                GGRSSessionKind::Closed => return Err(Error::session_closed()),
+                GGRSSessionKind::Peer2Peer(sess) => sess.current_state(),
+                GGRSSessionKind::Spectator(sess) => sess.current_state(),
+                // nobody to synchronize with, they run from the first frame
+                GGRSSessionKind::Synctest(_) | GGRSSessionKind::Replay(_) => SessionState::Running,
+            };
+
+            let ggrs_state = match state {
+                SessionState::Synchronizing => GGRSSessionState::Synchronizing,
+                SessionState::Running => GGRSSessionState::Running,
+            };
+            Ok(ggrs_state)
+        })
+    }
+
+    pub fn get_events(&mut self) -> Result<Vec<wrapper::GGRSEvent>, Error> {
+        self.guard(|sess| {
+            // the handler gets everything on the next poll, nothing is taken away from it here
+            if sess.event_handler.is_some() {
+                sess.check_open()?;
+                return Ok(Vec::new());
             }
-        }
-        GGRSSession::Spectator(sess) => {
-            for ev in sess.events() {
-                ggrs_events.push(ev);
+            let mut result = Vec::new();
+            for event in sess.take_events()? {
+                let mut ev = wrapper::GGRSEvent::default();
+                let event = match event {
+                    GGRSSessionEvent::Ggrs(event) => event,
+                    GGRSSessionEvent::Desync(desync) => {
+                        let mut ev = wrapper::GGRSEvent::desync_detected(desync);
+                        sess.fill_event_players(&mut ev);
+                        result.push(ev);
+                        continue;
+                    }
+                };
+                match event {
+                    GGRSEvent::Synchronizing { addr, total, count } => {
+                        ev.event_type = GGRSEventType::Synchronizing;
+                        ev.event_info.addr = addr;
+                        ev.event_info.total = total;
+                        ev.event_info.count = count;
+                    }
+                    GGRSEvent::Synchronized { addr } => {
+                        ev.event_type = GGRSEventType::Synchronized;
+                        ev.event_info.addr = addr;
+                    }
+                    GGRSEvent::Disconnected { addr } => {
+                        ev.event_type = GGRSEventType::Disconnected;
+                        ev.event_info.addr = addr;
+                    }
+                    GGRSEvent::NetworkInterrupted {
+                        addr,
+                        disconnect_timeout,
+                    } => {
+                        ev.event_type = GGRSEventType::NetworkInterrupted;
+                        ev.event_info.addr = addr;
+                        ev.event_info.disconnect_timeout =
+                            GGRSDuration::from_millis(disconnect_timeout);
+                    }
+                    GGRSEvent::NetworkResumed { addr } => {
+                        ev.event_type = GGRSEventType::NetworkResumed;
+                        ev.event_info.addr = addr;
+                    }
+                    GGRSEvent::WaitRecommendation { skip_frames } => {
+                        ev.event_type = GGRSEventType::WaitRecommendation;
+                        ev.event_info.skip_frames = skip_frames;
+                    }
+                }
+                sess.fill_event_players(&mut ev);
+                result.push(ev);
             }
-        }
-        GGRSSession::Synctest(_) => (),
+            Ok(result)
+        })
     }
-    session = Box::into_raw(sess);
-    let mut result = Vec::new();
-    for event in ggrs_events {
-        let mut ev = wrapper::GGRSEvent::default();
-        match event {
-            GGRSEvent::Synchronizing { addr, total, count } => {
-                ev.event_type = GGRSEventType::Synchronizing;
-                ev.event_info.addr = addr.to_string();
-                ev.event_info.total = total;
-                ev.event_info.count = count;
+
+    pub fn advance_frame(&mut self) -> Result<GGRSFrameResult, Error> {
+        self.guard(|sess| {
+            if let Some(frame) = sess.mismatch_frame {
+                return Err(Error::mismatched_checksum(frame));
             }
-            GGRSEvent::Synchronized { addr } => {
-                ev.event_type = GGRSEventType::Synchronized;
-                ev.event_info.addr = addr.to_string();
+            let mut result = GGRSFrameResult::default();
+            // saves from the previous frame are either fulfilled by now or never will be
+            let mismatches = sess.compare_checksums();
+            sess.pending_saves.clear();
+            let pending_saves = &mut sess.pending_saves;
+            let mismatch_frame = &mut sess.mismatch_frame;
+            let reporter = &mut sess.reporter;
+            let info = &sess.info;
+            let input_size = sess.info.input_size_bytes as usize;
+            let frame = &mut sess.current_frame;
+            // spectators and synctests only ever advance with confirmed inputs
+            let mut confirmed_frame = Frame::MAX;
+            match &mut sess.kind {
+                GGRSSessionKind::Closed => return Err(Error::session_closed()),
+                GGRSSessionKind::Peer2Peer(sess) => {
+                    let advanced = sess.advance_frame();
+                    confirmed_frame = sess.confirmed_frame();
+                    match advanced {
+                        Ok(reqs) => {
+                            handle_requests(reqs, &mut result, pending_saves, input_size, frame);
+                        }
+                        Err(ggrs::GGRSError::PredictionThreshold) => result.skip_frame = true,
+                        Err(err) => return Err(Error::from(err)),
+                    }
+                }
+                GGRSSessionKind::Spectator(sess) => match sess.advance_frame() {
+                    Ok(reqs) => {
+                        handle_requests(reqs, &mut result, pending_saves, input_size, frame);
+                    }
+                    Err(ggrs::GGRSError::PredictionThreshold) => result.skip_frame = true,
+                    Err(err) => return Err(Error::from(err)),
+                },
+                GGRSSessionKind::Synctest(sess) => match sess.advance_frame() {
+                    Ok(reqs) => {
+                        handle_requests(reqs, &mut result, pending_saves, input_size, frame);
+                    }
+                    Err(ggrs::GGRSError::PredictionThreshold) => result.skip_frame = true,
+                    Err(ggrs::GGRSError::MismatchedChecksum { frame }) => {
+                        *mismatch_frame = Some(frame);
+                        // the saves of the last frame hold both checksums of the frame ggrs names. without
+                        // them there is nothing to report but the frame
+                        if !mismatches.iter().any(|m| m.frame == frame) {
+                            if let Some(reporter) = reporter {
+                                reporter.write_synctest_frame(info, frame);
+                            }
+                            return Err(Error::mismatched_checksum(frame));
+                        }
+                        result.checksum_mismatches = mismatches;
+                    }
+                    Err(err) => return Err(Error::from(err)),
+                },
+                GGRSSessionKind::Replay(replay) => match replay.next_inputs() {
+                    Some((replay_frame, inputs)) => {
+                        result.actions.push(GGRSFrameAction {
+                            action_type: GGRSFrameActionType::AdvanceFrame,
+                            action_info: GGRSFrameActionInfo {
+                                frame: replay_frame,
+                                inputs,
+                                state: Vec::new(),
+                            },
+                        });
+                        *frame = replay_frame + 1;
+                    }
+                    None => {
+                        return Err(Error {
+                            code: GGRSErrorCode::ReplayFinished,
+                            msg: "Error the replay has no frames left".to_string(),
+                        })
+                    }
+                },
             }
-            GGRSEvent::Disconnected { addr } => {
-                ev.event_type = GGRSEventType::Disconnected;
-                ev.event_info.addr = addr.to_string();
+            if let Some(recorder) = &mut sess.recorder {
+                for act in &result.actions {
+                    if act.action_type == GGRSFrameActionType::AdvanceFrame {
+                        recorder.record(act.action_info.frame, &act.action_info.inputs);
+                    }
+                }
+                recorder.confirm(confirmed_frame.min(sess.current_frame - 1));
             }
-            GGRSEvent::NetworkInterrupted {
-                addr,
-                disconnect_timeout,
-            } => {
-                ev.event_type = GGRSEventType::NetworkInterrupted;
-                ev.event_info.addr = addr.to_string();
-                ev.event_info.disconnect_timeout = disconnect_timeout as u64;
+            if let Some(reporter) = &mut sess.reporter {
+                for act in &result.actions {
+                    if act.action_type == GGRSFrameActionType::AdvanceFrame {
+                        reporter.record_inputs(act.action_info.frame, &act.action_info.inputs);
+                    }
+                }
+                if !result.checksum_mismatches.is_empty() {
+                    reporter.write_synctest(&sess.info, &result.checksum_mismatches);
+                }
             }
-            GGRSEvent::NetworkResumed { addr } => {
-                ev.event_type = GGRSEventType::NetworkResumed;
-                ev.event_info.addr = addr.to_string();
+            if let Some(desync) = &mut sess.desync {
+                let found = desync.update(confirmed_frame);
+                if let (Some(reporter), false) = (&mut sess.reporter, found.is_empty()) {
+                    reporter.write_p2p(&sess.info, &found);
+                }
+                sess.desync_events.extend(found);
             }
-            GGRSEvent::WaitRecommendation { skip_frames } => {
-                ev.event_type = GGRSEventType::WaitRecommendation;
-                ev.event_info.skip_frames = skip_frames;
+            Ok(result)
+        })
+    }
+
+    pub fn frame_pacing(&mut self) -> Result<GGRSFramePacing, Error> {
+        self.guard(|sess| {
+            let frames_ahead = match &sess.kind {
+                GGRSSessionKind::Closed => return Err(Error::session_closed()),
+                GGRSSessionKind::Peer2Peer(sess) => sess.frames_ahead(),
+                GGRSSessionKind::Spectator(_)
+                | GGRSSessionKind::Synctest(_)
+                | GGRSSessionKind::Replay(_) => 0,
+            };
+            Ok(sess.pacer.pace(frames_ahead, Instant::now()))
+        })
+    }
+
+    pub fn tick(&mut self, local_inputs: &[GGRSLocalInput]) -> Result<GGRSTickResult, Error> {
+        let mut result = GGRSTickResult::default();
+        // events are left for the end of the tick, an event handler included
+        self.guard(|sess| sess.poll())?;
+        result.state = self.get_current_state()?;
+        if result.state == GGRSSessionState::Running {
+            // a replay plays back the recorded inputs, the local ones are not wanted
+            if !matches!(self.kind, GGRSSessionKind::Replay(_)) {
+                self.add_local_inputs(local_inputs)?;
+            }
+            let frame = self.advance_frame()?;
+            result.skip_frame = frame.skip_frame;
+            result.actions = frame.actions;
+            result.checksum_mismatches = frame.checksum_mismatches;
+        }
+        // taken last, so the tick also reports what advancing the frame brought up. an event handler gets
+        // them instead. either way the wait recommendations reach the frame pacer
+        if self.event_handler.is_some() {
+            self.guard(|sess| {
+                let events = sess.take_events()?;
+                sess.dispatch_events(events)
+            })?;
+            return Ok(result);
+        }
+        result.events = self.get_events()?;
+        Ok(result)
+    }
+
+    pub fn save_game_state(&mut self, frame: i32, state: &[u8]) -> Result<bool, Error> {
+        self.guard(|sess| {
+            sess.check_open()?;
+            match sess.pending_saves.get(&frame) {
+                Some(pending) => {
+                    pending
+                        .cell
+                        .save(frame, Some(state.to_vec()), pending.checksum);
+                    if let Some(reporter) = &mut sess.reporter {
+                        reporter.record_state(frame, state);
+                    }
+                    Ok(true)
+                }
+                None => Err(Error::no_pending_save(frame)),
+            }
+        })
+    }
+
+    pub fn submit_checksum(
+        &mut self,
+        frame: i32,
+        checksum_hi: u64,
+        checksum_lo: u64,
+    ) -> Result<bool, Error> {
+        self.guard(|sess| {
+            sess.check_open()?;
+            match sess.pending_saves.get_mut(&frame) {
+                Some(pending) => {
+                    let checksum = join_checksum(checksum_hi, checksum_lo);
+                    if let Some(recorder) = &mut sess.recorder {
+                        recorder.record_checksum(frame, checksum);
+                    }
+                    if let Some(desync) = &mut sess.desync {
+                        desync.submit(frame, checksum);
+                    }
+                    pending.checksum = Some(checksum);
+                    pending
+                        .cell
+                        .save(frame, pending.cell.load(), pending.checksum);
+                    Ok(true)
+                }
+                None => Err(Error::no_pending_save(frame)),
             }
+        })
+    }
+
+    pub fn get_frames_ahead(&mut self) -> Result<i32, Error> {
+        self.guard(|sess| match &sess.kind {
+            GGRSSessionKind::Closed => Err(Error::session_closed()),
+            GGRSSessionKind::Peer2Peer(sess) => Ok(sess.frames_ahead()),
+            GGRSSessionKind::Spectator(_)
+            | GGRSSessionKind::Synctest(_)
+            | GGRSSessionKind::Replay(_) => Ok(0),
+        })
+    }
+
+    pub fn clean_session(&mut self) -> bool {
+        // drops the ggrs session and its socket right away. the box itself is freed when c++ drops it.
+        // a poisoned session can still be cleaned up
+        let cleaned = catch_panic(|| {
+            self.kind = GGRSSessionKind::Closed;
+            self.pending_saves.clear();
+            self.checksum_history.clear();
+            // a recording that was not stopped is thrown away
+            self.recorder = None;
+            self.desync = None;
+            self.desync_events.clear();
+            self.event_handler = None;
+            self.reporter = None;
+            Ok(())
+        });
+        if cleaned.is_err() {
+            self.poisoned = true;
+            return false;
         }
-        result.push(ev);
+        true
+    }
+
+    pub fn network_stats(&mut self, player_handle: u32) -> Result<GGRSNetworkStats, Error> {
+        self.guard(|sess| {
+            let net_stats: NetworkStats;
+            match &sess.kind {
+                GGRSSessionKind::Closed => return Err(Error::session_closed()),
+                GGRSSessionKind::Peer2Peer(sess_ref) => {
+                    let stats = sess_ref.network_stats(player_handle as usize);
+                    match stats {
+                        Ok(stats) => net_stats = stats,
+                        Err(err) => {
+                            return Err(Error::from(err));
+                        }
+                    }
+                }
+                GGRSSessionKind::Spectator(sess_ref) => {
+                    let stats = sess_ref.network_stats();
+                    match stats {
+                        Ok(stats) => net_stats = stats,
+                        Err(err) => {
+                            return Err(Error::from(err));
+                        }
+                    }
+                }
+                GGRSSessionKind::Synctest(_) | GGRSSessionKind::Replay(_) => {
+                    return Err(Error {
+                        code: GGRSErrorCode::UnsupportedOperation,
+                        msg: "Unsupported Operation For This Sessoin Type".to_string(),
+                    });
+                }
+            }
+            Ok(GGRSNetworkStats::new(
+                net_stats.send_queue_len as u32,
+                GGRSDuration::from_millis(net_stats.ping),
+                net_stats.kbps_sent as u64,
+                net_stats.local_frames_behind,
+                net_stats.remote_frames_behind,
+            ))
+        })
+    }
+
+    pub fn disconnect_player(&mut self, player_handle: u32) -> Result<bool, Error> {
+        self.guard(|sess| match &mut sess.kind {
+            GGRSSessionKind::Closed => Err(Error::session_closed()),
+            GGRSSessionKind::Spectator(_)
+            | GGRSSessionKind::Synctest(_)
+            | GGRSSessionKind::Replay(_) => Err(Error {
+                code: GGRSErrorCode::UnsupportedOperation,
+                msg: "Unsupported Operation For This Sessoin Type".to_string(),
+            }),
+            GGRSSessionKind::Peer2Peer(sess_mut) => {
+                let dc = sess_mut.disconnect_player(player_handle as usize);
+                match dc {
+                    Ok(_) => Ok(true),
+                    Err(err) => Err(Error::from(err)),
+                }
+            }
+        })
     }
-    return result;
 }
 
-#[allow(unused_assignments)]
-fn advance_frame(mut session: *mut GGRSSession) -> Result<GGRSFrameResult, Error> {
-    let mut sess = unsafe { Box::from_raw(session) };
-    let mut result = GGRSFrameResult::default();
-    match sess.as_mut() {
-        GGRSSession::NotSet => (),
-        GGRSSession::Peer2Peer(sess) => match sess.advance_frame() {
-            Ok(reqs) => {
-                handle_requests(reqs, &mut result);
+impl GGRSSession {
+    pub fn start_recording(&mut self, path: &str) -> Result<bool, Error> {
+        self.guard(|sess| {
+            sess.check_open()?;
+            if let GGRSSessionKind::Replay(_) = sess.kind {
+                return Err(Error {
+                    code: GGRSErrorCode::UnsupportedOperation,
+                    msg: "Error a replay session can not be recorded".to_string(),
+                });
             }
-            Err(ggrs::GGRSError::PredictionThreshold) => result.skip_frame = true,
-            Err(err) => {
+            if sess.recorder.is_some() || sess.current_frame != 0 {
                 return Err(Error {
-                    msg: err.to_string(),
-                })
+                    code: GGRSErrorCode::UnsupportedOperation,
+                    msg: "Error recording has to start once, before the first frame is advanced"
+                        .to_string(),
+                });
             }
-        },
-        GGRSSession::Spectator(sess) => match sess.advance_frame() {
-            Ok(reqs) => {
-                handle_requests(reqs, &mut result);
+            // create the file right away so a bad path is reported here and not at the end of the match
+            if let Err(err) = fs::File::create(path) {
+                return Err(Error::replay_io(path, err));
             }
-            Err(ggrs::GGRSError::PredictionThreshold) => result.skip_frame = true,
-            Err(err) => {
-                return Err(Error {
-                    msg: err.to_string(),
-                })
+            sess.recorder = Some(GGRSReplayRecorder::new(path, sess.info.clone()));
+            Ok(true)
+        })
+    }
+
+    pub fn stop_recording(&mut self) -> Result<bool, Error> {
+        self.guard(|sess| {
+            sess.check_open()?;
+            let recorder = match sess.recorder.take() {
+                Some(it) => it,
+                None => {
+                    return Err(Error {
+                        code: GGRSErrorCode::UnsupportedOperation,
+                        msg: "Error the session is not recording".to_string(),
+                    })
+                }
+            };
+            let path = recorder.path().to_string();
+            match recorder.finish() {
+                Ok(_) => Ok(true),
+                Err(err) => Err(Error::replay_io(&path, err)),
             }
-        },
-        GGRSSession::Synctest(sess) => match sess.advance_frame() {
-            Ok(reqs) => {
-                handle_requests(reqs, &mut result);
+        })
+    }
+
+    pub fn set_event_handler(
+        &mut self,
+        handler: UniquePtr<GGRSEventHandler>,
+    ) -> Result<bool, Error> {
+        self.guard(|sess| {
+            sess.check_open()?;
+            sess.event_handler = if handler.is_null() {
+                None
+            } else {
+                Some(handler)
+            };
+            Ok(true)
+        })
+    }
+
+    pub fn desync_report_path(&mut self) -> Result<String, Error> {
+        self.guard(|sess| {
+            sess.check_open()?;
+            match sess.reporter.as_ref().and_then(|it| it.written()) {
+                None => Ok(String::new()),
+                Some(Ok(dir)) => Ok(dir.clone()),
+                Some(Err(msg)) => Err(Error {
+                    code: GGRSErrorCode::DesyncReportFailed,
+                    msg: msg.clone(),
+                }),
             }
-            Err(ggrs::GGRSError::PredictionThreshold) => result.skip_frame = true,
-            Err(err) => {
-                return Err(Error {
-                    msg: err.to_string(),
-                })
+        })
+    }
+
+    pub fn local_address(&mut self) -> Result<String, Error> {
+        self.guard(|sess| {
+            sess.check_open()?;
+            match &sess.local_address {
+                Some(addr) => Ok(addr.clone()),
+                None => Err(Error {
+                    code: GGRSErrorCode::UnsupportedOperation,
+                    msg: "Error this session has no known local address".to_string(),
+                }),
             }
-        },
+        })
+    }
+}
+
+pub fn start_replay_session(path: &str) -> Result<Box<GGRSSession>, Error> {
+    catch_panic(|| {
+        let replay = match GGRSReplay::read_from(path) {
+            Ok(it) => it,
+            Err(err) => return Err(Error::replay_io(path, err)),
+        };
+        let playback = GGRSReplayPlayback::new(replay);
+        let info = playback.info().clone();
+        Ok(Box::new(GGRSSession::new(
+            GGRSSessionKind::Replay(playback),
+            info,
+        )))
+    })
+}
+
+pub fn try_start_replay_session(path: &str, error: &mut GGRSErrorInfo) -> Box<GGRSSession> {
+    try_session(start_replay_session(path), error)
+}
+
+// non throwing variants, they only translate the result of the calls above
+impl GGRSSessionBuilder {
+    pub fn try_with_num_players(&mut self, num_players: u32) -> GGRSErrorInfo {
+        GGRSErrorInfo::from_result(self.with_num_players(num_players).map(|_| ()), &mut ())
+    }
+
+    pub fn try_with_input_size(&mut self, bytes: u32) -> GGRSErrorInfo {
+        GGRSErrorInfo::from_result(self.with_input_size(bytes).map(|_| ()), &mut ())
+    }
+
+    pub fn try_with_fps(&mut self, fps: u32) -> GGRSErrorInfo {
+        GGRSErrorInfo::from_result(self.with_fps(fps).map(|_| ()), &mut ())
+    }
+
+    pub fn try_with_input_delay(&mut self, delay: u32) -> GGRSErrorInfo {
+        GGRSErrorInfo::from_result(self.with_input_delay(delay).map(|_| ()), &mut ())
+    }
+
+    pub fn try_with_max_prediction_window(&mut self, window: u32) -> GGRSErrorInfo {
+        GGRSErrorInfo::from_result(self.with_max_prediction_window(window).map(|_| ()), &mut ())
+    }
+
+    pub fn try_with_max_frames_behind(&mut self, max_frames_behind: u32) -> GGRSErrorInfo {
+        GGRSErrorInfo::from_result(
+            self.with_max_frames_behind(max_frames_behind).map(|_| ()),
+            &mut (),
+        )
+    }
+
+    pub fn try_with_catchup_speed(&mut self, catchup_speed: u32) -> GGRSErrorInfo {
+        GGRSErrorInfo::from_result(self.with_catchup_speed(catchup_speed).map(|_| ()), &mut ())
+    }
+
+    pub fn try_with_check_distance(&mut self, check_distance: u32) -> GGRSErrorInfo {
+        GGRSErrorInfo::from_result(
+            self.with_check_distance(check_distance).map(|_| ()),
+            &mut (),
+        )
+    }
+
+    pub fn try_with_disconnect_timeout(&mut self, timeout_ms: u64) -> GGRSErrorInfo {
+        GGRSErrorInfo::from_result(
+            self.with_disconnect_timeout(timeout_ms).map(|_| ()),
+            &mut (),
+        )
+    }
+
+    pub fn try_with_disconnect_notify_delay(&mut self, notify_delay_ms: u64) -> GGRSErrorInfo {
+        GGRSErrorInfo::from_result(
+            self.with_disconnect_notify_delay(notify_delay_ms)
+                .map(|_| ()),
+            &mut (),
+        )
+    }
+
+    pub fn try_with_network_conditions(
+        &mut self,
+        conditions: GGRSNetworkConditions,
+    ) -> GGRSErrorInfo {
+        GGRSErrorInfo::from_result(
+            self.with_network_conditions(conditions).map(|_| ()),
+            &mut (),
+        )
+    }
+
+    pub fn try_with_sparse_saving_mode(&mut self, enable: bool) -> GGRSErrorInfo {
+        GGRSErrorInfo::from_result(self.with_sparse_saving_mode(enable).map(|_| ()), &mut ())
+    }
+
+    pub fn try_with_desync_detection_interval(&mut self, interval: u32) -> GGRSErrorInfo {
+        GGRSErrorInfo::from_result(
+            self.with_desync_detection_interval(interval).map(|_| ()),
+            &mut (),
+        )
+    }
+
+    pub fn try_with_desync_report_dir(&mut self, dir: &str) -> GGRSErrorInfo {
+        GGRSErrorInfo::from_result(self.with_desync_report_dir(dir).map(|_| ()), &mut ())
+    }
+
+    pub fn try_with_desync_report_frames(&mut self, frames: u32) -> GGRSErrorInfo {
+        GGRSErrorInfo::from_result(self.with_desync_report_frames(frames).map(|_| ()), &mut ())
+    }
+
+    pub fn try_with_pacing_mode(&mut self, mode: GGRSPacingMode) -> GGRSErrorInfo {
+        GGRSErrorInfo::from_result(self.with_pacing_mode(mode).map(|_| ()), &mut ())
+    }
+
+    pub fn try_with_bind_address(&mut self, ip: &str) -> GGRSErrorInfo {
+        GGRSErrorInfo::from_result(self.with_bind_address(ip).map(|_| ()), &mut ())
+    }
+
+    pub fn try_with_address_preference(
+        &mut self,
+        preference: GGRSAddressPreference,
+    ) -> GGRSErrorInfo {
+        GGRSErrorInfo::from_result(
+            self.with_address_preference(preference).map(|_| ()),
+            &mut (),
+        )
+    }
+
+    pub fn try_add_player(&mut self, player: GGRSPlayer) -> GGRSErrorInfo {
+        GGRSErrorInfo::from_result(self.add_player(player).map(|_| ()), &mut ())
+    }
+
+    pub fn try_start_p2p_session(
+        &mut self,
+        local_port: u16,
+        error: &mut GGRSErrorInfo,
+    ) -> Box<GGRSSession> {
+        try_session(self.start_p2p_session(local_port), error)
+    }
+
+    pub fn try_start_spectator_session(
+        &mut self,
+        local_port: u16,
+        host: &str,
+        error: &mut GGRSErrorInfo,
+    ) -> Box<GGRSSession> {
+        try_session(self.start_spectator_session(local_port, host), error)
+    }
+
+    pub fn try_start_synctest_session(&mut self, error: &mut GGRSErrorInfo) -> Box<GGRSSession> {
+        try_session(self.start_synctest_session(), error)
+    }
+
+    pub fn try_start_p2p_session_with_socket(
+        &mut self,
+        socket: UniquePtr<GGRSNonBlockingSocket>,
+        error: &mut GGRSErrorInfo,
+    ) -> Box<GGRSSession> {
+        try_session(self.start_p2p_session_with_socket(socket), error)
+    }
+
+    pub fn try_start_spectator_session_with_socket(
+        &mut self,
+        host: &str,
+        socket: UniquePtr<GGRSNonBlockingSocket>,
+        error: &mut GGRSErrorInfo,
+    ) -> Box<GGRSSession> {
+        try_session(
+            self.start_spectator_session_with_socket(host, socket),
+            error,
+        )
+    }
+
+    pub fn try_start_p2p_session_on_loopback(
+        &mut self,
+        local_name: &str,
+        error: &mut GGRSErrorInfo,
+    ) -> Box<GGRSSession> {
+        try_session(self.start_p2p_session_on_loopback(local_name), error)
+    }
+
+    pub fn try_start_spectator_session_on_loopback(
+        &mut self,
+        local_name: &str,
+        host: &str,
+        error: &mut GGRSErrorInfo,
+    ) -> Box<GGRSSession> {
+        try_session(
+            self.start_spectator_session_on_loopback(local_name, host),
+            error,
+        )
     }
-    session = Box::into_raw(sess);
-    Ok(result)
 }
 
-fn handle_requests(reqs: Vec<GGRSRequest<GGRSConfig>>, result: &mut GGRSFrameResult) {
+impl GGRSSession {
+    pub fn try_poll_remote_clients(&mut self) -> GGRSErrorInfo {
+        GGRSErrorInfo::from_result(self.poll_remote_clients(), &mut true)
+    }
+
+    pub fn try_add_local_input(&mut self, player_handle: u32, input: &[u8]) -> GGRSErrorInfo {
+        GGRSErrorInfo::from_result(self.add_local_input(player_handle, input), &mut true)
+    }
+
+    pub fn try_add_local_inputs(&mut self, local_inputs: &[GGRSLocalInput]) -> GGRSErrorInfo {
+        GGRSErrorInfo::from_result(self.add_local_inputs(local_inputs), &mut true)
+    }
+
+    pub fn try_get_current_state(&mut self, state: &mut GGRSSessionState) -> GGRSErrorInfo {
+        GGRSErrorInfo::from_result(self.get_current_state(), state)
+    }
+
+    pub fn try_get_events(&mut self, events: &mut Vec<wrapper::GGRSEvent>) -> GGRSErrorInfo {
+        GGRSErrorInfo::from_result(self.get_events(), events)
+    }
+
+    pub fn try_advance_frame(&mut self, result: &mut GGRSFrameResult) -> GGRSErrorInfo {
+        GGRSErrorInfo::from_result(self.advance_frame(), result)
+    }
+
+    pub fn try_frame_pacing(&mut self, pacing: &mut GGRSFramePacing) -> GGRSErrorInfo {
+        GGRSErrorInfo::from_result(self.frame_pacing(), pacing)
+    }
+
+    pub fn try_tick(
+        &mut self,
+        local_inputs: &[GGRSLocalInput],
+        result: &mut GGRSTickResult,
+    ) -> GGRSErrorInfo {
+        GGRSErrorInfo::from_result(self.tick(local_inputs), result)
+    }
+
+    pub fn try_save_game_state(&mut self, frame: i32, state: &[u8]) -> GGRSErrorInfo {
+        GGRSErrorInfo::from_result(self.save_game_state(frame, state), &mut true)
+    }
+
+    pub fn try_submit_checksum(
+        &mut self,
+        frame: i32,
+        checksum_hi: u64,
+        checksum_lo: u64,
+    ) -> GGRSErrorInfo {
+        GGRSErrorInfo::from_result(
+            self.submit_checksum(frame, checksum_hi, checksum_lo),
+            &mut true,
+        )
+    }
+
+    pub fn try_get_frames_ahead(&mut self, frames_ahead: &mut i32) -> GGRSErrorInfo {
+        GGRSErrorInfo::from_result(self.get_frames_ahead(), frames_ahead)
+    }
+
+    pub fn try_network_stats(
+        &mut self,
+        player_handle: u32,
+        stats: &mut GGRSNetworkStats,
+    ) -> GGRSErrorInfo {
+        GGRSErrorInfo::from_result(self.network_stats(player_handle), stats)
+    }
+
+    pub fn try_disconnect_player(&mut self, player_handle: u32) -> GGRSErrorInfo {
+        GGRSErrorInfo::from_result(self.disconnect_player(player_handle), &mut true)
+    }
+
+    pub fn try_start_recording(&mut self, path: &str) -> GGRSErrorInfo {
+        GGRSErrorInfo::from_result(self.start_recording(path), &mut true)
+    }
+
+    pub fn try_stop_recording(&mut self) -> GGRSErrorInfo {
+        GGRSErrorInfo::from_result(self.stop_recording(), &mut true)
+    }
+
+    pub fn try_desync_report_path(&mut self, path: &mut String) -> GGRSErrorInfo {
+        GGRSErrorInfo::from_result(self.desync_report_path(), path)
+    }
+
+    pub fn try_local_address(&mut self, addr: &mut String) -> GGRSErrorInfo {
+        GGRSErrorInfo::from_result(self.local_address(), addr)
+    }
+
+    pub fn try_set_event_handler(&mut self, handler: UniquePtr<GGRSEventHandler>) -> GGRSErrorInfo {
+        GGRSErrorInfo::from_result(self.set_event_handler(handler), &mut true)
+    }
+}
+
+fn handle_requests(
+    reqs: Vec<GGRSRequest<GGRSConfig>>,
+    result: &mut GGRSFrameResult,
+    pending_saves: &mut HashMap<Frame, GGRSPendingSave>,
+    input_size: usize,
+    current_frame: &mut Frame,
+) {
     for req in reqs {
         match req {
             ggrs::GGRSRequest::SaveGameState { cell, frame } => {
-                cell.save(frame, Some(0), Some(0));
+                // mark the cell with its frame right away, the data follows through save_game_state
+                cell.save(frame, None, None);
+                pending_saves.insert(
+                    frame,
+                    GGRSPendingSave {
+                        cell,
+                        checksum: None,
+                    },
+                );
                 let mut act = GGRSFrameAction::default();
                 act.action_info.frame = frame;
                 act.action_type = GGRSFrameActionType::SaveGameState;
                 result.actions.push(act);
             }
-            ggrs::GGRSRequest::LoadGameState { cell: _, frame } => {
+            ggrs::GGRSRequest::LoadGameState { cell, frame } => {
                 let mut act = GGRSFrameAction::default();
                 act.action_info.frame = frame;
+                act.action_info.state = cell.load().unwrap_or_default();
                 act.action_type = GGRSFrameActionType::LoadGameState;
                 result.actions.push(act);
+                *current_frame = frame;
             }
             ggrs::GGRSRequest::AdvanceFrame { inputs } => {
-                let mut act = GGRSFrameAction::default();
-                act.action_type = GGRSFrameActionType::AdvanceFrame;
+                let mut act = GGRSFrameAction {
+                    action_type: GGRSFrameActionType::AdvanceFrame,
+                    action_info: GGRSFrameActionInfo {
+                        frame: *current_frame,
+                        ..GGRSFrameActionInfo::default()
+                    },
+                };
+                *current_frame += 1;
                 for (input, status) in inputs {
                     let stat = match status {
                         ggrs::InputStatus::Confirmed => GGRSInputStatus::Confirmed,
@@ -766,7 +2620,7 @@ fn handle_requests(reqs: Vec<GGRSRequest<GGRSConfig>>, result: &mut GGRSFrameRes
                         ggrs::InputStatus::Disconnected => GGRSInputStatus::Disconnected,
                     };
                     act.action_info.inputs.push(GGRSInput {
-                        input,
+                        input: input.bytes[..input_size].to_vec(),
                         status: stat,
                     });
                 }
@@ -776,110 +2630,143 @@ fn handle_requests(reqs: Vec<GGRSRequest<GGRSConfig>>, result: &mut GGRSFrameRes
     }
 }
 
-#[allow(unused_assignments)]
-fn get_frames_ahead(mut session: *mut GGRSSession) -> i32 {
-    let mut sess = unsafe { Box::from_raw(session) };
-    let mut ahead = 0;
-    match sess.as_mut() {
-        GGRSSession::NotSet | GGRSSession::Spectator(_) | GGRSSession::Synctest(_) => (),
-        GGRSSession::Peer2Peer(sess) => ahead = sess.frames_ahead(),
-    }
-    session = Box::into_raw(sess);
-    return ahead;
+#[derive(Debug)]
+pub struct Error {
+    code: GGRSErrorCode,
+    msg: String,
 }
 
-#[allow(unused_assignments)]
-fn clean_session(session: *mut GGRSSession) -> bool {
-    unsafe { drop(Box::from_raw(session)) };
-    return true;
-}
+impl Error {
+    pub fn code(&self) -> GGRSErrorCode {
+        self.code
+    }
 
-#[allow(unused_assignments)]
-fn network_stats(
-    mut session: *mut GGRSSession,
-    player_handle: u32,
-) -> Result<GGRSNetworkStats, Error> {
-    let sess = unsafe { Box::from_raw(session) };
-    let mut net_stats = NetworkStats::new();
-    match sess.as_ref() {
-        GGRSSession::Peer2Peer(sess_ref) => {
-            let stats = sess_ref.network_stats(player_handle as usize);
-            match stats {
-                Ok(stats) => net_stats = stats,
-                Err(err) => {
-                    session = Box::into_raw(sess);
-                    return Err(Error {
-                        msg: err.to_string(),
-                    });
-                }
-            }
+    fn session_closed() -> Self {
+        Self {
+            code: GGRSErrorCode::SessionClosed,
+            msg: "Error session has already been cleaned up".to_string(),
         }
-        GGRSSession::Spectator(sess_ref) => {
-            let stats = sess_ref.network_stats();
-            match stats {
-                Ok(stats) => net_stats = stats,
-                Err(err) => {
-                    session = Box::into_raw(sess);
-                    return Err(Error {
-                        msg: err.to_string(),
-                    });
-                }
-            }
+    }
+
+    // ggrs turns down a configuration it can't run with an InvalidRequest, the builder is what needs fixing
+    fn invalid_configuration(err: ggrs::GGRSError) -> Self {
+        match err {
+            ggrs::GGRSError::InvalidRequest { .. } => Self {
+                code: GGRSErrorCode::InvalidConfiguration,
+                msg: err.to_string(),
+            },
+            err => Self::from(err),
         }
-        GGRSSession::NotSet | GGRSSession::Synctest(_) => {
-            session = Box::into_raw(sess);
-            return Err(Error {
-                msg: "Unsupported Operation For This Sessoin Type".to_string(),
-            });
+    }
+
+    fn session_poisoned() -> Self {
+        Self {
+            code: GGRSErrorCode::SessionPoisoned,
+            msg: "Error session is unusable after a previous call panicked".to_string(),
         }
     }
-    session = Box::into_raw(sess);
-    Ok(GGRSNetworkStats::new(
-        net_stats.send_queue_len as u32,
-        net_stats.ping as u64,
-        net_stats.kbps_sent as u64,
-        net_stats.local_frames_behind,
-        net_stats.remote_frames_behind,
-    ))
-}
 
-#[allow(unused_assignments)]
-fn disconnect_player(mut session: *mut GGRSSession, player_handle: u32) -> Result<bool, Error> {
-    let mut sess = unsafe { Box::from_raw(session) };
-    match sess.as_mut() {
-        GGRSSession::NotSet | GGRSSession::Spectator(_) | GGRSSession::Synctest(_) => {
-            session = Box::into_raw(sess);
-            return Err(Error {
-                msg: "Unsupported Operation For This Sessoin Type".to_string(),
-            });
+    fn panic(payload: Box<dyn Any + Send>) -> Self {
+        let msg = if let Some(msg) = payload.downcast_ref::<&str>() {
+            msg.to_string()
+        } else if let Some(msg) = payload.downcast_ref::<String>() {
+            msg.clone()
+        } else {
+            "unknown panic payload".to_string()
+        };
+        Self {
+            code: GGRSErrorCode::Panic,
+            msg: format!("Error rust panicked: {}", msg),
         }
-        GGRSSession::Peer2Peer(sess_mut) => {
-            let dc = sess_mut.disconnect_player(player_handle as usize);
-            match dc {
-                Ok(_) => {
-                    session = Box::into_raw(sess);
-                    return Ok(true);
-                }
-                Err(err) => {
-                    session = Box::into_raw(sess);
-                    return Err(Error {
-                        msg: err.to_string(),
-                    });
-                }
-            }
+    }
+
+    fn replay_io(path: &str, err: io::Error) -> Self {
+        let code = match err.kind() {
+            io::ErrorKind::InvalidData => GGRSErrorCode::InvalidReplay,
+            _ => GGRSErrorCode::ReplayIoFailed,
+        };
+        Self {
+            code,
+            msg: format!("Error replay file {}: {}", path, err),
+        }
+    }
+
+    fn mismatched_checksum(frame: Frame) -> Self {
+        Self {
+            code: GGRSErrorCode::MismatchedChecksum,
+            msg: format!(
+                "Error the synctest found a checksum mismatch at frame {}, the session can't advance anymore",
+                frame
+            ),
+        }
+    }
+
+    fn no_pending_save(frame: Frame) -> Self {
+        Self {
+            code: GGRSErrorCode::NoPendingSave,
+            msg: format!("Error no pending save request for frame: {}", frame),
         }
     }
 }
 
-#[derive(Debug)]
-struct Error {
-    msg: String,
+impl From<ggrs::GGRSError> for Error {
+    fn from(err: ggrs::GGRSError) -> Self {
+        let code = match err {
+            ggrs::GGRSError::PredictionThreshold => GGRSErrorCode::PredictionThreshold,
+            ggrs::GGRSError::InvalidRequest { .. } => GGRSErrorCode::InvalidRequest,
+            ggrs::GGRSError::MismatchedChecksum { .. } => GGRSErrorCode::MismatchedChecksum,
+            ggrs::GGRSError::NotSynchronized => GGRSErrorCode::NotSynchronized,
+            ggrs::GGRSError::SpectatorTooFarBehind => GGRSErrorCode::SpectatorTooFarBehind,
+            ggrs::GGRSError::SocketCreationFailed => GGRSErrorCode::SocketCreationFailed,
+            ggrs::GGRSError::PlayerDisconnected => GGRSErrorCode::PlayerDisconnected,
+            ggrs::GGRSError::DecodingError => GGRSErrorCode::DecodingError,
+        };
+        Self {
+            code,
+            msg: err.to_string(),
+        }
+    }
 }
 
 impl std::error::Error for Error {}
 
+impl GGRSErrorInfo {
+    fn ok() -> Self {
+        Self {
+            code: GGRSErrorCode::Ok,
+            message: String::new(),
+        }
+    }
+
+    // writes the value of a successful call to out and reports how the call went
+    fn from_result<T>(result: Result<T, Error>, out: &mut T) -> Self {
+        match result {
+            Ok(value) => {
+                *out = value;
+                Self::ok()
+            }
+            Err(err) => Self::from(err),
+        }
+    }
+}
+
+impl From<Error> for GGRSErrorInfo {
+    fn from(err: Error) -> Self {
+        Self {
+            code: err.code,
+            message: err.msg,
+        }
+    }
+}
+
 impl Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        f.write_str(format!("INTERNAL RUST ERROR! msg: {}", self.msg).as_str())
+        f.write_str(
+            format!(
+                "INTERNAL RUST ERROR! code: {:?} msg: {}",
+                self.code, self.msg
+            )
+            .as_str(),
+        )
     }
 }
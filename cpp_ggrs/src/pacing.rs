@@ -0,0 +1,90 @@
+use std::time::{Duration, Instant};
+
+use crate::wrapper::{GGRSDuration, GGRSFramePacing, GGRSPacingMode};
+
+// how much longer a frame gets while a loop is ahead of its peers. a fixed step loop saves it up and skips a
+// whole frame once it adds up to one
+const SLOWDOWN_PERCENT: u32 = 10;
+
+// keeps the frame loop of a session in step with its peers. ggrs only says how far ahead this peer runs and
+// recommends skipping frames, the pacer turns that and the fps into a frame to skip or a time to wait
+pub struct GGRSFramePacer {
+    mode: GGRSPacingMode,
+    frame_duration: Duration,
+    // when the next frame is due, unset before the first frame
+    next_frame: Option<Instant>,
+    // recommended frames that have not been waited out yet
+    frames_to_skip: u32,
+    // extra time the loop already spent, or saved up, towards the next frame it waits out
+    slowed_down: Duration,
+}
+
+impl Default for GGRSFramePacer {
+    fn default() -> Self {
+        Self::new(GGRSPacingMode::FixedStep, 60)
+    }
+}
+
+impl GGRSFramePacer {
+    pub fn new(mode: GGRSPacingMode, fps: u32) -> Self {
+        Self {
+            mode,
+            frame_duration: Duration::from_secs(1) / fps.max(1),
+            next_frame: None,
+            frames_to_skip: 0,
+            slowed_down: Duration::ZERO,
+        }
+    }
+
+    pub fn wait_recommendation(&mut self, skip_frames: u32) {
+        self.frames_to_skip = self.frames_to_skip.saturating_add(skip_frames);
+    }
+
+    // called once at the start of every frame of the loop, now is when the frame starts
+    pub fn pace(&mut self, frames_ahead: i32, now: Instant) -> GGRSFramePacing {
+        let mut skip_frame = false;
+        let mut frame_duration = self.frame_duration;
+        if self.mode == GGRSPacingMode::VariableRefresh {
+            // a slower frame instead of a skipped one, the recommendation is paid off bit by bit
+            if frames_ahead > 0 || self.frames_to_skip > 0 {
+                let extra = self.frame_duration * SLOWDOWN_PERCENT / 100;
+                frame_duration += extra;
+                self.slowed_down += extra;
+            }
+            if self.slowed_down >= self.frame_duration {
+                self.slowed_down -= self.frame_duration;
+                self.frames_to_skip = self.frames_to_skip.saturating_sub(1);
+            }
+        } else if self.frames_to_skip > 0 {
+            self.frames_to_skip -= 1;
+            skip_frame = true;
+        } else if frames_ahead > 0 {
+            // ahead without a recommendation yet, back off as gently as a variable refresh loop would
+            self.slowed_down += self.frame_duration * SLOWDOWN_PERCENT / 100;
+            if self.slowed_down >= self.frame_duration {
+                self.slowed_down -= self.frame_duration;
+                skip_frame = true;
+            }
+        }
+
+        // after a hitch of more than a frame the loop starts over instead of rushing to catch up
+        let due = match self.next_frame {
+            Some(due) if due + self.frame_duration >= now => due,
+            _ => now,
+        };
+        self.next_frame = Some(due + frame_duration);
+        GGRSFramePacing {
+            skip_frame,
+            sleep: duration(due.saturating_duration_since(now)),
+            frame_duration: duration(frame_duration),
+            frames_ahead,
+        }
+    }
+}
+
+fn duration(duration: Duration) -> GGRSDuration {
+    GGRSDuration {
+        ms: u64::try_from(duration.as_millis()).unwrap_or(u64::MAX),
+        us: u64::try_from(duration.as_micros()).unwrap_or(u64::MAX),
+    }
+}
@@ -0,0 +1,379 @@
+use std::{
+    collections::BTreeMap,
+    fs,
+    io::{self, ErrorKind},
+};
+
+use ggrs::Frame;
+
+use crate::wrapper::{GGRSInput, GGRSInputStatus, GGRSPlayer, GGRSPlayerType};
+use crate::GGRSSessionInfo;
+
+const REPLAY_MAGIC: &[u8; 8] = b"GGRSRPLY";
+// bump whenever the layout below changes, unknown versions are rejected instead of misread.
+// version 1 files have no checksums
+const REPLAY_VERSION: u16 = 2;
+
+// a recorded match. frames[n] holds the confirmed input of every player for frame n.
+// layout (little endian): magic, version, session info, player list, frame count, then per frame and
+// player a status byte followed by input_size_bytes of input. since version 2 every frame ends with a
+// flag byte, followed by the 16 byte checksum of the state at the start of that frame when it is set
+pub struct GGRSReplay {
+    pub(crate) info: GGRSSessionInfo,
+    pub(crate) frames: Vec<Vec<GGRSInput>>,
+    pub(crate) checksums: BTreeMap<Frame, u128>,
+}
+
+impl GGRSReplay {
+    pub fn num_players(&self) -> u32 {
+        self.info.num_players
+    }
+
+    pub fn input_size(&self) -> usize {
+        self.info.input_size_bytes as usize
+    }
+
+    pub fn players(&self) -> &[GGRSPlayer] {
+        &self.info.players
+    }
+
+    pub fn frames(&self) -> &[Vec<GGRSInput>] {
+        &self.frames
+    }
+
+    // the checksum submitted for the state at the start of the frame, if the game submitted one
+    pub fn checksum(&self, frame: Frame) -> Option<u128> {
+        self.checksums.get(&frame).copied()
+    }
+
+    // the first frame where the confirmed inputs or the checksums of both replays differ.
+    // a replay that ends early diverges at its first missing frame
+    pub fn first_divergence(&self, other: &GGRSReplay) -> Option<Frame> {
+        let shared = self.frames.len().min(other.frames.len());
+        for frame in 0..shared {
+            let (inputs, other_inputs) = (&self.frames[frame], &other.frames[frame]);
+            let same_inputs = inputs.len() == other_inputs.len()
+                && inputs
+                    .iter()
+                    .zip(other_inputs)
+                    .all(|(a, b)| a.input == b.input && a.status == b.status);
+            let frame = frame as Frame;
+            let same_checksum = match (self.checksum(frame), other.checksum(frame)) {
+                (Some(a), Some(b)) => a == b,
+                _ => true,
+            };
+            if !same_inputs || !same_checksum {
+                return Some(frame);
+            }
+        }
+        if self.frames.len() != other.frames.len() {
+            return Some(shared as Frame);
+        }
+        None
+    }
+
+    pub fn write_to(&self, path: &str) -> io::Result<()> {
+        let info = &self.info;
+        let mut buf = Vec::new();
+        buf.extend_from_slice(REPLAY_MAGIC);
+        buf.extend_from_slice(&REPLAY_VERSION.to_le_bytes());
+        for value in [
+            info.num_players,
+            info.fps,
+            info.input_delay,
+            info.input_size_bytes,
+            info.max_prediction_frames,
+            info.max_frames_behind,
+            info.catchup_speed,
+            info.check_distance,
+        ] {
+            buf.extend_from_slice(&value.to_le_bytes());
+        }
+        buf.extend_from_slice(&info.disconnect_timeout_ms.to_le_bytes());
+        buf.extend_from_slice(&info.disconnect_notify_delay_ms.to_le_bytes());
+        buf.push(info.sparse_saving as u8);
+
+        buf.extend_from_slice(&(info.players.len() as u32).to_le_bytes());
+        for player in &info.players {
+            buf.extend_from_slice(&player.player_handle.to_le_bytes());
+            buf.push(player_type_to_byte(player.player_type));
+            buf.extend_from_slice(&(player.socket_addr.len() as u32).to_le_bytes());
+            buf.extend_from_slice(player.socket_addr.as_bytes());
+        }
+
+        buf.extend_from_slice(&(self.frames.len() as u32).to_le_bytes());
+        for (frame, inputs) in self.frames.iter().enumerate() {
+            for input in inputs {
+                buf.push(status_to_byte(input.status));
+                buf.extend_from_slice(&input.input);
+            }
+            match self.checksum(frame as Frame) {
+                Some(checksum) => {
+                    buf.push(1);
+                    buf.extend_from_slice(&checksum.to_le_bytes());
+                }
+                None => buf.push(0),
+            }
+        }
+        fs::write(path, buf)
+    }
+
+    // malformed files are reported as ErrorKind::InvalidData
+    pub fn read_from(path: &str) -> io::Result<Self> {
+        let data = fs::read(path)?;
+        let mut reader = ReplayReader { data: &data };
+
+        if reader.take(REPLAY_MAGIC.len())? != REPLAY_MAGIC {
+            return Err(invalid_data("not a replay file".to_string()));
+        }
+        let version = u16::from_le_bytes(reader.array()?);
+        if version == 0 || version > REPLAY_VERSION {
+            return Err(invalid_data(format!(
+                "unsupported replay version {}, expected at most {}",
+                version, REPLAY_VERSION
+            )));
+        }
+
+        // fields are read in the order they are listed
+        let mut info = GGRSSessionInfo {
+            num_players: reader.u32()?,
+            fps: reader.u32()?,
+            input_delay: reader.u32()?,
+            input_size_bytes: reader.u32()?,
+            max_prediction_frames: reader.u32()?,
+            max_frames_behind: reader.u32()?,
+            catchup_speed: reader.u32()?,
+            check_distance: reader.u32()?,
+            disconnect_timeout_ms: u64::from_le_bytes(reader.array()?),
+            disconnect_notify_delay_ms: u64::from_le_bytes(reader.array()?),
+            sparse_saving: reader.byte()? != 0,
+            ..GGRSSessionInfo::default()
+        };
+        // every frame holds one input per player, without players a frame count could never run out of data
+        if info.num_players == 0 {
+            return Err(invalid_data(
+                "a replay needs at least one player".to_string(),
+            ));
+        }
+        if info.input_size_bytes == 0 || info.input_size_bytes as usize > crate::MAX_INPUT_SIZE {
+            return Err(invalid_data(format!(
+                "invalid input size {}",
+                info.input_size_bytes
+            )));
+        }
+
+        let num_players = reader.u32()?;
+        for _ in 0..num_players {
+            let player_handle = reader.u32()?;
+            let player_type = byte_to_player_type(reader.byte()?)?;
+            let addr_len = reader.u32()? as usize;
+            let socket_addr = match String::from_utf8(reader.take(addr_len)?.to_vec()) {
+                Ok(it) => it,
+                Err(_) => return Err(invalid_data("player address is not utf-8".to_string())),
+            };
+            info.players.push(GGRSPlayer {
+                player_handle,
+                player_type,
+                socket_addr,
+            });
+        }
+
+        let num_frames = reader.u32()?;
+        let input_size = info.input_size_bytes as usize;
+        // the smallest a frame gets is a status byte and an input per player, plus the checksum flag
+        let min_frame_len =
+            info.num_players as u64 * (1 + input_size as u64) + (version >= 2) as u64;
+        if num_frames as u64 * min_frame_len > reader.data.len() as u64 {
+            return Err(invalid_data(format!(
+                "replay declares {} frames but only holds {} bytes of them",
+                num_frames,
+                reader.data.len()
+            )));
+        }
+        let mut frames = Vec::with_capacity(num_frames as usize);
+        let mut checksums = BTreeMap::new();
+        for frame in 0..num_frames {
+            let mut inputs = Vec::new();
+            for _ in 0..info.num_players {
+                let status = byte_to_status(reader.byte()?)?;
+                inputs.push(GGRSInput {
+                    input: reader.take(input_size)?.to_vec(),
+                    status,
+                });
+            }
+            frames.push(inputs);
+            if version >= 2 && reader.byte()? != 0 {
+                checksums.insert(frame as Frame, u128::from_le_bytes(reader.array()?));
+            }
+        }
+        if !reader.data.is_empty() {
+            return Err(invalid_data(
+                "trailing bytes after the last frame".to_string(),
+            ));
+        }
+        Ok(Self {
+            info,
+            frames,
+            checksums,
+        })
+    }
+}
+
+// collects the inputs of a running session. ggrs only resimulates frames it predicted wrong, so the
+// latest inputs of every frame are kept until the session reports that frame as confirmed
+pub struct GGRSReplayRecorder {
+    path: String,
+    replay: GGRSReplay,
+    unconfirmed: BTreeMap<Frame, Vec<GGRSInput>>,
+}
+
+impl GGRSReplayRecorder {
+    pub fn new(path: &str, info: GGRSSessionInfo) -> Self {
+        Self {
+            path: path.to_string(),
+            replay: GGRSReplay {
+                info,
+                frames: Vec::new(),
+                checksums: BTreeMap::new(),
+            },
+            unconfirmed: BTreeMap::new(),
+        }
+    }
+
+    pub fn record(&mut self, frame: Frame, inputs: &[GGRSInput]) {
+        if frame as usize >= self.replay.frames.len() {
+            self.unconfirmed.insert(frame, inputs.to_vec());
+        }
+    }
+
+    // rollbacks save a frame again, the last checksum is the one of the confirmed state
+    pub fn record_checksum(&mut self, frame: Frame, checksum: u128) {
+        self.replay.checksums.insert(frame, checksum);
+    }
+
+    // moves every frame up to and including confirmed_frame into the replay
+    pub fn confirm(&mut self, confirmed_frame: Frame) {
+        loop {
+            let next = self.replay.frames.len() as Frame;
+            if next > confirmed_frame {
+                break;
+            }
+            match self.unconfirmed.remove(&next) {
+                Some(mut inputs) => {
+                    // a correct prediction is never resimulated, by now it is confirmed as well
+                    for input in &mut inputs {
+                        if input.status == GGRSInputStatus::Predicted {
+                            input.status = GGRSInputStatus::Confirmed;
+                        }
+                    }
+                    self.replay.frames.push(inputs);
+                }
+                None => break,
+            }
+        }
+    }
+
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    // writes the confirmed frames, inputs that never got confirmed are left out
+    pub fn finish(mut self) -> io::Result<()> {
+        let num_frames = self.replay.frames.len() as Frame;
+        self.replay.checksums.retain(|&frame, _| frame < num_frames);
+        self.replay.write_to(&self.path)
+    }
+}
+
+// feeds a recorded match back frame by frame
+pub struct GGRSReplayPlayback {
+    replay: GGRSReplay,
+    next_frame: usize,
+}
+
+impl GGRSReplayPlayback {
+    pub fn new(replay: GGRSReplay) -> Self {
+        Self {
+            replay,
+            next_frame: 0,
+        }
+    }
+
+    pub fn info(&self) -> &GGRSSessionInfo {
+        &self.replay.info
+    }
+
+    pub fn next_inputs(&mut self) -> Option<(Frame, Vec<GGRSInput>)> {
+        let inputs = self.replay.frames.get(self.next_frame)?.clone();
+        let frame = self.next_frame as Frame;
+        self.next_frame += 1;
+        Some((frame, inputs))
+    }
+}
+
+struct ReplayReader<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> ReplayReader<'a> {
+    fn take(&mut self, len: usize) -> io::Result<&'a [u8]> {
+        if self.data.len() < len {
+            return Err(invalid_data("replay file is truncated".to_string()));
+        }
+        let (head, tail) = self.data.split_at(len);
+        self.data = tail;
+        Ok(head)
+    }
+
+    fn array<const N: usize>(&mut self) -> io::Result<[u8; N]> {
+        let mut result = [0; N];
+        result.copy_from_slice(self.take(N)?);
+        Ok(result)
+    }
+
+    fn u32(&mut self) -> io::Result<u32> {
+        Ok(u32::from_le_bytes(self.array()?))
+    }
+
+    fn byte(&mut self) -> io::Result<u8> {
+        Ok(self.take(1)?[0])
+    }
+}
+
+fn invalid_data(msg: String) -> io::Error {
+    io::Error::new(ErrorKind::InvalidData, msg)
+}
+
+fn player_type_to_byte(player_type: GGRSPlayerType) -> u8 {
+    match player_type {
+        GGRSPlayerType::Local => 0,
+        GGRSPlayerType::Remote => 1,
+        _ => 2,
+    }
+}
+
+fn byte_to_player_type(byte: u8) -> io::Result<GGRSPlayerType> {
+    match byte {
+        0 => Ok(GGRSPlayerType::Local),
+        1 => Ok(GGRSPlayerType::Remote),
+        2 => Ok(GGRSPlayerType::Spectator),
+        _ => Err(invalid_data(format!("unknown player type {}", byte))),
+    }
+}
+
+fn status_to_byte(status: GGRSInputStatus) -> u8 {
+    match status {
+        GGRSInputStatus::Confirmed => 0,
+        GGRSInputStatus::Predicted => 1,
+        _ => 2,
+    }
+}
+
+fn byte_to_status(byte: u8) -> io::Result<GGRSInputStatus> {
+    match byte {
+        0 => Ok(GGRSInputStatus::Confirmed),
+        1 => Ok(GGRSInputStatus::Predicted),
+        2 => Ok(GGRSInputStatus::Disconnected),
+        _ => Err(invalid_data(format!("unknown input status {}", byte))),
+    }
+}
@@ -0,0 +1,252 @@
+use std::{
+    collections::BTreeMap,
+    fmt::Write as _,
+    fs,
+    io::{self, ErrorKind},
+    path::{Path, PathBuf},
+};
+
+use ggrs::Frame;
+
+use crate::desync::GGRSDesync;
+use crate::wrapper::{GGRSChecksumMismatch, GGRSInput, GGRSInputStatus, GGRSPlayerType};
+use crate::GGRSSessionInfo;
+
+// the states saved for a frame. a synctest compares the first one with the resimulated latest one
+struct GGRSSavedStates {
+    first: Vec<u8>,
+    latest: Vec<u8>,
+}
+
+// keeps the recent inputs and states of a session and dumps them into a directory once a desync shows up.
+// only the first desync of a session is written, everything after it usually follows from it
+pub struct GGRSDesyncReporter {
+    dir: PathBuf,
+    // how many of the most recent frames are kept
+    frames: Frame,
+    inputs: BTreeMap<Frame, Vec<GGRSInput>>,
+    states: BTreeMap<Frame, GGRSSavedStates>,
+    // the report directory, or why it could not be written
+    written: Option<Result<String, String>>,
+}
+
+impl GGRSDesyncReporter {
+    pub fn new(dir: &str, frames: u32) -> Self {
+        Self {
+            dir: PathBuf::from(dir),
+            frames: frames.min(Frame::MAX as u32) as Frame,
+            inputs: BTreeMap::new(),
+            states: BTreeMap::new(),
+            written: None,
+        }
+    }
+
+    // the directory of the report once one was written, or why writing it failed
+    pub fn written(&self) -> Option<&Result<String, String>> {
+        self.written.as_ref()
+    }
+
+    // rollbacks advance a frame again, the latest inputs replace the predicted ones
+    pub fn record_inputs(&mut self, frame: Frame, inputs: &[GGRSInput]) {
+        self.inputs.insert(frame, inputs.to_vec());
+        let oldest = frame.saturating_sub(self.frames);
+        self.inputs.retain(|&f, _| f > oldest);
+    }
+
+    pub fn record_state(&mut self, frame: Frame, state: &[u8]) {
+        match self.states.get_mut(&frame) {
+            Some(saved) => saved.latest = state.to_vec(),
+            None => {
+                self.states.insert(
+                    frame,
+                    GGRSSavedStates {
+                        first: state.to_vec(),
+                        latest: state.to_vec(),
+                    },
+                );
+            }
+        }
+        let oldest = frame.saturating_sub(self.frames);
+        self.states.retain(|&f, _| f > oldest);
+    }
+
+    // desyncs a p2p session found by comparing checksums with its peers
+    pub fn write_p2p(&mut self, info: &GGRSSessionInfo, desyncs: &[GGRSDesync]) {
+        let mut text = String::new();
+        let mut blobs = Vec::new();
+        for desync in desyncs {
+            let _ = writeln!(text, "frame {}: desync with {}", desync.frame, desync.addr);
+            let _ = writeln!(text, "  local checksum:  {:032x}", desync.local_checksum);
+            let _ = writeln!(text, "  remote checksum: {:032x}", desync.remote_checksum);
+            if let Some(saved) = self.states.get(&desync.frame) {
+                blobs.push((
+                    format!("local_state_{}.bin", desync.frame),
+                    saved.latest.clone(),
+                ));
+            }
+        }
+        self.write("p2p desync", desyncs[0].frame, info, text, blobs)
+    }
+
+    // frames a synctest session resimulated into a different state
+    pub fn write_synctest(&mut self, info: &GGRSSessionInfo, mismatches: &[GGRSChecksumMismatch]) {
+        let mut text = String::new();
+        let mut blobs = Vec::new();
+        for mismatch in mismatches {
+            let _ = writeln!(
+                text,
+                "frame {}: resimulation changed the state",
+                mismatch.frame
+            );
+            let _ = writeln!(
+                text,
+                "  original checksum:    {}",
+                describe_checksum(mismatch.original_checksum())
+            );
+            let _ = writeln!(
+                text,
+                "  resimulated checksum: {}",
+                describe_checksum(mismatch.resimulated_checksum())
+            );
+            self.push_states(mismatch.frame, &mut blobs);
+        }
+        self.write("synctest mismatch", mismatches[0].frame, info, text, blobs)
+    }
+
+    // ggrs found a mismatch the session did not see in the last saves, all there is to go on is the frame
+    pub fn write_synctest_frame(&mut self, info: &GGRSSessionInfo, frame: Frame) {
+        let text = format!(
+            "frame {}: resimulation changed the state\n  checksums: unknown, only ggrs saw them differ\n",
+            frame
+        );
+        let mut blobs = Vec::new();
+        self.push_states(frame, &mut blobs);
+        self.write("synctest mismatch", frame, info, text, blobs)
+    }
+
+    fn push_states(&self, frame: Frame, blobs: &mut Vec<(String, Vec<u8>)>) {
+        if let Some(saved) = self.states.get(&frame) {
+            blobs.push((format!("original_state_{}.bin", frame), saved.first.clone()));
+            blobs.push((
+                format!("resimulated_state_{}.bin", frame),
+                saved.latest.clone(),
+            ));
+        }
+    }
+
+    fn write(
+        &mut self,
+        kind: &str,
+        frame: Frame,
+        info: &GGRSSessionInfo,
+        mismatches: String,
+        blobs: Vec<(String, Vec<u8>)>,
+    ) {
+        if self.written.is_some() {
+            return;
+        }
+        // a failed report must not break the session, the error is kept for desync_report_path
+        self.written = Some(match self.write_dir(kind, frame, info, mismatches, blobs) {
+            Ok(dir) => Ok(dir.to_string_lossy().into_owned()),
+            Err(err) => Err(format!(
+                "Error writing desync report to {}: {}",
+                self.dir.display(),
+                err
+            )),
+        });
+    }
+
+    fn write_dir(
+        &self,
+        kind: &str,
+        frame: Frame,
+        info: &GGRSSessionInfo,
+        mismatches: String,
+        blobs: Vec<(String, Vec<u8>)>,
+    ) -> io::Result<PathBuf> {
+        let dir = create_report_dir(&self.dir, frame)?;
+
+        let mut text = String::new();
+        let _ = writeln!(text, "cpp_ggrs desync report: {}", kind);
+        text.push_str(&mismatches);
+        if blobs.is_empty() {
+            let _ = writeln!(text, "no saved states, they are older than the kept frames");
+        }
+
+        let _ = writeln!(text, "\n[session]");
+        let _ = writeln!(text, "num_players: {}", info.num_players);
+        let _ = writeln!(text, "fps: {}", info.fps);
+        let _ = writeln!(text, "input_delay: {}", info.input_delay);
+        let _ = writeln!(text, "input_size_bytes: {}", info.input_size_bytes);
+        let _ = writeln!(
+            text,
+            "max_prediction_frames: {}",
+            info.max_prediction_frames
+        );
+        let _ = writeln!(text, "check_distance: {}", info.check_distance);
+        let _ = writeln!(text, "sparse_saving: {}", info.sparse_saving);
+        let _ = writeln!(
+            text,
+            "desync_detection_interval: {}",
+            info.desync_detection_interval
+        );
+        for player in &info.players {
+            let player_type = match player.player_type {
+                GGRSPlayerType::Local => "local",
+                GGRSPlayerType::Remote => "remote",
+                _ => "spectator",
+            };
+            let line = format!(
+                "player {}: {} {}",
+                player.player_handle, player_type, player.socket_addr
+            );
+            let _ = writeln!(text, "{}", line.trim_end());
+        }
+
+        let _ = writeln!(text, "\n[inputs]");
+        for (frame, inputs) in &self.inputs {
+            let _ = write!(text, "frame {}:", frame);
+            for input in inputs {
+                let status = match input.status {
+                    GGRSInputStatus::Confirmed => "confirmed",
+                    GGRSInputStatus::Predicted => "predicted",
+                    _ => "disconnected",
+                };
+                let _ = write!(text, " {} {}", hex(&input.input), status);
+            }
+            text.push('\n');
+        }
+
+        for (name, blob) in blobs {
+            fs::write(dir.join(name), blob)?;
+        }
+        fs::write(dir.join("report.txt"), text)?;
+        Ok(dir)
+    }
+}
+
+// a new directory per report, several sessions may share the parent
+fn create_report_dir(parent: &Path, frame: Frame) -> io::Result<PathBuf> {
+    fs::create_dir_all(parent)?;
+    for n in 0.. {
+        let dir = parent.join(format!("desync_frame_{}_{}", frame, n));
+        match fs::create_dir(&dir) {
+            Ok(()) => return Ok(dir),
+            Err(err) if err.kind() == ErrorKind::AlreadyExists => continue,
+            Err(err) => return Err(err),
+        }
+    }
+    unreachable!()
+}
+
+// a save without a submitted checksum has none, not a checksum of zero
+fn describe_checksum(checksum: Option<u128>) -> String {
+    match checksum {
+        Some(checksum) => format!("{:032x}", checksum),
+        None => "none".to_string(),
+    }
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
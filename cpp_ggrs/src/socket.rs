@@ -0,0 +1,455 @@
+use std::{
+    cell::RefCell,
+    collections::BTreeMap,
+    io::{self, ErrorKind},
+    net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, ToSocketAddrs, UdpSocket},
+    rc::Rc,
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+use cxx::UniquePtr;
+use ggrs::{Frame, Message, NonBlockingSocket};
+
+use crate::wrapper::{GGRSAddressPreference, GGRSNetworkConditions, GGRSNonBlockingSocket};
+
+// marks a checksum report on the wire. ggrs fails to decode it as a message, so a peer without desync
+// detection drops it like any other garbage packet
+const CHECKSUM_REPORT_MARKER: &[u8; 8] = b"GGRSSUM\0";
+
+// everything the wrapper sends to other peers, the ggrs protocol plus its own checksum reports
+#[derive(Clone)]
+pub enum GGRSPacket {
+    Ggrs(Message),
+    ChecksumReport { frame: Frame, checksum: u128 },
+}
+
+impl GGRSPacket {
+    pub fn encode(&self) -> Vec<u8> {
+        match self {
+            GGRSPacket::Ggrs(msg) => {
+                bincode::serialize(msg).expect("ggrs messages always serialize")
+            }
+            GGRSPacket::ChecksumReport { frame, checksum } => {
+                let mut buf = CHECKSUM_REPORT_MARKER.to_vec();
+                buf.extend_from_slice(&frame.to_le_bytes());
+                buf.extend_from_slice(&checksum.to_le_bytes());
+                buf
+            }
+        }
+    }
+
+    // None for packets that are neither, they are dropped just like ggrs drops them
+    pub fn decode(buf: &[u8]) -> Option<Self> {
+        match buf.strip_prefix(CHECKSUM_REPORT_MARKER) {
+            Some(report) if report.len() == 20 => Some(GGRSPacket::ChecksumReport {
+                frame: Frame::from_le_bytes(report[..4].try_into().ok()?),
+                checksum: u128::from_le_bytes(report[4..].try_into().ok()?),
+            }),
+            Some(_) => None,
+            None => bincode::deserialize(buf).ok().map(GGRSPacket::Ggrs),
+        }
+    }
+}
+
+// a socket a session can be created with. addresses are plain strings so every transport can pick its own format
+pub trait GGRSTransport: 'static {
+    fn send_packet(&mut self, packet: &GGRSPacket, addr: &str);
+    fn receive_all_packets(&mut self) -> Vec<(String, GGRSPacket)>;
+    // turns a user supplied address into the exact string this transport reports the sender with, or says why
+    // it can't
+    fn resolve_address(addr: &str, preference: GGRSAddressPreference) -> Result<String, String>;
+    // the address peers reach this socket at, if the transport knows it
+    fn local_address(&self) -> Option<String>;
+}
+
+// checksum reports on their way between the session and its socket
+#[derive(Default)]
+pub struct GGRSChecksumChannel {
+    pub outgoing: Vec<(String, Frame, u128)>,
+    pub incoming: Vec<(String, Frame, u128)>,
+}
+
+// what ggrs talks through. ggrs messages pass straight to the transport, checksum reports go through the
+// channel shared with the session. without a channel received reports are dropped
+pub struct GGRSSessionSocket<S: GGRSTransport> {
+    transport: S,
+    checksums: Option<Rc<RefCell<GGRSChecksumChannel>>>,
+}
+
+impl<S: GGRSTransport> GGRSSessionSocket<S> {
+    pub fn new(transport: S, checksums: Option<Rc<RefCell<GGRSChecksumChannel>>>) -> Self {
+        Self {
+            transport,
+            checksums,
+        }
+    }
+}
+
+impl<S: GGRSTransport> NonBlockingSocket<String> for GGRSSessionSocket<S> {
+    fn send_to(&mut self, msg: &Message, addr: &String) {
+        self.transport
+            .send_packet(&GGRSPacket::Ggrs(msg.clone()), addr);
+    }
+
+    fn receive_all_messages(&mut self) -> Vec<(String, Message)> {
+        // ggrs receives at the start of every poll, a good moment to send the queued reports as well
+        if let Some(checksums) = &self.checksums {
+            let outgoing = std::mem::take(&mut checksums.borrow_mut().outgoing);
+            for (addr, frame, checksum) in outgoing {
+                self.transport
+                    .send_packet(&GGRSPacket::ChecksumReport { frame, checksum }, &addr);
+            }
+        }
+        let mut messages = Vec::new();
+        for (addr, packet) in self.transport.receive_all_packets() {
+            match packet {
+                GGRSPacket::Ggrs(msg) => messages.push((addr, msg)),
+                GGRSPacket::ChecksumReport { frame, checksum } => {
+                    if let Some(checksums) = &self.checksums {
+                        checksums
+                            .borrow_mut()
+                            .incoming
+                            .push((addr, frame, checksum));
+                    }
+                }
+            }
+        }
+        messages
+    }
+}
+
+const RECV_BUFFER_SIZE: usize = 4096;
+
+// the default transport, a non-blocking udp socket. an ipv6 socket reaches ipv4 peers through mapped
+// addresses where the os allows dual stack sockets
+pub struct GGRSUdpSocket {
+    socket: UdpSocket,
+    // ipv4 peers of an ipv6 wildcard socket that only takes ipv6, the default on windows
+    ipv4_socket: Option<UdpSocket>,
+    ipv6: bool,
+    buffer: [u8; RECV_BUFFER_SIZE],
+}
+
+impl GGRSUdpSocket {
+    // port 0 lets the os pick a free port
+    pub fn bind(addr: SocketAddr) -> Result<Self, io::Error> {
+        let socket = UdpSocket::bind(addr)?;
+        socket.set_nonblocking(true)?;
+        let mut ipv4_socket = None;
+        if addr.ip() == Ipv6Addr::UNSPECIFIED {
+            // a dual stack socket already holds the ipv4 side of its port and the bind fails. when it
+            // works the ipv6 socket is ipv6 only and the ipv4 peers go through this one, on the same port
+            let port = socket.local_addr()?.port();
+            match UdpSocket::bind((Ipv4Addr::UNSPECIFIED, port)) {
+                Ok(it) => {
+                    it.set_nonblocking(true)?;
+                    ipv4_socket = Some(it);
+                }
+                Err(err) if err.kind() == ErrorKind::AddrInUse => (),
+                Err(err) => return Err(err),
+            }
+        }
+        Ok(Self {
+            socket,
+            ipv4_socket,
+            ipv6: addr.is_ipv6(),
+            buffer: [0; RECV_BUFFER_SIZE],
+        })
+    }
+
+    // the wildcard address of the family the peers need
+    pub fn any_address(ipv6: bool) -> IpAddr {
+        match ipv6 {
+            true => IpAddr::V6(Ipv6Addr::UNSPECIFIED),
+            false => IpAddr::V4(Ipv4Addr::UNSPECIFIED),
+        }
+    }
+}
+
+fn receive_from(
+    socket: &UdpSocket,
+    buffer: &mut [u8; RECV_BUFFER_SIZE],
+    received: &mut Vec<(String, GGRSPacket)>,
+) {
+    loop {
+        match socket.recv_from(buffer) {
+            Ok((len, addr)) => {
+                if let Some(packet) = GGRSPacket::decode(&buffer[..len]) {
+                    received.push((canonical_addr(addr).to_string(), packet));
+                }
+            }
+            Err(err) if err.kind() == ErrorKind::WouldBlock => return,
+            // some platforms report an earlier send to a closed port here
+            Err(err) if err.kind() == ErrorKind::ConnectionReset => continue,
+            Err(_) => return,
+        }
+    }
+}
+
+// mapped ipv4 senders are reported as plain ipv4, the way their address was resolved
+fn canonical_addr(addr: SocketAddr) -> SocketAddr {
+    match addr {
+        SocketAddr::V6(v6) => match v6.ip().to_ipv4_mapped() {
+            Some(ip) => SocketAddr::new(IpAddr::V4(ip), v6.port()),
+            None => addr,
+        },
+        SocketAddr::V4(_) => addr,
+    }
+}
+
+impl GGRSTransport for GGRSUdpSocket {
+    fn send_packet(&mut self, packet: &GGRSPacket, addr: &str) {
+        // every address went through resolve_address when the session was created. udp is unreliable
+        // anyway, a packet that could not be sent counts as lost
+        if let Ok(mut addr) = addr.parse::<SocketAddr>() {
+            let mut socket = &self.socket;
+            match (addr, &self.ipv4_socket) {
+                (SocketAddr::V4(_), Some(ipv4_socket)) => socket = ipv4_socket,
+                (SocketAddr::V4(v4), None) if self.ipv6 => {
+                    addr = SocketAddr::new(IpAddr::V6(v4.ip().to_ipv6_mapped()), v4.port());
+                }
+                _ => (),
+            }
+            let _ = socket.send_to(&packet.encode(), addr);
+        }
+    }
+
+    fn receive_all_packets(&mut self) -> Vec<(String, GGRSPacket)> {
+        let mut received = Vec::new();
+        receive_from(&self.socket, &mut self.buffer, &mut received);
+        if let Some(ipv4_socket) = &self.ipv4_socket {
+            receive_from(ipv4_socket, &mut self.buffer, &mut received);
+        }
+        received
+    }
+
+    fn resolve_address(addr: &str, preference: GGRSAddressPreference) -> Result<String, String> {
+        if let Ok(addr) = addr.parse::<SocketAddr>() {
+            return Ok(canonical_addr(addr).to_string());
+        }
+        // hostnames go through the system resolver, hosts file included
+        let found: Vec<SocketAddr> = match addr.to_socket_addrs() {
+            Ok(it) => it.collect(),
+            Err(err) => return Err(err.to_string()),
+        };
+        let prefer_ipv6 = preference == GGRSAddressPreference::IPv6;
+        match found
+            .iter()
+            .find(|addr| addr.is_ipv6() == prefer_ipv6)
+            .or(found.first())
+        {
+            Some(addr) => Ok(canonical_addr(*addr).to_string()),
+            None => Err("the hostname has no addresses".to_string()),
+        }
+    }
+
+    fn local_address(&self) -> Option<String> {
+        self.socket.local_addr().ok().map(|addr| addr.to_string())
+    }
+}
+
+// a transport implemented in c++. packets cross the bridge as encoded bytes, ggrs messages bincode encoded
+pub struct GGRSCustomSocket {
+    socket: UniquePtr<GGRSNonBlockingSocket>,
+}
+
+impl GGRSCustomSocket {
+    pub fn new(socket: UniquePtr<GGRSNonBlockingSocket>) -> Option<Self> {
+        if socket.is_null() {
+            return None;
+        }
+        Some(Self { socket })
+    }
+}
+
+impl GGRSTransport for GGRSCustomSocket {
+    // a socket that throws lost the packet, ggrs sends it again like after any other loss
+    fn send_packet(&mut self, packet: &GGRSPacket, addr: &str) {
+        let _ = self.socket.pin_mut().send_to(&packet.encode(), addr);
+    }
+
+    fn receive_all_packets(&mut self) -> Vec<(String, GGRSPacket)> {
+        self.socket
+            .pin_mut()
+            .receive_all_messages()
+            .unwrap_or_default()
+            .into_iter()
+            // packets that don't decode are dropped, just like the udp socket does
+            .filter_map(|received| {
+                GGRSPacket::decode(&received.msg).map(|packet| (received.addr, packet))
+            })
+            .collect()
+    }
+
+    fn resolve_address(addr: &str, _: GGRSAddressPreference) -> Result<String, String> {
+        Ok(addr.to_string())
+    }
+
+    // only the c++ side knows where its socket lives
+    fn local_address(&self) -> Option<String> {
+        None
+    }
+}
+
+// packets waiting to be received by the loopback socket with that name
+static LOOPBACK_INBOXES: Mutex<BTreeMap<String, Vec<(String, GGRSPacket)>>> =
+    Mutex::new(BTreeMap::new());
+
+// an in-memory transport for sessions living in the same process. addresses are plain names like "peer0",
+// a name belongs to one socket until that socket is dropped
+pub struct GGRSLoopbackSocket {
+    name: String,
+}
+
+impl GGRSLoopbackSocket {
+    // None when another loopback socket already uses the name
+    pub fn bind(name: &str) -> Option<Self> {
+        let mut inboxes = LOOPBACK_INBOXES
+            .lock()
+            .unwrap_or_else(|err| err.into_inner());
+        if name.is_empty() || inboxes.contains_key(name) {
+            return None;
+        }
+        inboxes.insert(name.to_string(), Vec::new());
+        Some(Self {
+            name: name.to_string(),
+        })
+    }
+}
+
+impl Drop for GGRSLoopbackSocket {
+    fn drop(&mut self) {
+        let mut inboxes = LOOPBACK_INBOXES
+            .lock()
+            .unwrap_or_else(|err| err.into_inner());
+        inboxes.remove(&self.name);
+    }
+}
+
+impl GGRSTransport for GGRSLoopbackSocket {
+    fn send_packet(&mut self, packet: &GGRSPacket, addr: &str) {
+        let mut inboxes = LOOPBACK_INBOXES
+            .lock()
+            .unwrap_or_else(|err| err.into_inner());
+        // nobody listening on that name, the packet is lost just like an unanswered udp packet
+        if let Some(inbox) = inboxes.get_mut(addr) {
+            inbox.push((self.name.clone(), packet.clone()));
+        }
+    }
+
+    fn receive_all_packets(&mut self) -> Vec<(String, GGRSPacket)> {
+        let mut inboxes = LOOPBACK_INBOXES
+            .lock()
+            .unwrap_or_else(|err| err.into_inner());
+        match inboxes.get_mut(&self.name) {
+            Some(inbox) => std::mem::take(inbox),
+            None => Vec::new(),
+        }
+    }
+
+    fn resolve_address(addr: &str, _: GGRSAddressPreference) -> Result<String, String> {
+        if addr.is_empty() {
+            return Err("the loopback name is empty".to_string());
+        }
+        Ok(addr.to_string())
+    }
+
+    fn local_address(&self) -> Option<String> {
+        Some(self.name.clone())
+    }
+}
+
+// wraps another transport and degrades everything it sends. the same seed always makes the same decisions,
+// so a bad connection can be reproduced between two local sessions
+pub struct GGRSSimulatedSocket<S: GGRSTransport> {
+    socket: S,
+    conditions: GGRSNetworkConditions,
+    rng: SplitMix64,
+    // packets waiting for their delivery time
+    in_flight: Vec<(Instant, String, GGRSPacket)>,
+}
+
+impl<S: GGRSTransport> GGRSSimulatedSocket<S> {
+    pub fn new(socket: S, conditions: GGRSNetworkConditions) -> Self {
+        Self {
+            socket,
+            conditions,
+            rng: SplitMix64(conditions.seed),
+            in_flight: Vec::new(),
+        }
+    }
+
+    fn delay(&mut self) -> Duration {
+        let jitter = self.rng.next() % (self.conditions.jitter_ms as u64 + 1);
+        Duration::from_millis(self.conditions.latency_ms as u64 + jitter)
+    }
+
+    // hands every packet that is due to the wrapped socket, earliest first
+    fn flush(&mut self) {
+        let now = Instant::now();
+        self.in_flight.sort_by_key(|(deliver_at, _, _)| *deliver_at);
+        let due = self
+            .in_flight
+            .iter()
+            .take_while(|(deliver_at, _, _)| *deliver_at <= now)
+            .count();
+        for (_, addr, packet) in self.in_flight.drain(..due) {
+            self.socket.send_packet(&packet, &addr);
+        }
+    }
+}
+
+impl<S: GGRSTransport> GGRSTransport for GGRSSimulatedSocket<S> {
+    fn send_packet(&mut self, packet: &GGRSPacket, addr: &str) {
+        if !self.rng.chance(self.conditions.packet_loss) {
+            let copies = if self.rng.chance(self.conditions.duplication) {
+                2
+            } else {
+                1
+            };
+            for _ in 0..copies {
+                let mut delay = self.delay();
+                // a reordered packet is held back long enough for the following ones to overtake it
+                if self.rng.chance(self.conditions.reordering) {
+                    delay += self.delay();
+                }
+                self.in_flight
+                    .push((Instant::now() + delay, addr.to_string(), packet.clone()));
+            }
+        }
+        self.flush();
+    }
+
+    fn receive_all_packets(&mut self) -> Vec<(String, GGRSPacket)> {
+        self.flush();
+        self.socket.receive_all_packets()
+    }
+
+    fn resolve_address(addr: &str, preference: GGRSAddressPreference) -> Result<String, String> {
+        S::resolve_address(addr, preference)
+    }
+
+    fn local_address(&self) -> Option<String> {
+        self.socket.local_address()
+    }
+}
+
+// small and good enough for simulated packet loss, and no extra dependency
+struct SplitMix64(u64);
+
+impl SplitMix64 {
+    fn next(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    fn chance(&mut self, probability: f32) -> bool {
+        // 53 random bits give a uniform value in [0, 1)
+        let roll = (self.next() >> 11) as f64 / (1u64 << 53) as f64;
+        roll < probability as f64
+    }
+}
@@ -0,0 +1,32 @@
+// c++ implementations of the extension points, only built with the testing feature. the integration tests
+// drive the c++ side of the bridge through them
+pub use ffi::*;
+
+#[cxx::bridge(namespace = "GGRS")]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cpp_ggrs/tests/support/ggrs_testing.h");
+
+        type GGRSNonBlockingSocket = crate::wrapper::GGRSNonBlockingSocket;
+        type GGRSEventHandler = crate::wrapper::GGRSEventHandler;
+
+        // an in-memory socket reachable under name by the other memory sockets, like the loopback transport
+        fn new_memory_socket(name: &str) -> UniquePtr<GGRSNonBlockingSocket>;
+        // a memory socket whose send_to and receive_all_messages throw on every fourth call, the packets of
+        // those calls are lost
+        fn new_throwing_memory_socket(name: &str) -> UniquePtr<GGRSNonBlockingSocket>;
+        // an event handler that writes every callback as a line like "on_synchronized addr [1] remote" to the
+        // log of that name. the callback named throw_on throws after it was recorded
+        fn new_recording_handler(log: &str, throw_on: &str) -> UniquePtr<GGRSEventHandler>;
+        // the lines recorded since the last call
+        fn take_recorded_calls(log: &str) -> Vec<String>;
+    }
+}
+
+impl crate::GGRSSession {
+    // forgets the checksums a synctest saw so far, ggrs keeps its own. the only way to get a mismatch past the
+    // comparison of the session, the two see the same saves otherwise
+    pub fn forget_checksums(&mut self) {
+        self.checksum_history.clear();
+    }
+}
@@ -0,0 +1,192 @@
+// udp peers that name each other by hostname or ipv6 address, the socket they bind and the errors for addresses
+// that don't resolve
+use std::thread;
+use std::time::{Duration, Instant};
+
+use cpp_ggrs::wrapper::{
+    GGRSAddressPreference, GGRSErrorCode, GGRSEvent, GGRSEventType, GGRSPlayerType,
+    GGRSSessionState,
+};
+use cpp_ggrs::{new_session_builder, GGRSSession};
+
+mod common;
+use common::{code, error, player};
+
+fn session(
+    local: u32,
+    local_port: u16,
+    remote_addr: &str,
+    preference: GGRSAddressPreference,
+) -> Box<GGRSSession> {
+    new_session_builder()
+        .with_address_preference(preference)
+        .unwrap()
+        .add_player(player(local, GGRSPlayerType::Local, ""))
+        .unwrap()
+        .add_player(player(1 - local, GGRSPlayerType::Remote, remote_addr))
+        .unwrap()
+        .start_p2p_session(local_port)
+        .unwrap()
+}
+
+// polls both peers until they are running, returns the synchronized event of the first one
+fn synchronize(first: &mut GGRSSession, second: &mut GGRSSession) -> GGRSEvent {
+    let mut synchronized = None;
+    let deadline = Instant::now() + Duration::from_secs(5);
+    while first.get_current_state().unwrap() != GGRSSessionState::Running
+        || second.get_current_state().unwrap() != GGRSSessionState::Running
+    {
+        assert!(Instant::now() < deadline, "peers did not synchronize");
+        first.poll_remote_clients().unwrap();
+        second.poll_remote_clients().unwrap();
+        for event in first.get_events().unwrap() {
+            if event.event_type == GGRSEventType::Synchronized {
+                synchronized = Some(event);
+            }
+        }
+        second.get_events().unwrap();
+        thread::sleep(Duration::from_millis(1));
+    }
+    synchronized.expect("no synchronized event")
+}
+
+#[test]
+fn peers_resolve_hostnames() {
+    // localhost only has an ipv4 address here, preferring ipv6 falls back to it
+    let mut first = session(0, 27821, "localhost:27822", GGRSAddressPreference::IPv6);
+    let mut second = session(1, 27822, "127.0.0.1:27821", GGRSAddressPreference::IPv4);
+    let synchronized = synchronize(&mut first, &mut second);
+    // events name the resolved address
+    assert_eq!(synchronized.event_info.addr, "127.0.0.1:27822");
+    assert_eq!(synchronized.event_info.player_handles, [1]);
+}
+
+#[test]
+fn peers_talk_over_ipv6() {
+    let mut first = session(0, 27823, "[::1]:27824", GGRSAddressPreference::IPv4);
+    let mut second = session(1, 27824, "[::1]:27823", GGRSAddressPreference::IPv4);
+    let synchronized = synchronize(&mut first, &mut second);
+    assert_eq!(synchronized.event_info.addr, "[::1]:27824");
+}
+
+#[test]
+fn ipv6_sockets_reach_ipv4_peers() {
+    // the first and last peer need ipv6 and get a dual stack socket, the middle one stays on ipv4
+    let peers = [
+        (27825, ["127.0.0.1:27826", "[::1]:27827"]),
+        (27826, ["127.0.0.1:27825", "127.0.0.1:27827"]),
+        (27827, ["[::1]:27825", "127.0.0.1:27826"]),
+    ];
+    let mut sessions: Vec<Box<GGRSSession>> = Vec::new();
+    for (local, (port, remotes)) in peers.iter().enumerate() {
+        let local = local as u32;
+        let mut builder = new_session_builder();
+        builder
+            .with_num_players(3)
+            .unwrap()
+            .add_player(player(local, GGRSPlayerType::Local, ""))
+            .unwrap();
+        for (remote, addr) in (0..3).filter(|handle| *handle != local).zip(remotes) {
+            builder
+                .add_player(player(remote, GGRSPlayerType::Remote, addr))
+                .unwrap();
+        }
+        sessions.push(builder.start_p2p_session(*port).unwrap());
+    }
+
+    let deadline = Instant::now() + Duration::from_secs(5);
+    while sessions
+        .iter_mut()
+        .any(|sess| sess.get_current_state().unwrap() != GGRSSessionState::Running)
+    {
+        assert!(Instant::now() < deadline, "peers did not synchronize");
+        for sess in &mut sessions {
+            sess.poll_remote_clients().unwrap();
+            sess.get_events().unwrap();
+        }
+        thread::sleep(Duration::from_millis(1));
+    }
+}
+
+#[test]
+fn ephemeral_ports_are_reported() {
+    // the second peer lets the os pick its port and the first one learns it, the way a lobby would
+    let mut second = new_session_builder()
+        .with_bind_address("127.0.0.1")
+        .unwrap()
+        .add_player(player(1, GGRSPlayerType::Local, ""))
+        .unwrap()
+        .add_player(player(0, GGRSPlayerType::Remote, "127.0.0.1:27828"))
+        .unwrap()
+        .start_p2p_session(0)
+        .unwrap();
+    let second_addr = second.local_address().unwrap();
+    assert!(second_addr.starts_with("127.0.0.1:"));
+    assert_ne!(second_addr, "127.0.0.1:0");
+    let mut first = session(0, 27828, &second_addr, GGRSAddressPreference::IPv4);
+    // without a bind address the socket listens on every interface
+    assert_eq!(first.local_address().unwrap(), "0.0.0.0:27828");
+    synchronize(&mut first, &mut second);
+
+    let mut builder = new_session_builder();
+    assert!(builder.try_with_bind_address("localhost").code == GGRSErrorCode::AddressParseFailed);
+    builder.with_bind_address("::1").unwrap();
+    builder.with_bind_address("").unwrap();
+
+    // only sessions with a socket have an address
+    let mut synctest = new_session_builder()
+        .add_player(player(0, GGRSPlayerType::Local, ""))
+        .unwrap()
+        .add_player(player(1, GGRSPlayerType::Local, ""))
+        .unwrap()
+        .start_synctest_session()
+        .unwrap();
+    assert!(code(synctest.local_address()) == GGRSErrorCode::UnsupportedOperation);
+    let mut loopback = new_session_builder()
+        .add_player(player(0, GGRSPlayerType::Local, ""))
+        .unwrap()
+        .add_player(player(1, GGRSPlayerType::Remote, "addr_peer1"))
+        .unwrap()
+        .start_p2p_session_on_loopback("addr_peer0")
+        .unwrap();
+    assert_eq!(loopback.local_address().unwrap(), "addr_peer0");
+}
+
+#[test]
+fn errors_name_the_address() {
+    let mut builder = new_session_builder();
+    builder
+        .add_player(player(0, GGRSPlayerType::Local, ""))
+        .unwrap()
+        .add_player(player(1, GGRSPlayerType::Remote, "127.0.0.1:9"))
+        .unwrap()
+        .add_player(player(
+            2,
+            GGRSPlayerType::Spectator,
+            "no-such-host.invalid:7000",
+        ))
+        .unwrap();
+    assert!(code(builder.start_p2p_session(0)) == GGRSErrorCode::AddressParseFailed);
+    let msg = error(builder.start_p2p_session(0));
+    assert!(
+        msg.contains("player 2 \"no-such-host.invalid:7000\""),
+        "{}",
+        msg
+    );
+
+    let mut builder = new_session_builder();
+    builder
+        .add_player(player(0, GGRSPlayerType::Local, ""))
+        .unwrap()
+        .add_player(player(1, GGRSPlayerType::Remote, "localhost"))
+        .unwrap();
+    let msg = error(builder.start_p2p_session(0));
+    assert!(msg.contains("player 1 \"localhost\""), "{}", msg);
+
+    let msg = error(new_session_builder().start_spectator_session(0, "host-without-port"));
+    assert!(
+        msg.contains("host address \"host-without-port\""),
+        "{}",
+        msg
+    );
+}
@@ -0,0 +1,139 @@
+// helpers shared by the integration tests. every test binary only uses some of them
+#![allow(dead_code)]
+
+use std::fmt::Display;
+use std::path::PathBuf;
+
+use cpp_ggrs::wrapper::{
+    GGRSErrorCode, GGRSFrameActionType, GGRSFrameResult, GGRSLocalInput, GGRSPlayer,
+    GGRSPlayerType, GGRSSessionState,
+};
+use cpp_ggrs::{new_session_builder, Error, GGRSSession, GGRSSessionBuilder};
+
+pub fn player(player_handle: u32, player_type: GGRSPlayerType, socket_addr: &str) -> GGRSPlayer {
+    GGRSPlayer {
+        player_handle,
+        player_type,
+        socket_addr: socket_addr.to_string(),
+    }
+}
+
+pub fn local_input(player_handle: u32, input: &[u8]) -> GGRSLocalInput {
+    GGRSLocalInput {
+        player_handle,
+        input: input.to_vec(),
+    }
+}
+
+// a p2p session of two players, local is the handle of this peer and remote the address of the other one.
+// tweak sets up the rest of the builder first
+pub fn peer_builder(
+    local: u32,
+    remote: &str,
+    tweak: impl FnOnce(&mut GGRSSessionBuilder),
+) -> Box<GGRSSessionBuilder> {
+    let mut builder = new_session_builder();
+    tweak(&mut builder);
+    builder
+        .add_player(player(local, GGRSPlayerType::Local, ""))
+        .unwrap()
+        .add_player(player(1 - local, GGRSPlayerType::Remote, remote))
+        .unwrap();
+    builder
+}
+
+// names are global to the process, every test uses its own
+pub fn loopback_peer(
+    local: u32,
+    name: &str,
+    remote: &str,
+    tweak: impl FnOnce(&mut GGRSSessionBuilder),
+) -> Box<GGRSSession> {
+    peer_builder(local, remote, tweak)
+        .start_p2p_session_on_loopback(name)
+        .unwrap()
+}
+
+// polls the session and advances it once it is running, every saved state holds its frame. nothing is
+// returned while the session synchronizes. the events are left to the caller
+pub fn step_peer(
+    sess: &mut GGRSSession,
+    local_inputs: &[GGRSLocalInput],
+) -> Option<GGRSFrameResult> {
+    sess.poll_remote_clients().unwrap();
+    if sess.get_current_state().unwrap() != GGRSSessionState::Running {
+        return None;
+    }
+    sess.add_local_inputs(local_inputs).unwrap();
+    let result = sess.advance_frame().unwrap();
+    for action in &result.actions {
+        if action.action_type == GGRSFrameActionType::SaveGameState {
+            let frame = action.action_info.frame;
+            sess.save_game_state(frame, &frame.to_le_bytes()).unwrap();
+        }
+    }
+    Some(result)
+}
+
+// a synctest session of two local players, tweak sets up the rest of the builder first
+pub fn synctest_session(tweak: impl FnOnce(&mut GGRSSessionBuilder)) -> Box<GGRSSession> {
+    let mut builder = new_session_builder();
+    tweak(&mut builder);
+    builder
+        .add_player(player(0, GGRSPlayerType::Local, ""))
+        .unwrap()
+        .add_player(player(1, GGRSPlayerType::Local, ""))
+        .unwrap()
+        .start_synctest_session()
+        .unwrap()
+}
+
+// runs a frame of a synctest session with the input of each player and returns the inputs of the newest
+// AdvanceFrame action. every saved state gets its frame as checksum, 1 in the high half
+pub fn run_synctest_frame(sess: &mut GGRSSession, inputs: [&[u8]; 2]) -> Vec<Vec<u8>> {
+    sess.add_local_inputs(&[local_input(0, inputs[0]), local_input(1, inputs[1])])
+        .unwrap();
+    let result = sess.advance_frame().unwrap();
+    let mut advanced = Vec::new();
+    for action in result.actions {
+        match action.action_type {
+            GGRSFrameActionType::SaveGameState => {
+                let frame = action.action_info.frame;
+                sess.save_game_state(frame, &[0]).unwrap();
+                sess.submit_checksum(frame, 1, frame as u64).unwrap();
+            }
+            GGRSFrameActionType::AdvanceFrame => {
+                advanced = action
+                    .action_info
+                    .inputs
+                    .into_iter()
+                    .map(|i| i.input)
+                    .collect();
+            }
+            _ => (),
+        }
+    }
+    advanced
+}
+
+// the message of a call that has to fail
+pub fn error<T, E: Display>(result: Result<T, E>) -> String {
+    match result {
+        Ok(_) => panic!("expected the call to fail"),
+        Err(err) => err.to_string(),
+    }
+}
+
+pub fn code<T>(result: Result<T, Error>) -> GGRSErrorCode {
+    match result {
+        Ok(_) => GGRSErrorCode::Ok,
+        Err(err) => err.code(),
+    }
+}
+
+// a file or directory below the tmp dir cargo keeps for integration tests
+pub fn tmp_path(name: &str) -> String {
+    let mut path = PathBuf::from(env!("CARGO_TARGET_TMPDIR"));
+    path.push(name);
+    path.to_string_lossy().into_owned()
+}
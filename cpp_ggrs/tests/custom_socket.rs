@@ -0,0 +1,109 @@
+// sessions running over a transport implemented in c++, the in-memory socket of the testing feature.
+// names are global to the process, every test uses its own
+use std::thread;
+use std::time::{Duration, Instant};
+
+use cpp_ggrs::testing::{new_memory_socket, new_throwing_memory_socket};
+use cpp_ggrs::wrapper::{
+    GGRSErrorCode, GGRSFrameActionType, GGRSInputStatus, GGRSNonBlockingSocket,
+};
+use cpp_ggrs::GGRSSession;
+use cxx::UniquePtr;
+
+mod common;
+use common::{code, local_input, peer_builder, step_peer};
+
+fn peer(local: u32, socket: UniquePtr<GGRSNonBlockingSocket>, remote: &str) -> Box<GGRSSession> {
+    peer_builder(local, remote, |_| {})
+        .start_p2p_session_with_socket(socket)
+        .unwrap()
+}
+
+// advances the session if it can, the input of a player is the frame times handle + 1.
+// returns the frame and the confirmed inputs of every simulated frame
+fn step(sess: &mut GGRSSession, handle: u32, frame: &mut u32) -> Vec<(i32, Vec<Vec<u8>>)> {
+    let input = (*frame * (handle + 1)).to_le_bytes();
+    let Some(result) = step_peer(sess, &[local_input(handle, &input)]) else {
+        return Vec::new();
+    };
+    if !result.skip_frame {
+        *frame += 1;
+    }
+    result
+        .actions
+        .into_iter()
+        .filter(|action| {
+            action.action_type == GGRSFrameActionType::AdvanceFrame
+                && action
+                    .action_info
+                    .inputs
+                    .iter()
+                    .all(|input| input.status == GGRSInputStatus::Confirmed)
+        })
+        .map(|action| {
+            let info = action.action_info;
+            (
+                info.frame,
+                info.inputs.into_iter().map(|input| input.input).collect(),
+            )
+        })
+        .collect()
+}
+
+#[test]
+fn peers_talk_through_a_cpp_socket() {
+    let mut first = peer(0, new_memory_socket("cpp_peer0"), "cpp_peer1");
+    let mut second = peer(1, new_memory_socket("cpp_peer1"), "cpp_peer0");
+    // the c++ side decides what an address is, the session doesn't know its own
+    assert!(code(first.local_address()) == GGRSErrorCode::UnsupportedOperation);
+
+    let mut confirmed = Vec::new();
+    let (mut first_frame, mut second_frame) = (0, 0);
+    let deadline = Instant::now() + Duration::from_secs(5);
+    while confirmed.len() < 30 {
+        assert!(Instant::now() < deadline, "peers did not get going");
+        confirmed.extend(step(&mut first, 0, &mut first_frame));
+        step(&mut second, 1, &mut second_frame);
+        thread::sleep(Duration::from_millis(1));
+    }
+    // the inputs of the remote peer went through c++ and back
+    for (frame, inputs) in confirmed {
+        let frame = frame as u32;
+        assert_eq!(inputs[0], frame.to_le_bytes());
+        assert_eq!(inputs[1], (frame * 2).to_le_bytes());
+    }
+}
+
+#[test]
+fn throwing_sockets_lose_packets() {
+    let mut first = peer(0, new_throwing_memory_socket("cpp_throw0"), "cpp_throw1");
+    let mut second = peer(1, new_throwing_memory_socket("cpp_throw1"), "cpp_throw0");
+
+    // every fourth send and receive throws, ggrs sends again what got lost. it is in no hurry to repeat a
+    // lost sync packet, so the deadline is loose
+    let mut confirmed = 0;
+    let (mut first_frame, mut second_frame) = (0, 0);
+    let deadline = Instant::now() + Duration::from_secs(60);
+    while confirmed < 30 {
+        assert!(Instant::now() < deadline, "peers did not get going");
+        confirmed += step(&mut first, 0, &mut first_frame).len();
+        step(&mut second, 1, &mut second_frame);
+        thread::sleep(Duration::from_millis(1));
+    }
+}
+
+#[test]
+fn null_sockets_are_rejected() {
+    let mut builder = peer_builder(0, "cpp_nobody", |_| {});
+    assert!(
+        code(builder.start_p2p_session_with_socket(UniquePtr::null()))
+            == GGRSErrorCode::InvalidSocket
+    );
+    assert!(
+        code(builder.start_spectator_session_with_socket("cpp_host", UniquePtr::null()))
+            == GGRSErrorCode::InvalidSocket
+    );
+    assert!(builder
+        .start_p2p_session_with_socket(new_memory_socket("cpp_null0"))
+        .is_ok());
+}
@@ -0,0 +1,322 @@
+// two peers run a tiny game over the loopback transport, its state is the running sum of all inputs.
+// one of them starts to compute a wrong checksum at some point and both have to notice
+use std::fs;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use cpp_ggrs::wrapper::{
+    GGRSErrorCode, GGRSEvent, GGRSEventType, GGRSFrameActionType, GGRSPlayerType, GGRSSessionState,
+};
+use cpp_ggrs::{new_session_builder, GGRSSession};
+
+mod common;
+use common::{player, tmp_path};
+
+const DRIFT_FRAME: i32 = 40;
+
+struct Peer {
+    sess: Box<GGRSSession>,
+    handle: u32,
+    state: u64,
+    drifts: bool,
+    frames: i32,
+    events: Vec<GGRSEvent>,
+}
+
+impl Peer {
+    fn new(handle: u32, name: &str, remote: &str, interval: u32, drifts: bool) -> Self {
+        Self::with_report_dir(handle, name, remote, interval, drifts, "")
+    }
+
+    fn with_report_dir(
+        handle: u32,
+        name: &str,
+        remote: &str,
+        interval: u32,
+        drifts: bool,
+        report_dir: &str,
+    ) -> Self {
+        let sess = new_session_builder()
+            .with_desync_detection_interval(interval)
+            .unwrap()
+            .with_desync_report_dir(report_dir)
+            .unwrap()
+            .add_player(player(handle, GGRSPlayerType::Local, ""))
+            .unwrap()
+            .add_player(player(1 - handle, GGRSPlayerType::Remote, remote))
+            .unwrap()
+            .start_p2p_session_on_loopback(name)
+            .unwrap();
+        Self {
+            sess,
+            handle,
+            state: 0,
+            drifts,
+            frames: 0,
+            events: Vec::new(),
+        }
+    }
+
+    fn step(&mut self) {
+        self.sess.poll_remote_clients().unwrap();
+        self.events.extend(self.sess.get_events().unwrap());
+        if self.sess.get_current_state().unwrap() != GGRSSessionState::Running {
+            return;
+        }
+        let input = (self.frames as u32 * 7 + self.handle).to_le_bytes();
+        self.sess.add_local_input(self.handle, &input).unwrap();
+        let result = self.sess.advance_frame().unwrap();
+        for action in result.actions {
+            let frame = action.action_info.frame;
+            match action.action_type {
+                GGRSFrameActionType::SaveGameState => {
+                    self.sess
+                        .save_game_state(frame, &self.state.to_le_bytes())
+                        .unwrap();
+                    let mut checksum = self.state;
+                    if self.drifts && frame >= DRIFT_FRAME {
+                        checksum += 1;
+                    }
+                    self.sess.submit_checksum(frame, 0, checksum).unwrap();
+                }
+                GGRSFrameActionType::LoadGameState => {
+                    self.state = u64::from_le_bytes(action.action_info.state.try_into().unwrap());
+                }
+                _ => {
+                    for input in action.action_info.inputs {
+                        self.state += u32::from_le_bytes(input.input.try_into().unwrap()) as u64;
+                    }
+                }
+            }
+        }
+        if !result.skip_frame {
+            self.frames += 1;
+        }
+    }
+
+    fn desyncs(&self) -> Vec<&GGRSEvent> {
+        self.events
+            .iter()
+            .filter(|e| e.event_type == GGRSEventType::DesyncDetected)
+            .collect()
+    }
+}
+
+fn report_dir(name: &str) -> String {
+    let path = tmp_path(name);
+    let _ = fs::remove_dir_all(&path);
+    path
+}
+
+fn run(first: &mut Peer, second: &mut Peer, frames: i32) {
+    let deadline = Instant::now() + Duration::from_secs(10);
+    while first.frames < frames || second.frames < frames {
+        assert!(Instant::now() < deadline, "peers got stuck");
+        first.step();
+        second.step();
+        thread::sleep(Duration::from_millis(1));
+    }
+}
+
+#[test]
+fn peers_detect_a_desync() {
+    let mut first = Peer::new(0, "desync_peer0", "desync_peer1", 5, false);
+    let mut second = Peer::new(1, "desync_peer1", "desync_peer0", 5, true);
+
+    run(&mut first, &mut second, 30);
+    assert!(first.desyncs().is_empty());
+    assert!(second.desyncs().is_empty());
+
+    run(&mut first, &mut second, 80);
+    for (peer, remote, handle) in [(&first, "desync_peer1", 1), (&second, "desync_peer0", 0)] {
+        let desyncs = peer.desyncs();
+        assert!(!desyncs.is_empty(), "{} was not reported", remote);
+        // the first report names the first compared frame the checksums differ at
+        let info = &desyncs[0].event_info;
+        assert_eq!(info.frame, DRIFT_FRAME);
+        assert_eq!(info.addr, remote);
+        assert_eq!(info.player_handles, [handle]);
+        assert_eq!(info.local_checksum_hi, 0);
+        assert_eq!(info.remote_checksum_hi, 0);
+        assert_eq!(info.local_checksum_lo.abs_diff(info.remote_checksum_lo), 1);
+        // every frame is reported once
+        let frames: Vec<_> = desyncs.iter().map(|e| e.event_info.frame).collect();
+        assert!(frames.windows(2).all(|w| w[0] < w[1]));
+        assert!(frames.iter().all(|frame| frame % 5 == 0));
+    }
+}
+
+#[test]
+fn detection_is_off_by_default() {
+    let mut first = Peer::new(0, "desync_off0", "desync_off1", 0, false);
+    let mut second = Peer::new(1, "desync_off1", "desync_off0", 0, true);
+    run(&mut first, &mut second, 80);
+    assert!(first.desyncs().is_empty());
+    assert!(second.desyncs().is_empty());
+
+    // the interval is validated like any other setting
+    assert!(
+        new_session_builder()
+            .try_with_desync_detection_interval(10)
+            .code
+            == GGRSErrorCode::Ok
+    );
+}
+
+#[test]
+fn desyncs_are_dumped() {
+    let dir = report_dir("desync_reports_p2p");
+    let mut first = Peer::with_report_dir(0, "report_peer0", "report_peer1", 5, false, &dir);
+    let mut second = Peer::new(1, "report_peer1", "report_peer0", 5, true);
+    run(&mut first, &mut second, 30);
+    assert_eq!(first.sess.desync_report_path().unwrap(), "");
+
+    run(&mut first, &mut second, 80);
+    // one report for the first desync, the following ones are not dumped
+    let path = first.sess.desync_report_path().unwrap();
+    assert!(path.ends_with(&format!("desync_frame_{}_0", DRIFT_FRAME)));
+    assert_eq!(fs::read_dir(&dir).unwrap().count(), 1);
+
+    let report = fs::read_to_string(format!("{}/report.txt", path)).unwrap();
+    assert!(report.contains("p2p desync"));
+    assert!(report.contains(&format!("frame {}: desync with report_peer1", DRIFT_FRAME)));
+    assert!(report.contains("player 1: remote report_peer1"));
+    assert!(report.contains("desync_detection_interval: 5"));
+    // the recent inputs of both players and their status
+    assert!(report.contains(" confirmed"));
+    let state = fs::read(format!("{}/local_state_{}.bin", path, DRIFT_FRAME)).unwrap();
+    assert_eq!(state.len(), 8);
+}
+
+#[test]
+fn synctest_mismatches_are_dumped() {
+    let dir = report_dir("desync_reports_synctest");
+    let mut sess = new_session_builder()
+        .with_num_players(1)
+        .unwrap()
+        .with_desync_report_dir(&dir)
+        .unwrap()
+        .with_desync_report_frames(4)
+        .unwrap()
+        .add_player(player(0, GGRSPlayerType::Local, ""))
+        .unwrap()
+        .start_synctest_session()
+        .unwrap();
+
+    // every save of a frame gets a different state, the first resimulation notices
+    let mut saves = 0u64;
+    let mut mismatch = None;
+    for frame in 0..10u32 {
+        sess.add_local_input(0, &frame.to_le_bytes()).unwrap();
+        let result = sess.advance_frame().unwrap();
+        if let Some(first) = result.checksum_mismatches.first() {
+            mismatch = Some(first.frame);
+            break;
+        }
+        for action in result.actions {
+            if action.action_type == GGRSFrameActionType::SaveGameState {
+                saves += 1;
+                let frame = action.action_info.frame;
+                sess.save_game_state(frame, &saves.to_le_bytes()).unwrap();
+                sess.submit_checksum(frame, 0, saves).unwrap();
+            }
+        }
+    }
+    let frame = mismatch.expect("the synctest did not notice");
+
+    let path = sess.desync_report_path().unwrap();
+    let report = fs::read_to_string(format!("{}/report.txt", path)).unwrap();
+    assert!(report.contains("synctest mismatch"));
+    assert!(report.contains(&format!("frame {}: resimulation changed the state", frame)));
+    // only the last 4 frames of inputs are kept
+    assert_eq!(
+        report
+            .lines()
+            .filter(|l| l.starts_with("frame ") && l.contains(" confirmed"))
+            .count(),
+        4
+    );
+    let original = fs::read(format!("{}/original_state_{}.bin", path, frame)).unwrap();
+    let resimulated = fs::read(format!("{}/resimulated_state_{}.bin", path, frame)).unwrap();
+    assert_ne!(original, resimulated);
+
+    assert!(
+        new_session_builder().try_with_desync_report_frames(0).code
+            == GGRSErrorCode::InvalidConfiguration
+    );
+}
+
+#[test]
+fn synctest_mismatches_only_ggrs_sees_are_dumped() {
+    let dir = report_dir("desync_reports_synctest_frame");
+    let mut sess = new_session_builder()
+        .with_num_players(1)
+        .unwrap()
+        .with_desync_report_dir(&dir)
+        .unwrap()
+        .add_player(player(0, GGRSPlayerType::Local, ""))
+        .unwrap()
+        .start_synctest_session()
+        .unwrap();
+
+    // frame 4 is resimulated into a different checksum after the session forgot the first one
+    let mut saves_of_4 = 0;
+    let mut failed = None;
+    for frame in 0..10u32 {
+        sess.add_local_input(0, &frame.to_le_bytes()).unwrap();
+        let result = match sess.advance_frame() {
+            Ok(result) => result,
+            Err(err) => {
+                failed = Some(err);
+                break;
+            }
+        };
+        assert!(result.checksum_mismatches.is_empty());
+        for action in result.actions {
+            if action.action_type == GGRSFrameActionType::SaveGameState {
+                let frame = action.action_info.frame;
+                let mut checksum = 0;
+                if frame == 4 {
+                    saves_of_4 += 1;
+                    if saves_of_4 == 2 {
+                        sess.forget_checksums();
+                        checksum = 1;
+                    }
+                }
+                sess.save_game_state(frame, &[checksum as u8]).unwrap();
+                sess.submit_checksum(frame, 0, checksum).unwrap();
+            }
+        }
+    }
+    let err = failed.expect("ggrs did not notice");
+    assert!(err.code() == GGRSErrorCode::MismatchedChecksum);
+    assert!(err.to_string().contains("frame 4"));
+
+    // the report has the frame and its states, but no checksums to show
+    let path = sess.desync_report_path().unwrap();
+    let report = fs::read_to_string(format!("{}/report.txt", path)).unwrap();
+    assert!(report.contains("frame 4: resimulation changed the state"));
+    assert!(report.contains("checksums: unknown"));
+    assert_eq!(
+        fs::read(format!("{}/original_state_4.bin", path)).unwrap(),
+        [0]
+    );
+    assert_eq!(
+        fs::read(format!("{}/resimulated_state_4.bin", path)).unwrap(),
+        [1]
+    );
+}
+
+#[test]
+fn failed_reports_are_reported() {
+    // a file where the report directory should be
+    let dir = report_dir("desync_reports_blocked");
+    fs::write(&dir, b"not a directory").unwrap();
+    let mut first = Peer::with_report_dir(0, "blocked_peer0", "blocked_peer1", 5, false, &dir);
+    let mut second = Peer::new(1, "blocked_peer1", "blocked_peer0", 5, true);
+    run(&mut first, &mut second, 80);
+    assert!(!first.desyncs().is_empty());
+    let err = first.sess.desync_report_path().unwrap_err();
+    assert!(err.code() == GGRSErrorCode::DesyncReportFailed);
+    fs::remove_file(&dir).unwrap();
+}
@@ -0,0 +1,203 @@
+// a c++ event handler of the testing feature records every callback it gets, the sessions run over the
+// loopback transport. names are global to the process, every test uses its own
+use std::thread;
+use std::time::{Duration, Instant};
+
+use cpp_ggrs::testing::{new_recording_handler, take_recorded_calls};
+use cpp_ggrs::wrapper::{GGRSErrorCode, GGRSFrameActionType, GGRSSessionState};
+use cpp_ggrs::{GGRSSession, GGRSSessionBuilder};
+
+mod common;
+use common::{local_input, loopback_peer, step_peer};
+
+// the second peer computes wrong checksums from here on
+const DRIFT_FRAME: i32 = 30;
+
+// every event shows up within a few seconds: desyncs are looked for every 10 frames and a silent remote is
+// interrupted after 100 ms
+fn quick_events(builder: &mut GGRSSessionBuilder) {
+    builder
+        .with_desync_detection_interval(10)
+        .unwrap()
+        .with_disconnect_timeout(600)
+        .unwrap()
+        .with_disconnect_notify_delay(100)
+        .unwrap();
+}
+
+// advances the session if it can. the checksum of a frame is the frame, off by one once a peer drifts.
+// events are left to the event handler
+fn step(sess: &mut GGRSSession, handle: u32, drifts: bool) {
+    let Some(result) = step_peer(sess, &[local_input(handle, &[0; 4])]) else {
+        return;
+    };
+    for action in &result.actions {
+        if action.action_type == GGRSFrameActionType::SaveGameState {
+            let frame = action.action_info.frame;
+            let drift = (drifts && frame >= DRIFT_FRAME) as u64;
+            sess.submit_checksum(frame, 0, frame as u64 + drift)
+                .unwrap();
+        }
+    }
+}
+
+// polls until a line starting with callback was recorded, returns everything recorded meanwhile
+fn wait_for(log: &str, callback: &str, mut poll: impl FnMut()) -> Vec<String> {
+    let mut calls = Vec::new();
+    let deadline = Instant::now() + Duration::from_secs(5);
+    while !calls.iter().any(|call: &String| call.starts_with(callback)) {
+        assert!(Instant::now() < deadline, "{} was never called", callback);
+        poll();
+        calls.extend(take_recorded_calls(log));
+        thread::sleep(Duration::from_millis(1));
+    }
+    calls
+}
+
+#[test]
+fn every_event_reaches_the_handler() {
+    let log = "handler_all";
+    let mut first = loopback_peer(0, "handler_peer0", "handler_peer1", quick_events);
+    let mut second = loopback_peer(1, "handler_peer1", "handler_peer0", quick_events);
+    first
+        .set_event_handler(new_recording_handler(log, ""))
+        .unwrap();
+
+    let calls = wait_for(log, "on_synchronized", || {
+        first.poll_remote_clients().unwrap();
+        second.poll_remote_clients().unwrap();
+        second.get_events().unwrap();
+    });
+    assert_eq!(calls[0], "on_synchronizing handler_peer1 [1] remote 1/5");
+    assert_eq!(
+        calls.last().unwrap(),
+        "on_synchronized handler_peer1 [1] remote"
+    );
+
+    // the first peer runs three frames for every frame of the second one, and the second one drifts
+    let mut calls = Vec::new();
+    let mut iteration = 0;
+    for callback in ["on_wait_recommendation", "on_desync_detected"] {
+        calls.extend(wait_for(log, callback, || {
+            step(&mut first, 0, false);
+            // the desyncs found while advancing wait for the next poll
+            assert!(first.get_events().unwrap().is_empty());
+            if iteration % 3 == 0 {
+                step(&mut second, 1, true);
+                second.get_events().unwrap();
+            }
+            iteration += 1;
+        }));
+    }
+    let wait = calls
+        .iter()
+        .find(|call| call.starts_with("on_wait_recommendation"))
+        .unwrap();
+    assert!(
+        wait["on_wait_recommendation ".len()..]
+            .parse::<u32>()
+            .unwrap()
+            > 0
+    );
+    let desync = calls
+        .iter()
+        .find(|call| call.starts_with("on_desync_detected"))
+        .unwrap();
+    let fields: Vec<&str> = desync.split(' ').collect();
+    let frame: i32 = fields[1].parse().unwrap();
+    assert!(frame >= DRIFT_FRAME);
+    assert_eq!(fields[2..5], ["handler_peer1", "[1]", "remote"]);
+    assert_eq!(fields[5], format!("0:{}", frame));
+    assert_eq!(fields[6], format!("0:{}", frame + 1));
+
+    // the second peer goes silent for a while, then for good
+    let calls = wait_for(log, "on_network_interrupted", || {
+        first.poll_remote_clients().unwrap();
+    });
+    assert!(calls
+        .contains(&"on_network_interrupted handler_peer1 [1] remote 500ms 500000us".to_string()));
+    wait_for(log, "on_network_resumed handler_peer1 [1] remote", || {
+        first.poll_remote_clients().unwrap();
+        second.poll_remote_clients().unwrap();
+        second.get_events().unwrap();
+    });
+    drop(second);
+    wait_for(log, "on_disconnected handler_peer1 [1] remote", || {
+        first.poll_remote_clients().unwrap();
+    });
+}
+
+#[test]
+fn throwing_callbacks_fail_the_poll() {
+    let log = "handler_throwing";
+    let mut first = loopback_peer(0, "handler_throw0", "handler_throw1", quick_events);
+    let mut second = loopback_peer(1, "handler_throw1", "handler_throw0", quick_events);
+    first
+        .set_event_handler(new_recording_handler(log, "on_synchronizing"))
+        .unwrap();
+
+    let mut failures = Vec::new();
+    let calls = wait_for(log, "on_synchronized", || {
+        if let Err(err) = first.poll_remote_clients() {
+            failures.push(err);
+        }
+        second.poll_remote_clients().unwrap();
+        second.get_events().unwrap();
+    });
+    // every synchronizing callback threw, none of them kept the next events from being delivered
+    let synchronizing = calls
+        .iter()
+        .filter(|call| call.starts_with("on_synchronizing"))
+        .count();
+    assert_eq!(synchronizing, 4);
+    assert!(!failures.is_empty());
+    for err in &failures {
+        assert!(err.code() == GGRSErrorCode::EventHandlerFailed);
+        assert!(err.to_string().contains("on_synchronizing failed"));
+    }
+    assert!(first.poll_remote_clients().is_ok());
+    assert!(first.get_current_state().unwrap() == GGRSSessionState::Running);
+}
+
+#[test]
+fn ticks_hand_their_events_to_the_handler() {
+    let log = "handler_tick";
+    let mut first = loopback_peer(0, "handler_tick0", "handler_tick1", quick_events);
+    let mut second = loopback_peer(1, "handler_tick1", "handler_tick0", quick_events);
+    first
+        .set_event_handler(new_recording_handler(log, ""))
+        .unwrap();
+
+    // the first peer only ticks, three times for every frame of the second one
+    let input = local_input(0, &[0; 4]);
+    let mut calls = Vec::new();
+    let mut iteration = 0;
+    for callback in ["on_wait_recommendation", "on_desync_detected"] {
+        calls.extend(wait_for(log, callback, || {
+            let result = first.tick(std::slice::from_ref(&input)).unwrap();
+            assert!(result.events.is_empty());
+            for action in &result.actions {
+                if action.action_type == GGRSFrameActionType::SaveGameState {
+                    let frame = action.action_info.frame;
+                    first.save_game_state(frame, &[0]).unwrap();
+                    first.submit_checksum(frame, 0, frame as u64).unwrap();
+                }
+            }
+            if iteration % 3 == 0 {
+                step(&mut second, 1, true);
+                second.get_events().unwrap();
+            }
+            iteration += 1;
+        }));
+    }
+    // the frame pacer still got the recommendations the handler got, it skips a frame for each
+    let recommended: u32 = calls
+        .iter()
+        .filter_map(|call| call.strip_prefix("on_wait_recommendation "))
+        .map(|skip_frames| skip_frames.parse::<u32>().unwrap())
+        .sum();
+    assert!(recommended > 0);
+    for _ in 0..recommended {
+        assert!(first.frame_pacing().unwrap().skip_frame);
+    }
+}
@@ -0,0 +1,111 @@
+// sessions in the same process talking over the in-memory loopback transport, no ports involved.
+// names are global to the process, every test uses its own
+use cpp_ggrs::wrapper::{
+    GGRSErrorCode, GGRSErrorInfo, GGRSFrameActionType, GGRSLocalInput, GGRSPlayerType,
+};
+use cpp_ggrs::{new_session_builder, GGRSSession};
+
+mod common;
+use common::{code, local_input, loopback_peer, peer_builder, player, step_peer};
+
+// the inputs of the frame the session simulated, if it simulated one
+fn step(sess: &mut GGRSSession, local_inputs: &[GGRSLocalInput]) -> Option<Vec<Vec<u8>>> {
+    let result = step_peer(sess, local_inputs)?;
+    let advanced = result
+        .actions
+        .iter()
+        .rfind(|action| action.action_type == GGRSFrameActionType::AdvanceFrame)?;
+    Some(
+        advanced
+            .action_info
+            .inputs
+            .iter()
+            .map(|i| i.input.clone())
+            .collect(),
+    )
+}
+
+#[test]
+fn peers_and_spectator_over_loopback() {
+    let mut first = loopback_peer(0, "lb_peer0", "lb_peer1", |builder| {
+        builder
+            .add_player(player(2, GGRSPlayerType::Spectator, "lb_spectator"))
+            .unwrap();
+    });
+    let mut second = loopback_peer(1, "lb_peer1", "lb_peer0", |_| {});
+    let mut spectator = new_session_builder()
+        .start_spectator_session_on_loopback("lb_spectator", "lb_peer0")
+        .unwrap();
+
+    let (mut first_frames, mut second_frames) = (0u32, 0u32);
+    let mut watched = Vec::new();
+    for _ in 0..2000 {
+        if first_frames >= 60 && second_frames >= 60 && watched.len() >= 30 {
+            break;
+        }
+        if step(&mut first, &[local_input(0, &first_frames.to_le_bytes())]).is_some() {
+            first_frames += 1;
+        }
+        if step(
+            &mut second,
+            &[local_input(1, &(second_frames * 2).to_le_bytes())],
+        )
+        .is_some()
+        {
+            second_frames += 1;
+        }
+        if let Some(inputs) = step(&mut spectator, &[]) {
+            watched.push(inputs);
+        }
+        std::thread::sleep(std::time::Duration::from_millis(1));
+    }
+    assert!(first_frames >= 60 && second_frames >= 60);
+    assert!(watched.len() >= 30);
+    // the spectator only ever sees confirmed inputs, so they are exactly what both peers entered
+    for (frame, inputs) in watched.iter().enumerate() {
+        let frame = frame as u32;
+        assert_eq!(inputs[0], frame.to_le_bytes());
+        assert_eq!(inputs[1], (frame * 2).to_le_bytes());
+    }
+}
+
+#[test]
+fn loopback_names_are_exclusive() {
+    let start =
+        |name: &str| peer_builder(0, "lb_nobody", |_| {}).start_p2p_session_on_loopback(name);
+    let first = start("lb_taken").unwrap();
+    assert!(matches!(start("lb_taken"), Err(err) if err.to_string().contains("lb_taken")));
+
+    let mut error = GGRSErrorInfo {
+        code: GGRSErrorCode::Ok,
+        message: String::new(),
+    };
+    new_session_builder().try_start_p2p_session_on_loopback("lb_taken", &mut error);
+    assert!(error.code == GGRSErrorCode::BindFailed);
+    new_session_builder().try_start_p2p_session_on_loopback("", &mut error);
+    assert!(error.code == GGRSErrorCode::BindFailed);
+
+    // the name is free again once its session is gone
+    drop(first);
+    assert!(start("lb_taken").is_ok());
+}
+
+#[test]
+fn cleaned_sessions_free_their_name() {
+    let start =
+        |name: &str| peer_builder(0, "lb_nobody", |_| {}).start_p2p_session_on_loopback(name);
+    let mut first = start("lb_cleaned").unwrap();
+    assert!(first.clean_session());
+    // the box is still around but the socket is not
+    let second = start("lb_cleaned").unwrap();
+    assert!(code(first.poll_remote_clients()) == GGRSErrorCode::SessionClosed);
+    assert!(code(first.add_local_input(0, &[0; 4])) == GGRSErrorCode::SessionClosed);
+    assert!(code(first.advance_frame()) == GGRSErrorCode::SessionClosed);
+    assert!(code(first.local_address()) == GGRSErrorCode::SessionClosed);
+
+    // dropping the cleaned box leaves the new owner of the name alone
+    drop(first);
+    assert!(start("lb_cleaned").is_err());
+    drop(second);
+    assert!(start("lb_cleaned").is_ok());
+}
@@ -0,0 +1,122 @@
+// two local sessions talking over a simulated 150 ms round trip link with 5% packet loss
+use std::net::UdpSocket;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use cpp_ggrs::wrapper::{GGRSErrorCode, GGRSNetworkConditions, GGRSSessionState};
+use cpp_ggrs::{new_session_builder, GGRSReplay, GGRSSession};
+
+mod common;
+use common::{local_input, peer_builder, step_peer, tmp_path};
+
+fn bad_link(seed: u64) -> GGRSNetworkConditions {
+    GGRSNetworkConditions {
+        latency_ms: 75,
+        jitter_ms: 0,
+        packet_loss: 0.05,
+        duplication: 0.0,
+        reordering: 0.0,
+        seed,
+    }
+}
+
+fn session(local: u32, local_port: u16, remote_addr: &str, seed: u64) -> Box<GGRSSession> {
+    peer_builder(local, remote_addr, |builder| {
+        builder
+            .with_network_conditions(bad_link(seed))
+            .unwrap()
+            .with_bind_address("127.0.0.1")
+            .unwrap();
+    })
+    .start_p2p_session(local_port)
+    .unwrap()
+}
+
+// advances the session if it can, returns whether a frame was simulated
+fn step(sess: &mut GGRSSession, handle: u32, frame: u32) -> bool {
+    let input = (frame * (handle + 1)).to_le_bytes();
+    let simulated =
+        step_peer(sess, &[local_input(handle, &input)]).is_some_and(|result| !result.skip_frame);
+    sess.get_events().unwrap();
+    simulated
+}
+
+#[test]
+fn sessions_stay_in_sync_over_a_bad_link() {
+    // the first session has to know where the second one will be. it gets a port the os handed out,
+    // which is given back right before the second session binds it
+    let reserved = UdpSocket::bind("127.0.0.1:0").unwrap();
+    let second_addr = reserved.local_addr().unwrap();
+    let mut first = session(0, 0, &second_addr.to_string(), 1);
+    let first_addr = first.local_address().unwrap();
+    drop(reserved);
+    let mut second = session(1, second_addr.port(), &first_addr, 2);
+    let (first_path, second_path) = (tmp_path("link_0.ggrsreplay"), tmp_path("link_1.ggrsreplay"));
+    first.start_recording(&first_path).unwrap();
+    second.start_recording(&second_path).unwrap();
+
+    // ggrs only resends a lost sync request after 200 ms without sending anything, and the answers to
+    // a peer that is already running keep it busy. losing the last sync packet can take seconds
+    let deadline = Instant::now() + Duration::from_secs(60);
+    while first.get_current_state().unwrap() != GGRSSessionState::Running
+        || second.get_current_state().unwrap() != GGRSSessionState::Running
+    {
+        assert!(Instant::now() < deadline, "sessions did not synchronize");
+        for sess in [&mut first, &mut second] {
+            sess.poll_remote_clients().unwrap();
+            sess.get_events().unwrap();
+        }
+        thread::sleep(Duration::from_millis(16));
+    }
+
+    let deadline = Instant::now() + Duration::from_secs(20);
+    let (mut first_frames, mut second_frames) = (0, 0);
+    while first_frames < 120 || second_frames < 120 {
+        assert!(
+            Instant::now() < deadline,
+            "sessions did not get through 120 frames"
+        );
+        if step(&mut first, 0, first_frames) {
+            first_frames += 1;
+        }
+        if step(&mut second, 1, second_frames) {
+            second_frames += 1;
+        }
+        thread::sleep(Duration::from_millis(16));
+    }
+
+    // the simulated latency shows up on both ends
+    assert!(first.network_stats(1).unwrap().ping.ms >= 140);
+    assert!(second.network_stats(0).unwrap().ping.ms >= 140);
+
+    first.stop_recording().unwrap();
+    second.stop_recording().unwrap();
+    let first = GGRSReplay::read_from(&first_path).unwrap();
+    let second = GGRSReplay::read_from(&second_path).unwrap();
+    let shared = first.frames().len().min(second.frames().len());
+    assert!(shared > 60);
+    // both sides agree on every input they confirmed
+    assert_eq!(
+        first
+            .first_divergence(&second)
+            .map_or(shared, |f| f as usize),
+        shared
+    );
+}
+
+#[test]
+fn chances_are_validated() {
+    let mut builder = new_session_builder();
+    let mut conditions = bad_link(0);
+    conditions.packet_loss = 1.5;
+    assert!(
+        builder.try_with_network_conditions(conditions).code == GGRSErrorCode::InvalidConfiguration
+    );
+    conditions.packet_loss = f32::NAN;
+    assert!(builder.with_network_conditions(conditions).is_err());
+    conditions.packet_loss = 0.05;
+    conditions.reordering = -0.1;
+    assert!(builder.with_network_conditions(conditions).is_err());
+    conditions.reordering = 1.0;
+    assert!(builder.with_network_conditions(conditions).is_ok());
+}
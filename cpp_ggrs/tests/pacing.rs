@@ -0,0 +1,136 @@
+// the frame pacer of a session: its clock, and how it reacts to a peer that runs ahead of the other one
+use std::thread;
+use std::time::{Duration, Instant};
+
+use cpp_ggrs::wrapper::{GGRSFramePacing, GGRSPacingMode, GGRSPlayerType};
+use cpp_ggrs::{new_session_builder, GGRSFramePacer, GGRSSession};
+
+mod common;
+use common::{local_input, loopback_peer, player, step_peer};
+
+fn peer(local: u32, name: &str, remote: &str, mode: GGRSPacingMode) -> Box<GGRSSession> {
+    loopback_peer(local, name, remote, |builder| {
+        builder.with_pacing_mode(mode).unwrap();
+    })
+}
+
+// advances the session if it can. the pacer hears of wait recommendations through the events
+fn step(sess: &mut GGRSSession, handle: u32) {
+    step_peer(sess, &[local_input(handle, &[0; 4])]);
+    sess.get_events().unwrap();
+}
+
+// the first peer simulates three frames for every frame of the second one and follows its pacer,
+// returns the pacing of every frame of the first peer
+fn run_ahead(first_name: &str, second_name: &str, mode: GGRSPacingMode) -> Vec<GGRSFramePacing> {
+    let mut first = peer(0, first_name, second_name, mode);
+    let mut second = peer(1, second_name, first_name, GGRSPacingMode::FixedStep);
+    let mut pacings = Vec::new();
+    for iteration in 0..600 {
+        let pacing = first.frame_pacing().unwrap();
+        let skip = pacing.skip_frame;
+        pacings.push(pacing);
+        if skip {
+            first.poll_remote_clients().unwrap();
+        } else {
+            step(&mut first, 0);
+        }
+        if iteration % 3 == 0 {
+            step(&mut second, 1);
+        } else {
+            second.poll_remote_clients().unwrap();
+        }
+        thread::sleep(Duration::from_millis(1));
+    }
+    pacings
+}
+
+#[test]
+fn pacer_keeps_the_frame_clock() {
+    // the frames start at made up times, milliseconds after start
+    let start = Instant::now();
+    let at = |ms: u64| start + Duration::from_millis(ms);
+    let mut pacer = GGRSFramePacer::new(GGRSPacingMode::VariableRefresh, 50);
+
+    // the first frame runs right away, the next one is due a frame later
+    let pacing = pacer.pace(0, at(0));
+    assert!(!pacing.skip_frame);
+    assert_eq!(pacing.sleep.us, 0);
+    assert_eq!(pacing.frame_duration.us, 20_000);
+    assert_eq!(pacing.frames_ahead, 0);
+    let pacing = pacer.pace(0, at(3));
+    assert_eq!((pacing.sleep.ms, pacing.sleep.us), (17, 17_000));
+
+    // a loop that slept as told is on time again. after a long hitch it starts over instead of rushing
+    assert_eq!(pacer.pace(0, at(25)).sleep.us, 15_000);
+    assert_eq!(pacer.pace(0, at(100)).sleep.us, 0);
+    assert_eq!(pacer.pace(0, at(104)).sleep.us, 16_000);
+
+    // the session runs the same pacer on the real clock
+    let mut sess = new_session_builder()
+        .with_fps(50)
+        .unwrap()
+        .add_player(player(0, GGRSPlayerType::Local, ""))
+        .unwrap()
+        .start_synctest_session()
+        .unwrap();
+    let pacing = sess.frame_pacing().unwrap();
+    assert_eq!((pacing.sleep.us, pacing.frame_duration.us), (0, 20_000));
+    sess.clean_session();
+    assert!(sess.frame_pacing().is_err());
+}
+
+#[test]
+fn fixed_step_backs_off_while_ahead() {
+    let start = Instant::now();
+    let mut pacer = GGRSFramePacer::new(GGRSPacingMode::FixedStep, 60);
+    let mut frame = 0;
+    let mut pace = |frames_ahead: i32| {
+        frame += 1;
+        let pacing = pacer.pace(frames_ahead, start + Duration::from_micros(16_667 * frame));
+        assert_eq!(pacing.frame_duration.us, 16_666);
+        pacing.skip_frame
+    };
+
+    // in step with the peers nothing is skipped
+    assert!((0..30).all(|_| !pace(0)));
+    // ahead without a recommendation, every frame is a tenth slower until a whole one can be skipped
+    let skipped: Vec<_> = (0..25).filter(|_| pace(2)).collect();
+    assert_eq!(skipped, [10, 20]);
+}
+
+#[test]
+fn fixed_step_waits_out_recommendations_first() {
+    let start = Instant::now();
+    let mut pacer = GGRSFramePacer::new(GGRSPacingMode::FixedStep, 60);
+    pacer.wait_recommendation(3);
+    let skipped: Vec<_> = (0..10)
+        .map(|frame| {
+            pacer
+                .pace(0, start + Duration::from_millis(16 * frame))
+                .skip_frame
+        })
+        .collect();
+    assert_eq!(skipped[..4], [true, true, true, false]);
+    assert!(skipped[4..].iter().all(|skip| !skip));
+}
+
+#[test]
+fn fixed_step_skips_recommended_frames() {
+    let pacings = run_ahead("pace_fixed0", "pace_fixed1", GGRSPacingMode::FixedStep);
+    let skipped: Vec<_> = pacings.iter().filter(|p| p.skip_frame).collect();
+    assert!(!skipped.is_empty(), "the peer ahead never waited");
+    assert!(skipped.iter().all(|p| p.frame_duration.us == 16_666));
+}
+
+#[test]
+fn variable_refresh_stretches_frames() {
+    let pacings = run_ahead("pace_vrr0", "pace_vrr1", GGRSPacingMode::VariableRefresh);
+    // never a skipped frame, the frames of the peer ahead get longer instead
+    assert!(pacings.iter().all(|p| !p.skip_frame));
+    let slowed = pacings
+        .iter()
+        .find(|p| p.frames_ahead > 0)
+        .expect("the peer never got ahead");
+    assert_eq!(slowed.frame_duration.us, 18_333);
+}
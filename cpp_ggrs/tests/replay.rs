@@ -0,0 +1,316 @@
+// recorded sessions played back through a replay session, and the replay files they leave behind
+use std::fs;
+use std::process::Command;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use cpp_ggrs::wrapper::{GGRSErrorCode, GGRSFrameActionType, GGRSInputStatus, GGRSSessionState};
+use cpp_ggrs::{start_replay_session, GGRSReplay, GGRSSession, GGRSSessionBuilder};
+
+mod common;
+use common::{
+    code, error, local_input, loopback_peer, run_synctest_frame, step_peer, synctest_session,
+    tmp_path,
+};
+
+// the frame doubles as input, a byte per player is enough
+fn two_byte_inputs(builder: &mut GGRSSessionBuilder) {
+    builder.with_input_size(2).unwrap();
+}
+
+#[test]
+fn synctest_recording_plays_back() {
+    let path = tmp_path("synctest.ggrsreplay");
+    let mut sess = synctest_session(two_byte_inputs);
+    sess.start_recording(&path).unwrap();
+    let mut recorded = Vec::new();
+    for frame in 0..20 {
+        recorded.push(run_synctest_frame(&mut sess, [&[frame, 0], &[frame, 1]]));
+    }
+    sess.stop_recording().unwrap();
+
+    let mut replay = start_replay_session(&path).unwrap();
+    for (frame, expected) in recorded.iter().enumerate() {
+        // local input is ignored while replaying
+        replay.add_local_input(0, &[0xff, 0xff]).unwrap();
+        let result = replay.advance_frame().unwrap();
+        assert_eq!(result.actions.len(), 1);
+        let action = &result.actions[0];
+        assert!(action.action_type == GGRSFrameActionType::AdvanceFrame);
+        assert_eq!(action.action_info.frame, frame as i32);
+        let inputs: Vec<Vec<u8>> = action
+            .action_info
+            .inputs
+            .iter()
+            .map(|i| i.input.clone())
+            .collect();
+        assert_eq!(&inputs, expected);
+    }
+    assert!(
+        replay.try_advance_frame(&mut Default::default()).code == GGRSErrorCode::ReplayFinished
+    );
+    assert!(replay
+        .start_recording(&tmp_path("nested.ggrsreplay"))
+        .is_err());
+}
+
+#[test]
+fn replays_tick_to_the_end() {
+    let path = record_synctest("tick.ggrsreplay", 10, |frame| frame);
+    let mut replay = start_replay_session(&path).unwrap();
+    for frame in 0..10 {
+        // the recorded players were local, the replay doesn't want their input anymore
+        let result = replay.tick(&[]).unwrap();
+        assert!(result.state == GGRSSessionState::Running);
+        assert!(result.events.is_empty());
+        assert_eq!(result.actions.len(), 1);
+        let info = &result.actions[0].action_info;
+        assert_eq!(info.frame, frame);
+        assert_eq!(info.inputs[0].input, [frame as u8, 0]);
+        assert_eq!(info.inputs[1].input, [frame as u8, 1]);
+    }
+    assert!(code(replay.tick(&[])) == GGRSErrorCode::ReplayFinished);
+}
+
+#[test]
+fn recording_rules() {
+    let mut sess = synctest_session(two_byte_inputs);
+    assert!(error(sess.stop_recording()).contains("not recording"));
+    assert!(sess.start_recording("/nonexistent/dir/replay").is_err());
+
+    let path = tmp_path("rules.ggrsreplay");
+    sess.start_recording(&path).unwrap();
+    assert!(sess.try_start_recording(&path).code == GGRSErrorCode::UnsupportedOperation);
+    run_synctest_frame(&mut sess, [&[0, 0], &[0, 1]]);
+    sess.stop_recording().unwrap();
+    // too late to start over once frames have been advanced
+    assert!(sess.start_recording(&path).is_err());
+}
+
+#[test]
+fn broken_replay_files_are_rejected() {
+    let path = tmp_path("broken.ggrsreplay");
+    let mut sess = synctest_session(two_byte_inputs);
+    sess.start_recording(&path).unwrap();
+    for frame in 0..5 {
+        run_synctest_frame(&mut sess, [&[frame, 0], &[frame, 1]]);
+    }
+    sess.stop_recording().unwrap();
+    let valid = fs::read(&path).unwrap();
+
+    let missing = tmp_path("missing.ggrsreplay");
+    assert!(replay_error_code(&missing) == GGRSErrorCode::ReplayIoFailed);
+
+    fs::write(&path, &valid[..valid.len() - 1]).unwrap();
+    assert!(replay_error_code(&path) == GGRSErrorCode::InvalidReplay);
+
+    let mut wrong_version = valid.clone();
+    wrong_version[8] = 99;
+    fs::write(&path, &wrong_version).unwrap();
+    let err = start_replay_session(&path).err().unwrap().to_string();
+    assert!(err.contains("unsupported replay version 99"));
+
+    fs::write(&path, b"definitely not a replay").unwrap();
+    assert!(replay_error_code(&path) == GGRSErrorCode::InvalidReplay);
+
+    // crafted version 1 headers that promise far more frames than the file holds
+    let crafted = |num_players: u32| {
+        let mut data = b"GGRSRPLY".to_vec();
+        data.extend_from_slice(&1u16.to_le_bytes());
+        for value in [num_players, 60, 0, 2, 8, 10, 1, 0] {
+            data.extend_from_slice(&value.to_le_bytes());
+        }
+        data.extend_from_slice(&2000u64.to_le_bytes());
+        data.extend_from_slice(&500u64.to_le_bytes());
+        data.push(0);
+        // no player entries, then the frame count
+        data.extend_from_slice(&0u32.to_le_bytes());
+        data.extend_from_slice(&u32::MAX.to_le_bytes());
+        data.extend_from_slice(&[1, 0, 0]);
+        data
+    };
+    fs::write(&path, crafted(0)).unwrap();
+    let err = start_replay_session(&path).err().unwrap();
+    assert!(err.code() == GGRSErrorCode::InvalidReplay);
+    assert!(err.to_string().contains("at least one player"));
+    fs::write(&path, crafted(1)).unwrap();
+    let err = start_replay_session(&path).err().unwrap();
+    assert!(err.code() == GGRSErrorCode::InvalidReplay);
+    assert!(err.to_string().contains("declares 4294967295 frames"));
+}
+
+fn replay_error_code(path: &str) -> GGRSErrorCode {
+    match start_replay_session(path) {
+        Ok(_) => GGRSErrorCode::Ok,
+        Err(err) => err.code(),
+    }
+}
+
+fn record_synctest(name: &str, frames: u8, input_of: impl Fn(u8) -> u8) -> String {
+    let path = tmp_path(name);
+    let mut sess = synctest_session(two_byte_inputs);
+    sess.start_recording(&path).unwrap();
+    for frame in 0..frames {
+        run_synctest_frame(&mut sess, [&[input_of(frame), 0], &[input_of(frame), 1]]);
+    }
+    sess.stop_recording().unwrap();
+    path
+}
+
+#[test]
+fn checksums_are_recorded() {
+    let path = record_synctest("checksums.ggrsreplay", 10, |frame| frame);
+    let replay = GGRSReplay::read_from(&path).unwrap();
+    assert_eq!(replay.frames().len(), 10);
+    assert_eq!(replay.num_players(), 2);
+    assert_eq!(replay.input_size(), 2);
+    for frame in 0..10 {
+        assert_eq!(replay.checksum(frame), Some((1 << 64) | frame as u128));
+    }
+    assert!(replay.first_divergence(&replay).is_none());
+}
+
+#[test]
+fn diverging_replays_name_the_frame() {
+    let first = record_synctest("first.ggrsreplay", 12, |frame| frame);
+    let second = record_synctest(
+        "second.ggrsreplay",
+        12,
+        |frame| if frame == 7 { 0 } else { frame },
+    );
+    let shorter = record_synctest("shorter.ggrsreplay", 9, |frame| frame);
+    let first = GGRSReplay::read_from(&first).unwrap();
+    assert_eq!(
+        first.first_divergence(&GGRSReplay::read_from(&second).unwrap()),
+        Some(7)
+    );
+    assert_eq!(
+        first.first_divergence(&GGRSReplay::read_from(&shorter).unwrap()),
+        Some(9)
+    );
+}
+
+// returns whether a frame was simulated
+fn step(sess: &mut GGRSSession, handle: u32, input: [u8; 2]) -> bool {
+    step_peer(sess, &[local_input(handle, &input)]).is_some_and(|result| !result.skip_frame)
+}
+
+#[test]
+fn recording_at_the_prediction_threshold_is_confirmed() {
+    let path = tmp_path("threshold.ggrsreplay");
+    let mut first = loopback_peer(0, "replay_threshold0", "replay_threshold1", two_byte_inputs);
+    let mut second = loopback_peer(1, "replay_threshold1", "replay_threshold0", two_byte_inputs);
+    let deadline = Instant::now() + Duration::from_secs(5);
+    while first.get_current_state().unwrap() != GGRSSessionState::Running
+        || second.get_current_state().unwrap() != GGRSSessionState::Running
+    {
+        assert!(Instant::now() < deadline, "peers did not synchronize");
+        for sess in [&mut first, &mut second] {
+            sess.poll_remote_clients().unwrap();
+            sess.get_events().unwrap();
+        }
+        thread::sleep(Duration::from_millis(1));
+    }
+    first.start_recording(&path).unwrap();
+
+    // the second peer stalls, the first one runs into the prediction threshold
+    let mut first_frame = 0u8;
+    let mut skipped = false;
+    for _ in 0..20 {
+        second.poll_remote_clients().unwrap();
+        match step(&mut first, 0, [first_frame, 0]) {
+            true => first_frame += 1,
+            false => skipped = true,
+        }
+    }
+    assert!(skipped);
+    // blank input is what ggrs predicts, so none of the predicted frames gets resimulated
+    let mut second_frame = 0;
+    while first_frame < 30 || second_frame < 30 {
+        assert!(Instant::now() < deadline, "peers did not catch up");
+        if first_frame < 30 && step(&mut first, 0, [first_frame, 0]) {
+            first_frame += 1;
+        }
+        if second_frame < 30 && step(&mut second, 1, [0, 0]) {
+            second_frame += 1;
+        }
+        thread::sleep(Duration::from_millis(1));
+    }
+    for _ in 0..20 {
+        first.poll_remote_clients().unwrap();
+        second.poll_remote_clients().unwrap();
+        thread::sleep(Duration::from_millis(1));
+    }
+    step(&mut first, 0, [first_frame, 0]);
+    first.stop_recording().unwrap();
+
+    // every frame that made it into the file is confirmed, predictions that turned out right included
+    let replay = GGRSReplay::read_from(&path).unwrap();
+    assert!(replay.frames().len() >= 20);
+    for (frame, inputs) in replay.frames().iter().enumerate() {
+        assert!(inputs
+            .iter()
+            .all(|input| input.status == GGRSInputStatus::Confirmed));
+        assert_eq!(inputs[0].input, [frame as u8, 0]);
+        assert_eq!(inputs[1].input, [0, 0]);
+    }
+}
+
+#[test]
+fn replay_tool_reports_divergence() {
+    let first = record_synctest("tool_first.ggrsreplay", 12, |frame| frame);
+    let second = record_synctest("tool_second.ggrsreplay", 12, |frame| {
+        if frame == 4 {
+            0
+        } else {
+            frame
+        }
+    });
+    let tool = env!("CARGO_BIN_EXE_ggrs_replay");
+
+    let info = Command::new(tool).args(["info", &first]).output().unwrap();
+    assert!(info.status.success());
+    let stdout = String::from_utf8(info.stdout).unwrap();
+    assert!(stdout.contains("frames: 12"));
+    assert!(stdout.contains("checksums: 12"));
+    assert!(stdout.contains("0b01 x1"));
+
+    let diff = Command::new(tool)
+        .args(["diff", &first, &second])
+        .output()
+        .unwrap();
+    assert_eq!(diff.status.code(), Some(1));
+    let stdout = String::from_utf8(diff.stdout).unwrap();
+    assert!(stdout.contains("first divergence at frame 4"));
+    assert!(stdout.contains("player 0: 0400 vs 0000"));
+
+    let same = Command::new(tool)
+        .args(["diff", &first, &first])
+        .output()
+        .unwrap();
+    assert_eq!(same.status.code(), Some(0));
+
+    // the same diverging input, recorded by a game that never submits checksums
+    let unchecked = tmp_path("tool_unchecked.ggrsreplay");
+    let mut sess = synctest_session(two_byte_inputs);
+    sess.start_recording(&unchecked).unwrap();
+    for frame in 0..12u8 {
+        let input = if frame == 4 { 0 } else { frame };
+        sess.add_local_input(0, &[input, 0]).unwrap();
+        sess.add_local_input(1, &[input, 1]).unwrap();
+        for action in sess.advance_frame().unwrap().actions {
+            if action.action_type == GGRSFrameActionType::SaveGameState {
+                sess.save_game_state(action.action_info.frame, &[0])
+                    .unwrap();
+            }
+        }
+    }
+    sess.stop_recording().unwrap();
+    let diff = Command::new(tool)
+        .args(["diff", &first, &unchecked])
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8(diff.stdout).unwrap();
+    assert!(stdout.contains("first divergence at frame 4"));
+    assert!(stdout.contains("checksum: 00000000000000010000000000000004 vs none"));
+}
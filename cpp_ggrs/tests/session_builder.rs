@@ -0,0 +1,137 @@
+// every builder call is validated on its own, a rejected call leaves the configuration as it was
+
+use cpp_ggrs::new_session_builder;
+use cpp_ggrs::wrapper::{GGRSErrorCode, GGRSPlayerType};
+
+mod common;
+use common::{code, error, player};
+
+const INVALID: GGRSErrorCode = GGRSErrorCode::InvalidConfiguration;
+
+#[test]
+fn invalid_values_are_rejected_at_the_call() {
+    let mut builder = new_session_builder();
+    assert!(error(builder.with_num_players(0)).contains("at least one player"));
+    assert!(code(builder.with_num_players(0)) == INVALID);
+    assert!(error(builder.with_input_size(0)).contains("input size"));
+    assert!(code(builder.with_input_size(65)) == INVALID);
+    assert!(error(builder.with_fps(0)).contains("FPS"));
+    assert!(code(builder.with_fps(0)) == INVALID);
+    assert!(code(builder.with_max_frames_behind(0)) == INVALID);
+    assert!(code(builder.with_catchup_speed(0)) == INVALID);
+    // catchup speed has to stay below max frames behind
+    assert!(code(builder.with_catchup_speed(10)) == INVALID);
+    builder
+        .with_max_frames_behind(20)
+        .unwrap()
+        .with_catchup_speed(10)
+        .unwrap();
+    assert!(code(builder.with_max_frames_behind(10)) == INVALID);
+}
+
+#[test]
+fn input_delay_fits_the_input_queue() {
+    let mut builder = new_session_builder();
+    // ggrs keeps 128 frames of input per player
+    assert!(error(builder.with_input_delay(128)).contains("below 128 frames"));
+    assert!(code(builder.with_input_delay(u32::MAX)) == INVALID);
+    assert!(builder.try_with_input_delay(1000).code == INVALID);
+    builder
+        .with_input_delay(127)
+        .unwrap()
+        .add_player(player(0, GGRSPlayerType::Local, ""))
+        .unwrap()
+        .add_player(player(1, GGRSPlayerType::Local, ""))
+        .unwrap();
+
+    // the longest delay still runs, the first frames get blank inputs
+    let mut sess = builder.start_synctest_session().unwrap();
+    sess.add_local_input(0, &[1, 0, 0, 0]).unwrap();
+    sess.add_local_input(1, &[2, 0, 0, 0]).unwrap();
+    assert!(sess.advance_frame().is_ok());
+}
+
+#[test]
+fn players_are_checked_against_the_player_count() {
+    let mut builder = new_session_builder();
+    builder
+        .with_num_players(3)
+        .unwrap()
+        .add_player(player(0, GGRSPlayerType::Local, ""))
+        .unwrap()
+        .add_player(player(2, GGRSPlayerType::Remote, "127.0.0.1:9"))
+        .unwrap();
+
+    assert!(error(builder.add_player(player(0, GGRSPlayerType::Local, ""))).contains("in use"));
+    assert!(code(builder.add_player(player(0, GGRSPlayerType::Local, ""))) == INVALID);
+    assert!(code(builder.add_player(player(3, GGRSPlayerType::Remote, "127.0.0.1:9"))) == INVALID);
+    assert!(
+        code(builder.add_player(player(1, GGRSPlayerType::Spectator, "127.0.0.1:9"))) == INVALID
+    );
+    assert!(builder
+        .add_player(player(1, GGRSPlayerType::Remote, ""))
+        .is_err());
+    // player 2 would no longer fit
+    assert!(code(builder.with_num_players(2)) == INVALID);
+
+    // the rejected calls did not change anything, the remaining player still fits
+    builder
+        .add_player(player(1, GGRSPlayerType::Remote, "127.0.0.1:9"))
+        .unwrap()
+        .add_player(player(3, GGRSPlayerType::Spectator, "127.0.0.1:10"))
+        .unwrap();
+    assert!(builder.start_p2p_session(0).is_ok());
+}
+
+#[test]
+fn start_reports_missing_setup() {
+    let mut builder = new_session_builder();
+    builder
+        .add_player(player(0, GGRSPlayerType::Local, ""))
+        .unwrap();
+    assert!(error(builder.start_p2p_session(0)).contains("Not enough players"));
+    assert!(code(builder.start_p2p_session(0)) == INVALID);
+
+    builder
+        .with_check_distance(8)
+        .unwrap()
+        .add_player(player(1, GGRSPlayerType::Local, ""))
+        .unwrap();
+    assert!(error(builder.start_synctest_session()).contains("Check distance"));
+    assert!(code(builder.start_synctest_session()) == INVALID);
+    builder.with_check_distance(2).unwrap();
+    assert!(builder.start_synctest_session().is_ok());
+
+    assert!(builder.start_spectator_session(0, "nowhere").is_err());
+    assert!(builder.try_with_fps(0).code == INVALID);
+    assert!(builder.try_with_input_size(0).code == INVALID);
+}
+
+#[test]
+fn disconnect_notify_delay_stays_below_timeout() {
+    let mut builder = new_session_builder();
+    builder
+        .add_player(player(0, GGRSPlayerType::Local, ""))
+        .unwrap()
+        .add_player(player(1, GGRSPlayerType::Remote, "127.0.0.1:9"))
+        .unwrap();
+    // the pair is checked when the session starts, raising both works in either order
+    builder
+        .with_disconnect_notify_delay(3000)
+        .unwrap()
+        .with_disconnect_timeout(10_000)
+        .unwrap();
+    assert!(builder.start_p2p_session(0).is_ok());
+    builder
+        .with_disconnect_timeout(500)
+        .unwrap()
+        .with_disconnect_notify_delay(200)
+        .unwrap();
+    assert!(builder.start_p2p_session(0).is_ok());
+
+    assert!(builder.try_with_disconnect_timeout(200).code == GGRSErrorCode::Ok);
+    assert!(error(builder.start_p2p_session(0)).contains("notify delay"));
+    assert!(code(builder.start_p2p_session(0)) == INVALID);
+    builder.with_disconnect_notify_delay(199).unwrap();
+    assert!(builder.start_p2p_session(0).is_ok());
+}
@@ -0,0 +1,257 @@
+// every call below fails on purpose and the session has to stay usable afterwards, unless ggrs panicked
+use std::net::UdpSocket;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use cpp_ggrs::wrapper::{
+    GGRSErrorCode, GGRSErrorInfo, GGRSFrameResult, GGRSPlayerType, GGRSSessionState,
+};
+use cpp_ggrs::{new_session_builder, GGRSSession};
+
+mod common;
+use common::{code, error, local_input, player, run_synctest_frame, synctest_session};
+
+// the remote never answers, so the session stays synchronizing forever
+fn unsynchronized_p2p_session() -> Box<GGRSSession> {
+    new_session_builder()
+        .add_player(player(0, GGRSPlayerType::Local, ""))
+        .unwrap()
+        .add_player(player(1, GGRSPlayerType::Remote, "127.0.0.1:9"))
+        .unwrap()
+        .start_p2p_session(0)
+        .unwrap()
+}
+
+#[test]
+fn p2p_advance_frame_error_keeps_session() {
+    let mut sess = unsynchronized_p2p_session();
+
+    assert!(sess.advance_frame().is_err());
+    assert!(sess.advance_frame().is_err());
+
+    assert!(sess.poll_remote_clients().unwrap());
+    assert!(sess.get_events().is_ok());
+    assert!(sess.get_current_state().unwrap() == GGRSSessionState::Synchronizing);
+    assert_eq!(sess.get_frames_ahead().unwrap(), 0);
+}
+
+#[test]
+fn p2p_error_paths_keep_session() {
+    let mut sess = unsynchronized_p2p_session();
+
+    let err = error(sess.add_local_input(1, &[0, 0, 0, 0]));
+    assert!(err.contains("not referring to a local player"));
+    assert!(sess.add_local_input(0, &[0]).is_err());
+    assert!(sess.network_stats(1).is_err());
+    assert!(sess.network_stats(7).is_err());
+    assert!(sess.disconnect_player(7).is_err());
+    assert!(sess.save_game_state(0, &[0]).is_err());
+    assert!(sess.submit_checksum(0, 0, 0).is_err());
+
+    assert!(sess.poll_remote_clients().unwrap());
+    assert!(sess.advance_frame().is_err());
+    assert!(sess.disconnect_player(1).unwrap());
+}
+
+#[test]
+fn synctest_error_paths_keep_session() {
+    let mut sess = synctest_session(|_| {});
+
+    assert!(sess.add_local_input(0, &[1, 0]).is_err());
+    assert!(sess.add_local_input(5, &[1, 0, 0, 0]).is_err());
+    // only one of two players has input
+    sess.add_local_input(0, &[1, 0, 0, 0]).unwrap();
+    assert!(sess.advance_frame().is_err());
+    assert!(sess.network_stats(0).is_err());
+    assert!(sess.disconnect_player(0).is_err());
+    assert!(sess.save_game_state(42, &[0]).is_err());
+    assert!(sess.submit_checksum(42, 0, 0).is_err());
+
+    for _ in 0..10 {
+        run_synctest_frame(&mut sess, [&[1, 0, 0, 0], &[2, 0, 0, 0]]);
+    }
+    assert_eq!(sess.get_frames_ahead().unwrap(), 0);
+}
+
+#[test]
+fn cleaned_session_reports_errors() {
+    let mut sess = synctest_session(|_| {});
+    run_synctest_frame(&mut sess, [&[1, 0, 0, 0], &[2, 0, 0, 0]]);
+    assert!(sess.clean_session());
+
+    let closed = "cleaned up";
+    assert!(error(sess.poll_remote_clients()).contains(closed));
+    assert!(error(sess.add_local_input(0, &[0, 0, 0, 0])).contains(closed));
+    assert!(error(sess.get_current_state()).contains(closed));
+    assert!(error(sess.get_events()).contains(closed));
+    assert!(error(sess.advance_frame()).contains(closed));
+    assert!(error(sess.save_game_state(0, &[0])).contains(closed));
+    assert!(error(sess.submit_checksum(0, 0, 0)).contains(closed));
+    assert!(error(sess.get_frames_ahead()).contains(closed));
+    assert!(error(sess.network_stats(0)).contains(closed));
+    assert!(error(sess.disconnect_player(0)).contains(closed));
+    // cleaning twice is harmless
+    assert!(sess.clean_session());
+}
+
+#[test]
+fn failed_start_keeps_builder() {
+    let mut builder = new_session_builder();
+    builder
+        .add_player(player(0, GGRSPlayerType::Local, ""))
+        .unwrap()
+        .add_player(player(1, GGRSPlayerType::Remote, "not an address"))
+        .unwrap();
+    assert!(builder.start_p2p_session(0).is_err());
+    assert!(builder.start_synctest_session().is_err());
+
+    let mut builder = new_session_builder();
+    builder
+        .add_player(player(0, GGRSPlayerType::Local, ""))
+        .unwrap()
+        .add_player(player(1, GGRSPlayerType::Remote, "127.0.0.1:9"))
+        .unwrap();
+    assert!(builder.start_synctest_session().is_ok());
+    assert!(builder.start_p2p_session(0).is_ok());
+}
+
+#[test]
+fn try_variants_report_error_codes() {
+    let mut sess = unsynchronized_p2p_session();
+    let mut result = GGRSFrameResult::default();
+    assert!(sess.try_advance_frame(&mut result).code == GGRSErrorCode::NotSynchronized);
+    assert!(sess.try_add_local_input(0, &[0]).code == GGRSErrorCode::InvalidInputSize);
+    assert!(sess.try_add_local_input(1, &[0, 0, 0, 0]).code == GGRSErrorCode::InvalidRequest);
+    assert!(sess.try_save_game_state(0, &[0]).code == GGRSErrorCode::NoPendingSave);
+    let ok = sess.try_poll_remote_clients();
+    assert!(ok.code == GGRSErrorCode::Ok && ok.message.is_empty());
+
+    let mut sess = synctest_session(|_| {});
+    assert!(sess.try_disconnect_player(0).code == GGRSErrorCode::UnsupportedOperation);
+    sess.clean_session();
+    let mut frames_ahead = 0;
+    assert!(sess.try_get_frames_ahead(&mut frames_ahead).code == GGRSErrorCode::SessionClosed);
+
+    let mut builder = new_session_builder();
+    assert!(
+        builder
+            .try_add_player(player(0, GGRSPlayerType::Local, ""))
+            .code
+            == GGRSErrorCode::Ok
+    );
+    let remote = player(1, GGRSPlayerType::Remote, "nowhere");
+    assert!(builder.try_add_player(remote).code == GGRSErrorCode::Ok);
+    let mut error = GGRSErrorInfo {
+        code: GGRSErrorCode::Ok,
+        message: String::new(),
+    };
+    let mut sess = builder.try_start_p2p_session(0, &mut error);
+    assert!(error.code == GGRSErrorCode::AddressParseFailed);
+    assert!(sess.try_poll_remote_clients().code == GGRSErrorCode::SessionClosed);
+}
+
+#[test]
+fn batched_inputs_name_the_wrong_handle() {
+    // two pads on this machine against two remote players
+    let mut sess = new_session_builder()
+        .with_num_players(4)
+        .unwrap()
+        .add_player(player(0, GGRSPlayerType::Local, ""))
+        .unwrap()
+        .add_player(player(1, GGRSPlayerType::Local, ""))
+        .unwrap()
+        .add_player(player(2, GGRSPlayerType::Remote, "127.0.0.1:9"))
+        .unwrap()
+        .add_player(player(3, GGRSPlayerType::Remote, "127.0.0.1:9"))
+        .unwrap()
+        .start_p2p_session(0)
+        .unwrap();
+    let input = [0, 0, 0, 0];
+    let cases = [
+        (
+            vec![local_input(0, &input), local_input(2, &input)],
+            "Error player 2 is not a local player",
+        ),
+        (
+            vec![local_input(0, &input), local_input(7, &input)],
+            "Error player 7 is not a registered player",
+        ),
+        (
+            vec![local_input(1, &input), local_input(1, &input)],
+            "Error player 1 got more than one input",
+        ),
+        (
+            vec![local_input(1, &input)],
+            "Error no input for local player 0",
+        ),
+    ];
+    for (inputs, msg) in &cases {
+        assert!(sess.try_add_local_inputs(inputs).code == GGRSErrorCode::InvalidRequest);
+        let err = error(sess.add_local_inputs(inputs));
+        assert!(err.contains(msg), "{}", err);
+    }
+    let short = [local_input(0, &input), local_input(1, &[0])];
+    assert!(sess.try_add_local_inputs(&short).code == GGRSErrorCode::InvalidInputSize);
+    assert!(error(sess.add_local_inputs(&short)).contains("player 1"));
+    let both = [local_input(0, &input), local_input(1, &input)];
+    assert!(sess.try_add_local_inputs(&both).code == GGRSErrorCode::Ok);
+
+    // a rejected batch leaves the session usable, the next full batch advances
+    let mut sess = synctest_session(|_| {});
+    assert!(sess
+        .add_local_inputs(&[local_input(0, &[1, 0, 0, 0])])
+        .is_err());
+    sess.add_local_inputs(&[local_input(1, &[2, 0, 0, 0]), local_input(0, &[1, 0, 0, 0])])
+        .unwrap();
+    sess.advance_frame().unwrap();
+    sess.clean_session();
+    assert!(sess.try_add_local_inputs(&[]).code == GGRSErrorCode::SessionClosed);
+}
+
+#[test]
+fn ggrs_panics_poison_the_session() {
+    let remote = UdpSocket::bind("127.0.0.1:0").unwrap();
+    let mut sess = new_session_builder()
+        .with_bind_address("127.0.0.1")
+        .unwrap()
+        .add_player(player(0, GGRSPlayerType::Local, ""))
+        .unwrap()
+        .add_player(player(
+            1,
+            GGRSPlayerType::Remote,
+            &remote.local_addr().unwrap().to_string(),
+        ))
+        .unwrap()
+        .start_p2p_session(0)
+        .unwrap();
+
+    // a bincode encoded quality reply that answers a ping from the future. ggrs asserts on it
+    let mut reply = 0u16.to_le_bytes().to_vec();
+    reply.extend_from_slice(&5u32.to_le_bytes());
+    reply.extend_from_slice(&u128::MAX.to_le_bytes());
+    remote
+        .send_to(&reply, sess.local_address().unwrap())
+        .unwrap();
+
+    let deadline = Instant::now() + Duration::from_secs(5);
+    let err = loop {
+        assert!(Instant::now() < deadline, "the reply never arrived");
+        match sess.poll_remote_clients() {
+            Ok(_) => thread::sleep(Duration::from_millis(1)),
+            Err(err) => break err,
+        }
+    };
+    assert!(err.code() == GGRSErrorCode::Panic);
+    assert!(err.to_string().contains("millis >= body.pong"));
+
+    // whatever ggrs left behind is never touched again
+    assert!(code(sess.poll_remote_clients()) == GGRSErrorCode::SessionPoisoned);
+    assert!(code(sess.get_current_state()) == GGRSErrorCode::SessionPoisoned);
+    assert!(code(sess.add_local_input(0, &[0; 4])) == GGRSErrorCode::SessionPoisoned);
+    assert!(
+        sess.try_advance_frame(&mut GGRSFrameResult::default()).code
+            == GGRSErrorCode::SessionPoisoned
+    );
+    // cleaning up still works
+    assert!(sess.clean_session());
+}
@@ -0,0 +1,592 @@
+// drives the session api the way the c++ side does: p2p peers and a spectator over the loopback
+// transport, and a synctest session. checks the action order and the translated events
+use std::thread;
+use std::time::{Duration, Instant};
+
+use cpp_ggrs::wrapper::{
+    GGRSDuration, GGRSErrorCode, GGRSEvent, GGRSEventType, GGRSFrameAction, GGRSFrameActionType,
+    GGRSFrameResult, GGRSInputStatus, GGRSPlayerType, GGRSSessionState,
+};
+use cpp_ggrs::{new_session_builder, GGRSSession, GGRSSessionBuilder};
+use cxx::UniquePtr;
+
+mod common;
+use common::{code, error, local_input, loopback_peer, player, step_peer, synctest_session};
+
+// peers notice a silent remote quickly
+fn short_timeouts(builder: &mut GGRSSessionBuilder) {
+    builder
+        .with_disconnect_notify_delay(100)
+        .unwrap()
+        .with_disconnect_timeout(400)
+        .unwrap();
+}
+
+fn describe(actions: &[GGRSFrameAction]) -> Vec<(&'static str, i32)> {
+    actions
+        .iter()
+        .map(|action| {
+            let kind = match action.action_type {
+                GGRSFrameActionType::SaveGameState => "save",
+                GGRSFrameActionType::LoadGameState => "load",
+                _ => "advance",
+            };
+            (kind, action.action_info.frame)
+        })
+        .collect()
+}
+
+// polls both peers until they are running, returns the events each of them saw
+fn synchronize(
+    first: &mut GGRSSession,
+    second: &mut GGRSSession,
+) -> (Vec<GGRSEvent>, Vec<GGRSEvent>) {
+    let (mut first_events, mut second_events) = (Vec::new(), Vec::new());
+    let deadline = Instant::now() + Duration::from_secs(5);
+    while first.get_current_state().unwrap() != GGRSSessionState::Running
+        || second.get_current_state().unwrap() != GGRSSessionState::Running
+    {
+        assert!(Instant::now() < deadline, "peers did not synchronize");
+        first.poll_remote_clients().unwrap();
+        second.poll_remote_clients().unwrap();
+        first_events.extend(first.get_events().unwrap());
+        second_events.extend(second.get_events().unwrap());
+        thread::sleep(Duration::from_millis(1));
+    }
+    (first_events, second_events)
+}
+
+// the frame doubles as input, returns whether the frame was simulated
+fn step(sess: &mut GGRSSession, handle: u32, frame: i32) -> bool {
+    step_peer(sess, &[local_input(handle, &frame.to_le_bytes())])
+        .is_some_and(|result| !result.skip_frame)
+}
+
+// runs both peers until each simulated the given number of frames
+fn run_peers(first: &mut GGRSSession, second: &mut GGRSSession, frames: i32) {
+    let (mut first_frame, mut second_frame) = (0, 0);
+    let deadline = Instant::now() + Duration::from_secs(5);
+    while first_frame < frames || second_frame < frames {
+        assert!(Instant::now() < deadline, "peers got stuck");
+        if step(first, 0, first_frame) {
+            first_frame += 1;
+        }
+        if step(second, 1, second_frame) {
+            second_frame += 1;
+        }
+        thread::sleep(Duration::from_millis(1));
+    }
+}
+
+#[test]
+fn synctest_actions_are_ordered() {
+    let mut sess = new_session_builder()
+        .with_num_players(1)
+        .unwrap()
+        .with_check_distance(2)
+        .unwrap()
+        .add_player(player(0, GGRSPlayerType::Local, ""))
+        .unwrap()
+        .start_synctest_session()
+        .unwrap();
+
+    let mut seen = Vec::new();
+    for frame in 0..5i32 {
+        sess.add_local_input(0, &frame.to_le_bytes()).unwrap();
+        let result = sess.advance_frame().unwrap();
+        assert!(!result.skip_frame);
+        assert!(result.checksum_mismatches.is_empty());
+        for action in &result.actions {
+            match action.action_type {
+                GGRSFrameActionType::SaveGameState => sess
+                    .save_game_state(
+                        action.action_info.frame,
+                        &action.action_info.frame.to_le_bytes(),
+                    )
+                    .map(|_| ())
+                    .unwrap(),
+                // a load hands back exactly what was saved for that frame
+                GGRSFrameActionType::LoadGameState => assert_eq!(
+                    action.action_info.state,
+                    action.action_info.frame.to_le_bytes()
+                ),
+                _ => assert_eq!(action.action_info.inputs.len(), 1),
+            }
+        }
+        seen.push(describe(&result.actions));
+    }
+
+    assert_eq!(seen[0], [("save", 0), ("advance", 0)]);
+    assert_eq!(seen[2], [("save", 2), ("advance", 2)]);
+    // from here on every frame rolls back check_distance frames and resimulates up to the new one
+    assert_eq!(
+        seen[3],
+        [
+            ("load", 1),
+            ("advance", 1),
+            ("save", 2),
+            ("advance", 2),
+            ("save", 3),
+            ("advance", 3)
+        ]
+    );
+    assert_eq!(seen[4][0], ("load", 2));
+    assert_eq!(seen[4].last(), Some(&("advance", 4)));
+}
+
+#[test]
+fn saved_states_come_back_on_load() {
+    let mut sess = new_session_builder()
+        .with_num_players(1)
+        .unwrap()
+        .add_player(player(0, GGRSPlayerType::Local, ""))
+        .unwrap()
+        .start_synctest_session()
+        .unwrap();
+    // states of every size, the wrapper stores them without looking inside
+    let state_of = |frame: i32| vec![frame as u8; 1000 * frame as usize];
+
+    let mut loads = 0;
+    for frame in 0..8i32 {
+        sess.add_local_input(0, &frame.to_le_bytes()).unwrap();
+        for action in sess.advance_frame().unwrap().actions {
+            let frame = action.action_info.frame;
+            match action.action_type {
+                GGRSFrameActionType::SaveGameState => {
+                    assert!(action.action_info.state.is_empty());
+                    sess.save_game_state(frame, &state_of(frame)).unwrap();
+                }
+                GGRSFrameActionType::LoadGameState => {
+                    assert_eq!(action.action_info.state, state_of(frame));
+                    loads += 1;
+                }
+                _ => assert!(action.action_info.state.is_empty()),
+            }
+        }
+    }
+    assert!(loads > 0);
+    // only the frames of the last advance_frame can be saved
+    assert!(code(sess.save_game_state(0, &[1])) == GGRSErrorCode::NoPendingSave);
+}
+
+// runs a synctest with check distance 2, the checksum of a frame is (frame, 1) except for resimulations of
+// unstable_frame, which get (frame, 2). returns every advance_frame result
+#[test]
+fn inputs_keep_the_configured_size() {
+    let mut sess = synctest_session(|builder| {
+        builder
+            .with_input_size(3)
+            .unwrap()
+            .with_check_distance(1)
+            .unwrap();
+    });
+
+    // only inputs of exactly the configured size get in
+    assert!(code(sess.add_local_input(0, &[1, 2])) == GGRSErrorCode::InvalidInputSize);
+    assert!(code(sess.add_local_input(0, &[1, 2, 3, 4])) == GGRSErrorCode::InvalidInputSize);
+    assert!(error(sess.add_local_input(1, &[])).contains("expects: 3"));
+
+    for frame in 0..4u8 {
+        for handle in 0..2u8 {
+            sess.add_local_input(handle as u32, &[frame, handle, 0xff])
+                .unwrap();
+        }
+        let result = sess.advance_frame().unwrap();
+        for action in &result.actions {
+            let info = &action.action_info;
+            match action.action_type {
+                GGRSFrameActionType::SaveGameState => {
+                    sess.save_game_state(info.frame, &[0]).unwrap();
+                }
+                // every byte of every player comes back, resimulated frames included
+                GGRSFrameActionType::AdvanceFrame => {
+                    let frame = info.frame as u8;
+                    assert_eq!(info.inputs[0].input, [frame, 0, 0xff]);
+                    assert_eq!(info.inputs[1].input, [frame, 1, 0xff]);
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+// runs a synctest until it finds a mismatch or ran all frames. the resimulation of unstable_frame submits
+// the low half resimulated_lo, or no checksum at all
+fn run_checksummed_synctest(
+    unstable_frame: i32,
+    resimulated_lo: Option<u64>,
+    frames: i32,
+) -> (Box<GGRSSession>, Vec<GGRSFrameResult>) {
+    let mut sess = new_session_builder()
+        .with_num_players(1)
+        .unwrap()
+        .with_check_distance(2)
+        .unwrap()
+        .add_player(player(0, GGRSPlayerType::Local, ""))
+        .unwrap()
+        .start_synctest_session()
+        .unwrap();
+    let mut saved = Vec::new();
+    let mut results = Vec::new();
+    for frame in 0..frames {
+        sess.add_local_input(0, &frame.to_le_bytes()).unwrap();
+        let result = sess.advance_frame().unwrap();
+        for action in &result.actions {
+            if action.action_type == GGRSFrameActionType::SaveGameState {
+                let frame = action.action_info.frame;
+                let checksum_lo = match saved.contains(&frame) && frame == unstable_frame {
+                    true => resimulated_lo,
+                    false => Some(1),
+                };
+                saved.push(frame);
+                sess.save_game_state(frame, &[0]).unwrap();
+                if let Some(checksum_lo) = checksum_lo {
+                    sess.submit_checksum(frame, frame as u64, checksum_lo)
+                        .unwrap();
+                }
+            }
+        }
+        let mismatched = !result.checksum_mismatches.is_empty();
+        results.push(result);
+        if mismatched {
+            break;
+        }
+    }
+    (sess, results)
+}
+
+#[test]
+fn synctest_reports_mismatching_checksums() {
+    // the same checksum on every resimulation is fine
+    let (_, results) = run_checksummed_synctest(-1, None, 10);
+    assert_eq!(results.len(), 10);
+    assert!(results.iter().all(|r| r.checksum_mismatches.is_empty()));
+
+    let (mut sess, results) = run_checksummed_synctest(3, Some(2), 10);
+    // frame 3 is resimulated by the 5th frame and compared by the 6th
+    assert_eq!(results.len(), 6);
+    let result = &results[5];
+    assert!(result.actions.is_empty());
+    let mismatch = &result.checksum_mismatches[0];
+    assert_eq!(mismatch.frame, 3);
+    assert!(mismatch.has_original_checksum && mismatch.has_resimulated_checksum);
+    assert_eq!(
+        (mismatch.original_checksum_hi, mismatch.original_checksum_lo),
+        (3, 1)
+    );
+    assert_eq!(
+        (
+            mismatch.resimulated_checksum_hi,
+            mismatch.resimulated_checksum_lo
+        ),
+        (3, 2)
+    );
+
+    // ggrs can't get past the mismatch, the session says so instead of reporting it again
+    for _ in 0..2 {
+        sess.add_local_input(0, &[0; 4]).unwrap();
+        assert!(code(sess.advance_frame()) == GGRSErrorCode::MismatchedChecksum);
+        assert!(error(sess.advance_frame()).contains("frame 3"));
+    }
+}
+
+#[test]
+fn synctest_tells_a_missing_checksum_from_zero() {
+    // the resimulation of frame 3 saves without a checksum
+    let (_, results) = run_checksummed_synctest(3, None, 10);
+    let mismatch = &results.last().unwrap().checksum_mismatches[0];
+    assert_eq!(mismatch.frame, 3);
+    assert!(mismatch.has_original_checksum);
+    assert_eq!(
+        (mismatch.original_checksum_hi, mismatch.original_checksum_lo),
+        (3, 1)
+    );
+    assert!(!mismatch.has_resimulated_checksum);
+
+    // a real checksum of zero is one
+    let (_, results) = run_checksummed_synctest(3, Some(0), 10);
+    let mismatch = &results.last().unwrap().checksum_mismatches[0];
+    assert!(mismatch.has_resimulated_checksum);
+    assert_eq!(mismatch.resimulated_checksum_lo, 0);
+}
+
+#[test]
+fn synctest_has_no_network() {
+    let mut sess = new_session_builder()
+        .with_num_players(1)
+        .unwrap()
+        .add_player(player(0, GGRSPlayerType::Local, ""))
+        .unwrap()
+        .start_synctest_session()
+        .unwrap();
+    sess.poll_remote_clients().unwrap();
+    assert!(sess.get_events().unwrap().is_empty());
+    assert!(sess.get_current_state().unwrap() == GGRSSessionState::Running);
+    assert!(code(sess.network_stats(0)) == GGRSErrorCode::UnsupportedOperation);
+    assert!(code(sess.disconnect_player(0)) == GGRSErrorCode::UnsupportedOperation);
+}
+
+#[test]
+fn peers_synchronize_and_advance() {
+    let mut first = loopback_peer(0, "sess_sync0", "sess_sync1", short_timeouts);
+    let mut second = loopback_peer(1, "sess_sync1", "sess_sync0", short_timeouts);
+    // no network stats before the peers know each other
+    assert!(code(first.network_stats(1)) == GGRSErrorCode::NotSynchronized);
+    assert!(code(first.advance_frame()) == GGRSErrorCode::NotSynchronized);
+
+    let (first_events, second_events) = synchronize(&mut first, &mut second);
+    for (events, remote) in [
+        (&first_events, "sess_sync1"),
+        (&second_events, "sess_sync0"),
+    ] {
+        let (last, progress) = events.split_last().unwrap();
+        assert!(last.event_type == GGRSEventType::Synchronized);
+        assert_eq!(last.event_info.addr, remote);
+        assert!(!progress.is_empty());
+        for (index, event) in progress.iter().enumerate() {
+            assert!(event.event_type == GGRSEventType::Synchronizing);
+            assert_eq!(event.event_info.addr, remote);
+            assert_eq!(event.event_info.count, index as u32 + 1);
+            assert!(event.event_info.count < event.event_info.total);
+        }
+    }
+
+    // the first frame is saved before it is advanced with one input per player. ggrs asks for the
+    // initial state twice, both requests are answered by a single save
+    first.add_local_input(0, &0i32.to_le_bytes()).unwrap();
+    let result = first.advance_frame().unwrap();
+    assert_eq!(
+        describe(&result.actions),
+        [("save", 0), ("save", 0), ("advance", 0)]
+    );
+    assert_eq!(result.actions[2].action_info.inputs.len(), 2);
+    first.save_game_state(0, &[0]).unwrap();
+
+    // ggrs needs a second of traffic before it reports any stats
+    let (mut first_frame, mut second_frame) = (1, 0);
+    let deadline = Instant::now() + Duration::from_secs(5);
+    while code(first.network_stats(1)) == GGRSErrorCode::NotSynchronized {
+        assert!(Instant::now() < deadline, "no network stats");
+        if step(&mut first, 0, first_frame) {
+            first_frame += 1;
+        }
+        if step(&mut second, 1, second_frame) {
+            second_frame += 1;
+        }
+        thread::sleep(Duration::from_millis(5));
+    }
+    assert!(first_frame > 30 && second_frame > 30);
+    assert!(first.network_stats(1).unwrap().ping.ms < 100);
+    // stats only exist for remote players
+    assert!(code(first.network_stats(0)) == GGRSErrorCode::InvalidRequest);
+}
+
+#[test]
+fn null_event_handler_keeps_get_events() {
+    let mut first = loopback_peer(0, "sess_handler0", "sess_handler1", short_timeouts);
+    let mut second = loopback_peer(1, "sess_handler1", "sess_handler0", short_timeouts);
+    // a null handler is the same as never setting one, events are still polled
+    first.set_event_handler(UniquePtr::null()).unwrap();
+    let (first_events, _) = synchronize(&mut first, &mut second);
+    assert!(first_events.last().unwrap().event_type == GGRSEventType::Synchronized);
+
+    first.clean_session();
+    assert!(code(first.set_event_handler(UniquePtr::null())) == GGRSErrorCode::SessionClosed);
+}
+
+#[test]
+fn tick_runs_whole_frames() {
+    let mut first = loopback_peer(0, "sess_tick0", "sess_tick1", short_timeouts);
+    let mut second = loopback_peer(1, "sess_tick1", "sess_tick0", short_timeouts);
+    // nothing advances while synchronizing, the events come along anyway
+    let (mut first_events, mut frames) = (Vec::new(), [0i32, 0]);
+    let deadline = Instant::now() + Duration::from_secs(5);
+    while frames[0] < 20 || frames[1] < 20 {
+        assert!(Instant::now() < deadline, "peers got stuck");
+        for (handle, sess) in [(0, &mut first), (1, &mut second)] {
+            let result = sess
+                .tick(&[local_input(handle, &frames[handle as usize].to_le_bytes())])
+                .unwrap();
+            if handle == 0 {
+                first_events.extend(result.events);
+            }
+            if result.state != GGRSSessionState::Running {
+                assert!(result.actions.is_empty());
+                continue;
+            }
+            for action in &result.actions {
+                if action.action_type == GGRSFrameActionType::SaveGameState {
+                    let frame = action.action_info.frame;
+                    sess.save_game_state(frame, &frame.to_le_bytes()).unwrap();
+                }
+            }
+            if !result.skip_frame {
+                frames[handle as usize] += 1;
+            }
+        }
+        thread::sleep(Duration::from_millis(1));
+    }
+    assert!(first_events
+        .iter()
+        .any(|e| e.event_type == GGRSEventType::Synchronized));
+    // a bad input fails the tick before anything advances
+    assert!(
+        code(first.tick(&[local_input(0, &[0; 4]), local_input(7, &[0; 4])]))
+            == GGRSErrorCode::InvalidRequest
+    );
+
+    // synctest sessions run and advance right away
+    let mut synctest = synctest_session(|_| {});
+    let result = synctest
+        .tick(&[local_input(0, &[0; 4]), local_input(1, &[0; 4])])
+        .unwrap();
+    assert!(result.state == GGRSSessionState::Running);
+    assert_eq!(describe(&result.actions), [("save", 0), ("advance", 0)]);
+}
+
+#[test]
+fn peers_report_interruptions_and_disconnects() {
+    let mut first = loopback_peer(0, "sess_drop0", "sess_drop1", short_timeouts);
+    let mut second = loopback_peer(1, "sess_drop1", "sess_drop0", short_timeouts);
+    synchronize(&mut first, &mut second);
+    // the remote goes silent, first hears about it after the notify delay and drops it after the timeout
+    drop(second);
+
+    let mut events = Vec::new();
+    let deadline = Instant::now() + Duration::from_secs(5);
+    let mut frame = 0;
+    while !events
+        .iter()
+        .any(|e: &GGRSEvent| e.event_type == GGRSEventType::Disconnected)
+    {
+        assert!(Instant::now() < deadline, "remote was never disconnected");
+        step(&mut first, 0, frame);
+        frame += 1;
+        events.extend(first.get_events().unwrap());
+        thread::sleep(Duration::from_millis(5));
+    }
+    let interrupted = events
+        .iter()
+        .find(|e| e.event_type == GGRSEventType::NetworkInterrupted)
+        .expect("no interruption before the disconnect");
+    assert_eq!(interrupted.event_info.addr, "sess_drop1");
+    let timeout = interrupted.event_info.disconnect_timeout;
+    assert_eq!((timeout.ms, timeout.us), (300, 300_000));
+    let disconnected = events.last().unwrap();
+    assert!(disconnected.event_type == GGRSEventType::Disconnected);
+    assert_eq!(disconnected.event_info.addr, "sess_drop1");
+    // events name the players behind the address as well
+    assert_eq!(disconnected.event_info.player_handles, [1]);
+    assert!(disconnected.event_info.player_type == GGRSPlayerType::Remote);
+}
+
+#[test]
+fn durations_saturate() {
+    let duration = GGRSDuration::from_millis(1500);
+    assert_eq!((duration.ms, duration.us), (1500, 1_500_000));
+    // microseconds run out of range long before milliseconds do
+    let duration = GGRSDuration::from_millis(u64::MAX as u128 / 10);
+    assert_eq!((duration.ms, duration.us), (u64::MAX / 10, u64::MAX));
+    let duration = GGRSDuration::from_millis(u128::MAX);
+    assert_eq!((duration.ms, duration.us), (u64::MAX, u64::MAX));
+}
+
+#[test]
+fn disconnect_player_validates_handles() {
+    let mut first = loopback_peer(0, "sess_kick0", "sess_kick1", short_timeouts);
+    let mut second = loopback_peer(1, "sess_kick1", "sess_kick0", short_timeouts);
+    synchronize(&mut first, &mut second);
+    run_peers(&mut first, &mut second, 10);
+
+    assert!(code(first.disconnect_player(0)) == GGRSErrorCode::InvalidRequest);
+    assert!(code(first.disconnect_player(7)) == GGRSErrorCode::InvalidRequest);
+    first.disconnect_player(1).unwrap();
+    // a player can only be disconnected once
+    assert!(code(first.disconnect_player(1)) == GGRSErrorCode::InvalidRequest);
+
+    // from now on the remote input is reported as disconnected
+    for frame in 10..13i32 {
+        let result = step_peer(&mut first, &[local_input(0, &frame.to_le_bytes())]).unwrap();
+        let last = result.actions.last().unwrap();
+        assert!(last.action_type == GGRSFrameActionType::AdvanceFrame);
+        assert!(last.action_info.inputs[1].status == GGRSInputStatus::Disconnected);
+    }
+}
+
+#[test]
+fn spectator_follows_host() {
+    let mut host = loopback_peer(0, "sess_host0", "sess_host1", |builder| {
+        short_timeouts(builder);
+        builder
+            .add_player(player(2, GGRSPlayerType::Spectator, "sess_watch"))
+            .unwrap();
+    });
+    let mut other = loopback_peer(1, "sess_host1", "sess_host0", short_timeouts);
+    let mut spectator = new_session_builder()
+        .start_spectator_session_on_loopback("sess_watch", "sess_host0")
+        .unwrap();
+    // a spectator has no players of its own to send inputs for or disconnect
+    assert!(code(spectator.disconnect_player(0)) == GGRSErrorCode::UnsupportedOperation);
+
+    let mut host_events = Vec::new();
+    let mut spectator_events = Vec::new();
+    let mut watched = Vec::new();
+    let (mut host_frame, mut other_frame) = (0, 0);
+    let deadline = Instant::now() + Duration::from_secs(5);
+    while watched.len() < 20 {
+        assert!(
+            Instant::now() < deadline,
+            "spectator did not follow the host"
+        );
+        for (sess, handle, frame) in [
+            (&mut host, 0, &mut host_frame),
+            (&mut other, 1, &mut other_frame),
+        ] {
+            if step(sess, handle, *frame) {
+                *frame += 1;
+            }
+            let events = sess.get_events().unwrap();
+            if handle == 0 {
+                host_events.extend(events);
+            }
+        }
+        spectator.poll_remote_clients().unwrap();
+        spectator_events.extend(spectator.get_events().unwrap());
+        if spectator.get_current_state().unwrap() == GGRSSessionState::Running {
+            let result = spectator.advance_frame().unwrap();
+            // spectators never roll back, they only advance on confirmed inputs
+            for action in result.actions {
+                assert!(action.action_type == GGRSFrameActionType::AdvanceFrame);
+                assert_eq!(action.action_info.frame, watched.len() as i32);
+                watched.push(action.action_info.inputs);
+            }
+        }
+        thread::sleep(Duration::from_millis(1));
+    }
+
+    // the host tells its remote player and its spectator apart, the spectator gets every player for the host
+    let players = |events: &[GGRSEvent], addr: &str| {
+        let event = events
+            .iter()
+            .find(|e| e.event_type == GGRSEventType::Synchronized && e.event_info.addr == addr)
+            .expect("peer never synchronized");
+        (
+            event.event_info.player_handles.clone(),
+            event.event_info.player_type,
+        )
+    };
+    let (handles, player_type) = players(&host_events, "sess_host1");
+    assert_eq!(handles, [1]);
+    assert!(player_type == GGRSPlayerType::Remote);
+    let (handles, player_type) = players(&host_events, "sess_watch");
+    assert_eq!(handles, [2]);
+    assert!(player_type == GGRSPlayerType::Spectator);
+    let (handles, player_type) = players(&spectator_events, "sess_host0");
+    assert_eq!(handles, [0, 1]);
+    assert!(player_type == GGRSPlayerType::Remote);
+    assert!(spectator.get_frames_ahead().unwrap() == 0);
+    for (frame, inputs) in watched.iter().enumerate() {
+        assert_eq!(inputs[0].input, (frame as i32).to_le_bytes());
+        assert_eq!(inputs[1].input, (frame as i32).to_le_bytes());
+    }
+}
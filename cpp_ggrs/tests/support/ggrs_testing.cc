@@ -0,0 +1,219 @@
+#include "cpp_ggrs/tests/support/ggrs_testing.h"
+#include "cpp_ggrs/src/lib.rs.h"
+
+#include <map>
+#include <mutex>
+#include <sstream>
+#include <stdexcept>
+#include <string>
+#include <utility>
+#include <vector>
+
+namespace GGRS {
+namespace {
+struct Packet {
+  std::string from;
+  std::vector<std::uint8_t> msg;
+};
+
+// tests run on several threads, every socket owns the inbox of its name
+std::mutex inboxes_mutex;
+std::map<std::string, std::vector<Packet>> inboxes;
+
+class MemorySocket : public GGRSNonBlockingSocket {
+public:
+  explicit MemorySocket(std::string name) : name_(std::move(name)) {
+    std::lock_guard<std::mutex> lock(inboxes_mutex);
+    inboxes[name_];
+  }
+
+  ~MemorySocket() override {
+    std::lock_guard<std::mutex> lock(inboxes_mutex);
+    inboxes.erase(name_);
+  }
+
+  void send_to(rust::Slice<const std::uint8_t> msg, rust::Str addr) override {
+    std::lock_guard<std::mutex> lock(inboxes_mutex);
+    auto inbox = inboxes.find(std::string(addr));
+    if (inbox != inboxes.end()) {
+      inbox->second.push_back({name_, {msg.begin(), msg.end()}});
+    }
+  }
+
+  rust::Vec<GGRSReceivedMessage> receive_all_messages() override {
+    std::vector<Packet> packets;
+    {
+      std::lock_guard<std::mutex> lock(inboxes_mutex);
+      packets.swap(inboxes[name_]);
+    }
+    rust::Vec<GGRSReceivedMessage> received;
+    for (const auto &packet : packets) {
+      rust::Vec<std::uint8_t> msg;
+      for (auto byte : packet.msg) {
+        msg.push_back(byte);
+      }
+      received.push_back({rust::String(packet.from), std::move(msg)});
+    }
+    return received;
+  }
+
+private:
+  std::string name_;
+};
+
+// drops the packets of every fourth call, by throwing
+class ThrowingMemorySocket : public MemorySocket {
+public:
+  using MemorySocket::MemorySocket;
+
+  void send_to(rust::Slice<const std::uint8_t> msg, rust::Str addr) override {
+    if (sends_++ % 4 == 3) {
+      throw std::runtime_error("send failed");
+    }
+    MemorySocket::send_to(msg, addr);
+  }
+
+  rust::Vec<GGRSReceivedMessage> receive_all_messages() override {
+    auto received = MemorySocket::receive_all_messages();
+    if (receives_++ % 4 == 3) {
+      throw std::runtime_error("receive failed");
+    }
+    return received;
+  }
+
+private:
+  unsigned sends_ = 0;
+  unsigned receives_ = 0;
+};
+
+// every recording handler appends to the log of its name
+std::mutex logs_mutex;
+std::map<std::string, std::vector<std::string>> logs;
+
+std::string describe_peer(rust::Str addr,
+                          rust::Slice<const std::uint32_t> player_handles,
+                          GGRSPlayerType player_type) {
+  std::ostringstream line;
+  line << std::string(addr) << " [";
+  for (std::size_t i = 0; i < player_handles.size(); ++i) {
+    line << (i == 0 ? "" : ",") << player_handles[i];
+  }
+  line << "] ";
+  switch (player_type) {
+  case GGRSPlayerType::Local:
+    line << "local";
+    break;
+  case GGRSPlayerType::Remote:
+    line << "remote";
+    break;
+  case GGRSPlayerType::Spectator:
+    line << "spectator";
+    break;
+  default:
+    line << "unknown";
+  }
+  return line.str();
+}
+
+// writes every call as one line: the callback name followed by its arguments
+class RecordingHandler : public GGRSEventHandler {
+public:
+  RecordingHandler(std::string log, std::string throw_on)
+      : log_(std::move(log)), throw_on_(std::move(throw_on)) {}
+
+  void on_synchronizing(rust::Str addr,
+                        rust::Slice<const std::uint32_t> player_handles,
+                        GGRSPlayerType player_type, std::uint32_t count,
+                        std::uint32_t total) override {
+    record("on_synchronizing", describe_peer(addr, player_handles, player_type) +
+                                   " " + std::to_string(count) + "/" +
+                                   std::to_string(total));
+  }
+  void on_synchronized(rust::Str addr,
+                       rust::Slice<const std::uint32_t> player_handles,
+                       GGRSPlayerType player_type) override {
+    record("on_synchronized", describe_peer(addr, player_handles, player_type));
+  }
+  void on_network_interrupted(rust::Str addr,
+                              rust::Slice<const std::uint32_t> player_handles,
+                              GGRSPlayerType player_type,
+                              const GGRSDuration &disconnect_timeout) override {
+    record("on_network_interrupted",
+           describe_peer(addr, player_handles, player_type) + " " +
+               std::to_string(disconnect_timeout.ms) + "ms " +
+               std::to_string(disconnect_timeout.us) + "us");
+  }
+  void on_network_resumed(rust::Str addr,
+                          rust::Slice<const std::uint32_t> player_handles,
+                          GGRSPlayerType player_type) override {
+    record("on_network_resumed",
+           describe_peer(addr, player_handles, player_type));
+  }
+  void on_disconnected(rust::Str addr,
+                       rust::Slice<const std::uint32_t> player_handles,
+                       GGRSPlayerType player_type) override {
+    record("on_disconnected", describe_peer(addr, player_handles, player_type));
+  }
+  void on_wait_recommendation(std::uint32_t skip_frames) override {
+    record("on_wait_recommendation", std::to_string(skip_frames));
+  }
+  void on_desync_detected(std::int32_t frame, rust::Str addr,
+                          rust::Slice<const std::uint32_t> player_handles,
+                          GGRSPlayerType player_type,
+                          std::uint64_t local_checksum_hi,
+                          std::uint64_t local_checksum_lo,
+                          std::uint64_t remote_checksum_hi,
+                          std::uint64_t remote_checksum_lo) override {
+    record("on_desync_detected",
+           std::to_string(frame) + " " +
+               describe_peer(addr, player_handles, player_type) + " " +
+               std::to_string(local_checksum_hi) + ":" +
+               std::to_string(local_checksum_lo) + " " +
+               std::to_string(remote_checksum_hi) + ":" +
+               std::to_string(remote_checksum_lo));
+  }
+
+private:
+  void record(const std::string &callback, const std::string &args) {
+    {
+      std::lock_guard<std::mutex> lock(logs_mutex);
+      logs[log_].push_back(callback + " " + args);
+    }
+    if (callback == throw_on_) {
+      throw std::runtime_error(callback + " failed");
+    }
+  }
+
+  std::string log_;
+  std::string throw_on_;
+};
+} // namespace
+
+std::unique_ptr<GGRSNonBlockingSocket> new_memory_socket(rust::Str name) {
+  return std::make_unique<MemorySocket>(std::string(name));
+}
+
+std::unique_ptr<GGRSNonBlockingSocket>
+new_throwing_memory_socket(rust::Str name) {
+  return std::make_unique<ThrowingMemorySocket>(std::string(name));
+}
+
+std::unique_ptr<GGRSEventHandler> new_recording_handler(rust::Str log,
+                                                        rust::Str throw_on) {
+  return std::make_unique<RecordingHandler>(std::string(log),
+                                            std::string(throw_on));
+}
+
+rust::Vec<rust::String> take_recorded_calls(rust::Str log) {
+  std::vector<std::string> calls;
+  {
+    std::lock_guard<std::mutex> lock(logs_mutex);
+    calls.swap(logs[std::string(log)]);
+  }
+  rust::Vec<rust::String> recorded;
+  for (const auto &call : calls) {
+    recorded.push_back(rust::String(call));
+  }
+  return recorded;
+}
+} // namespace GGRS
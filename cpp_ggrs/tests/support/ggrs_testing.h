@@ -0,0 +1,14 @@
+#pragma once
+#include "cpp_ggrs/include/ggrs_events.h"
+#include "cpp_ggrs/include/ggrs_socket.h"
+#include "rust/cxx.h"
+#include <memory>
+
+namespace GGRS {
+std::unique_ptr<GGRSNonBlockingSocket> new_memory_socket(rust::Str name);
+std::unique_ptr<GGRSNonBlockingSocket>
+new_throwing_memory_socket(rust::Str name);
+std::unique_ptr<GGRSEventHandler> new_recording_handler(rust::Str log,
+                                                        rust::Str throw_on);
+rust::Vec<rust::String> take_recorded_calls(rust::Str log);
+} // namespace GGRS